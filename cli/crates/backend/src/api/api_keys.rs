@@ -0,0 +1,225 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::{ApiError, ApiKeyError};
+use super::graphql::mutations::{
+    ProjectApiKeyCreate, ProjectApiKeyCreateArguments, ProjectApiKeyCreateInput, ProjectApiKeyCreatePayload,
+    ProjectApiKeyDelete, ProjectApiKeyDeleteArguments, ProjectApiKeyDeleteInput, ProjectApiKeyDeletePayload,
+    ProjectApiKeyUpdate, ProjectApiKeyUpdateArguments, ProjectApiKeyUpdateInput, ProjectApiKeyUpdatePayload,
+};
+use super::graphql::queries::api_keys::{ApiKeysArguments, ApiKeysQuery};
+use super::types::{ApiKey, BranchEnvironment};
+use super::utils::{linked_project, project_metadata};
+use cynic::{http::ReqwestExt, Id, MutationBuilder, QueryBuilder};
+
+/// the API keys of the linked project, oldest first
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn list() -> Result<Vec<ApiKey>, ApiError> {
+    let client = create_client().await?;
+
+    let project = linked_project(&client).await?;
+
+    let query = ApiKeysQuery::build(ApiKeysArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let mut api_keys: Vec<ApiKey> = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_by_account_slug
+        .ok_or(ApiError::LinkedProjectDoesNotExist)?
+        .api_keys
+        .nodes
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    api_keys.sort_by(|left, right| left.created_at.cmp(&right.created_at));
+
+    Ok(api_keys)
+}
+
+/// # Errors
+///
+/// See [`ApiError`] and [`ApiKeyError`]
+pub async fn create(name: &str, environment: BranchEnvironment) -> Result<ApiKey, ApiError> {
+    let project_metadata = project_metadata().await?;
+
+    let client = create_client().await?;
+
+    let operation = ProjectApiKeyCreate::build(ProjectApiKeyCreateArguments {
+        input: ProjectApiKeyCreateInput {
+            project_id: Id::new(project_metadata.project_id),
+            environment: environment.into(),
+            name,
+        },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_api_key_create;
+
+    create_result(payload)
+}
+
+/// # Errors
+///
+/// See [`ApiError`] and [`ApiKeyError`]
+pub async fn rename(id: &str, name: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let operation = ProjectApiKeyUpdate::build(ProjectApiKeyUpdateArguments {
+        input: ProjectApiKeyUpdateInput { id: Id::new(id), name },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_api_key_update;
+
+    rename_result(id, &payload)
+}
+
+/// # Errors
+///
+/// See [`ApiError`] and [`ApiKeyError`]
+pub async fn delete(id: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let operation = ProjectApiKeyDelete::build(ProjectApiKeyDeleteArguments {
+        input: ProjectApiKeyDeleteInput { id: Id::new(id) },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_api_key_delete;
+
+    delete_result(id, &payload)
+}
+
+fn create_result(payload: ProjectApiKeyCreatePayload) -> Result<ApiKey, ApiError> {
+    match payload {
+        ProjectApiKeyCreatePayload::ProjectApiKeyCreateSuccess(payload) => Ok(payload.api_key.into()),
+        ProjectApiKeyCreatePayload::KeyLimitExceededError(_) => Err(ApiKeyError::KeyLimitExceeded.into()),
+        ProjectApiKeyCreatePayload::ProjectDoesNotExistError(_) => Err(ApiKeyError::ProjectDoesNotExist.into()),
+        ProjectApiKeyCreatePayload::Unknown => Err(ApiKeyError::Unknown.into()),
+    }
+}
+
+fn rename_result(id: &str, payload: &ProjectApiKeyUpdatePayload) -> Result<(), ApiError> {
+    match payload {
+        ProjectApiKeyUpdatePayload::ProjectApiKeyUpdateSuccess(_) => Ok(()),
+        ProjectApiKeyUpdatePayload::KeyDoesNotExistError(_) => Err(ApiKeyError::KeyDoesNotExist(id.to_owned()).into()),
+        ProjectApiKeyUpdatePayload::Unknown => Err(ApiKeyError::Unknown.into()),
+    }
+}
+
+fn delete_result(id: &str, payload: &ProjectApiKeyDeletePayload) -> Result<(), ApiError> {
+    match payload {
+        ProjectApiKeyDeletePayload::ProjectApiKeyDeleteSuccess(_) => Ok(()),
+        ProjectApiKeyDeletePayload::KeyDoesNotExistError(_) => Err(ApiKeyError::KeyDoesNotExist(id.to_owned()).into()),
+        ProjectApiKeyDeletePayload::MustLeaveAtLeastOneKeyForEnvironmentError(_) => {
+            Err(ApiKeyError::MustLeaveAtLeastOneKeyForEnvironment.into())
+        }
+        ProjectApiKeyDeletePayload::Unknown => Err(ApiKeyError::Unknown.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        create_result, delete_result, rename_result, ApiError, ApiKey, ApiKeyError, BranchEnvironment,
+        ProjectApiKeyCreate, ProjectApiKeyDelete, ProjectApiKeyUpdate,
+    };
+    use serde_json::json;
+
+    fn create(payload: &serde_json::Value) -> Result<ApiKey, ApiError> {
+        let data: ProjectApiKeyCreate = serde_json::from_value(json!({ "projectApiKeyCreate": payload })).unwrap();
+        create_result(data.project_api_key_create)
+    }
+
+    fn rename(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: ProjectApiKeyUpdate = serde_json::from_value(json!({ "projectApiKeyUpdate": payload })).unwrap();
+        rename_result("key-id", &data.project_api_key_update)
+    }
+
+    fn delete(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: ProjectApiKeyDelete = serde_json::from_value(json!({ "projectApiKeyDelete": payload })).unwrap();
+        delete_result("key-id", &data.project_api_key_delete)
+    }
+
+    #[test]
+    fn created_api_key() {
+        let api_key = create(&json!({
+            "__typename": "ProjectApiKeyCreateSuccess",
+            "apiKey": {
+                "id": "key-id",
+                "key": "secret",
+                "name": "ci",
+                "environment": "PREVIEW",
+                "createdAt": "2023-06-01T12:00:00Z"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(api_key.id, "key-id");
+        assert_eq!(api_key.key, "secret");
+        assert_eq!(api_key.name, "ci");
+        assert_eq!(api_key.environment, BranchEnvironment::Preview);
+    }
+
+    #[test]
+    fn api_key_errors() {
+        let error = create(&json!({ "__typename": "KeyLimitExceededError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::ApiKeyError(ApiKeyError::KeyLimitExceeded)),
+            "{error:?}"
+        );
+
+        let error = create(&json!({ "__typename": "ProjectDoesNotExistError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::ApiKeyError(ApiKeyError::ProjectDoesNotExist)),
+            "{error:?}"
+        );
+
+        let error = rename(&json!({ "__typename": "KeyDoesNotExistError" })).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::ApiKeyError(ApiKeyError::KeyDoesNotExist(id)) if id == "key-id"),
+            "{error:?}"
+        );
+
+        let error = delete(&json!({ "__typename": "MustLeaveAtLeastOneKeyForEnvironmentError" })).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ApiError::ApiKeyError(ApiKeyError::MustLeaveAtLeastOneKeyForEnvironment)
+            ),
+            "{error:?}"
+        );
+
+        assert!(rename(&json!({ "__typename": "ProjectApiKeyUpdateSuccess" })).is_ok());
+        assert!(delete(&json!({ "__typename": "ProjectApiKeyDeleteSuccess" })).is_ok());
+    }
+
+    #[test]
+    fn unknown_api_key_payloads() {
+        let error = delete(&json!({ "__typename": "SomeNewError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::ApiKeyError(ApiKeyError::Unknown)),
+            "{error:?}"
+        );
+    }
+}
@@ -1,19 +1,30 @@
 use super::consts::GRAFBASE_ACCESS_TOKEN_ENV_VAR;
-use super::types::Credentials;
-use super::{consts::CREDENTIALS_FILE, errors::ApiError};
+use super::errors::ApiError;
+use super::profiles;
 use crate::consts::USER_AGENT;
 use axum::http::{HeaderMap, HeaderValue};
-use common::environment::Environment;
 use reqwest::{header, Client};
 use std::env;
-use tokio::fs::read_to_string;
 
 /// # Errors
 ///
 /// See [`ApiError`]
 #[allow(clippy::module_name_repetitions)]
 pub async fn create_client() -> Result<reqwest::Client, ApiError> {
-    let token = get_access_token().await?;
+    // reading from the system keyring can block
+    let token = tokio::task::spawn_blocking(get_access_token)
+        .await
+        .expect("must not panic")?;
+    create_client_with_token(&token)
+}
+
+/// creates a client authenticated with the given access token rather than the stored one
+///
+/// # Errors
+///
+/// See [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub fn create_client_with_token(token: &str) -> Result<reqwest::Client, ApiError> {
     let mut headers = HeaderMap::new();
     let mut bearer_token =
         HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| ApiError::CorruptAccessToken)?;
@@ -29,37 +40,17 @@ pub async fn create_client() -> Result<reqwest::Client, ApiError> {
         .expect("TLS is supported in all targets"))
 }
 
-async fn get_access_token() -> Result<String, ApiError> {
-    match get_access_token_from_file().await {
+fn get_access_token() -> Result<String, ApiError> {
+    match get_stored_access_token() {
         Ok(token) => Ok(token),
         // attempt to also check GRAFBASE_ACCESS_TOKEN_ENV_VAR, returning the original error if it doesn't exist
         Err(error) => env::var(GRAFBASE_ACCESS_TOKEN_ENV_VAR).map_err(|_| error),
     }
 }
 
-async fn get_access_token_from_file() -> Result<String, ApiError> {
-    let environment = Environment::get();
-
-    match environment.user_dot_grafbase_path.try_exists() {
-        Ok(true) => {}
-        Ok(false) => return Err(ApiError::NotLoggedIn),
-        Err(error) => return Err(ApiError::ReadUserDotGrafbaseFolder(error)),
-    }
-
-    let credentials_file_path = environment.user_dot_grafbase_path.join(CREDENTIALS_FILE);
-
-    match credentials_file_path.try_exists() {
-        Ok(true) => {}
-        Ok(false) => return Err(ApiError::NotLoggedIn),
-        Err(error) => return Err(ApiError::ReadCredentialsFile(error)),
-    }
-
-    let credential_file = read_to_string(environment.user_dot_grafbase_path.join(CREDENTIALS_FILE))
-        .await
-        .map_err(ApiError::ReadCredentialsFile)?;
-
-    let credentials: Credentials<'_> =
-        serde_json::from_str(&credential_file).map_err(|_| ApiError::CorruptCredentialsFile)?;
+/// reads the access token of the current profile from wherever it is stored
+fn get_stored_access_token() -> Result<String, ApiError> {
+    let profile = profiles::current()?;
 
-    Ok(credentials.access_token.to_owned())
+    profiles::read_access_token(&profile)
 }
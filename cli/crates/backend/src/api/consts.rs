@@ -1,7 +1,23 @@
+use std::time::Duration;
+
 pub const CREDENTIALS_FILE: &str = "credentials.json";
+pub const PROFILES_FILE: &str = "profiles.json";
+pub const PROFILES_DIRECTORY: &str = "profiles";
+/// the profile whose credentials are stored directly in ~/.grafbase
+pub const DEFAULT_PROFILE: &str = "default";
+pub const GRAFBASE_PROFILE_ENV_VAR: &str = "GRAFBASE_PROFILE";
+/// the service access tokens are stored under in the system keyring, keyed by profile
+pub const KEYRING_SERVICE: &str = "grafbase";
 pub const PROJECT_METADATA_FILE: &str = "project.json";
 pub const AUTH_URL: &str = "https://grafbase.com/auth/cli";
+pub const DEVICE_AUTHORIZATION_URL: &str = "https://grafbase.com/auth/cli/device";
+pub const DEVICE_TOKEN_URL: &str = "https://grafbase.com/auth/cli/device/token";
 pub const API_URL: &str = "https://api.grafbase.com/graphql";
 pub const PACKAGE_JSON: &str = "package.json";
 pub const TAR_CONTENT_TYPE: &str = "application/x-tar";
+pub const GRAFBASE_IGNORE_FILE: &str = ".grafbaseignore";
 pub const GRAFBASE_ACCESS_TOKEN_ENV_VAR: &str = "GRAFBASE_ACCESS_TOKEN";
+/// the amount by which the polling interval of a device login is increased when asked to slow down
+pub const DEVICE_LOGIN_SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+/// the interval at which the status of a deployment is checked while following it
+pub const DEPLOYMENT_POLL_INTERVAL: Duration = Duration::from_secs(2);
@@ -114,7 +114,7 @@ pub async fn create(
             .await
             .map_err(ApiError::WriteProjectMetadataFile)?;
 
-            deploy::deploy().await?;
+            deploy::deploy(None).await?;
 
             Ok(project_create_success.project.production_branch.domains)
         }
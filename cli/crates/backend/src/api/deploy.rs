@@ -1,61 +1,52 @@
 use super::client::create_client;
-use super::consts::{API_URL, PACKAGE_JSON, PROJECT_METADATA_FILE, TAR_CONTENT_TYPE};
+use super::consts::{API_URL, DEPLOYMENT_POLL_INTERVAL, GRAFBASE_IGNORE_FILE, PACKAGE_JSON, TAR_CONTENT_TYPE};
 use super::errors::{ApiError, DeployError};
 use super::graphql::mutations::{
     ArchiveFileSizeLimitExceededError, DailyDeploymentCountLimitExceededError, DeploymentCreate,
     DeploymentCreateArguments, DeploymentCreateInput, DeploymentCreatePayload,
 };
-use super::types::ProjectMetadata;
+use super::graphql::queries::deployment::{DeploymentArguments, DeploymentQuery, DeploymentStatus};
+use super::types::{ArchiveFile, ArchiveSummary, DeploymentLogEntry};
+use super::utils::project_metadata;
 use crate::consts::USER_AGENT;
 use common::consts::GRAFBASE_DIRECTORY_NAME;
 use common::environment::Project;
 use cynic::http::ReqwestExt;
-use cynic::{Id, MutationBuilder};
+use cynic::{Id, MutationBuilder, QueryBuilder};
+use ignore::WalkBuilder;
 use reqwest::{header, Body, Client};
-use tokio::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use tempfile::TempPath;
 use tokio_util::codec::{BytesCodec, FramedRead};
 use tokio_util::compat::TokioAsyncReadCompatExt;
 
+/// builds the archive [`deploy`] would upload without uploading it, listing its files
+///
 /// # Errors
 ///
 /// See [`ApiError`]
-pub async fn deploy() -> Result<(), ApiError> {
-    let project = Project::get();
-
-    let project_metadata_file_path = project.dot_grafbase_directory_path.join(PROJECT_METADATA_FILE);
-
-    match project_metadata_file_path.try_exists() {
-        Ok(true) => {}
-        Ok(false) => return Err(ApiError::UnlinkedProject),
-        Err(error) => return Err(ApiError::ReadProjectMetadataFile(error)),
-    }
-
-    let project_metadata_file = read_to_string(project_metadata_file_path)
-        .await
-        .map_err(ApiError::ReadProjectMetadataFile)?;
-
-    let project_metadata: ProjectMetadata =
-        serde_json::from_str(&project_metadata_file).map_err(|_| ApiError::CorruptProjectMetadataFile)?;
-
-    let (tar_file, tar_file_path) = tempfile::NamedTempFile::new()
-        .map_err(ApiError::CreateTempFile)?
-        .into_parts();
-
-    let tar_file: tokio::fs::File = tar_file.into();
-    let tar_file = tar_file.compat();
+pub async fn dry_run() -> Result<ArchiveSummary, ApiError> {
+    let (_, summary) = build_archive().await?;
+    Ok(summary)
+}
 
-    let mut tar = async_tar::Builder::new(tar_file);
-    tar.mode(async_tar::HeaderMode::Deterministic);
+/// uploads the project to the given branch, or to the production branch if none is given,
+/// returning the ID of the created deployment.
+/// the size limit of the archive is not known ahead of time, it is only checked by the API
+///
+/// # Errors
+///
+/// returns [`DeployError::ArchiveFileSizeLimitExceeded`] if the API rejects the archive as too large
+///
+/// See [`ApiError`]
+pub async fn deploy(branch: Option<&str>) -> Result<String, ApiError> {
+    let project_metadata = project_metadata().await?;
 
-    if project.path.join(PACKAGE_JSON).exists() {
-        tar.append_path_with_name(project.path.join(PACKAGE_JSON), PACKAGE_JSON)
-            .await
-            .map_err(ApiError::AppendToArchive)?;
-    }
+    let (tar_file_path, summary) = build_archive().await?;
 
-    tar.append_dir_all(GRAFBASE_DIRECTORY_NAME, &project.grafbase_directory_path)
-        .await
-        .map_err(ApiError::AppendToArchive)?;
+    // the size is sent as a GraphQL `Int`, anything larger could never be accepted
+    let archive_file_size =
+        i32::try_from(summary.size).map_err(|_| DeployError::ArchiveFileSizeLimitExceeded { limit: i32::MAX })?;
 
     let tar_file = tokio::fs::File::open(&tar_file_path)
         .await
@@ -63,13 +54,10 @@ pub async fn deploy() -> Result<(), ApiError> {
 
     let client = create_client().await?;
 
-    #[allow(clippy::cast_possible_truncation)] // must fit or will be over allowed limit
-    let archive_file_size = tar_file.metadata().await.map_err(ApiError::ReadArchiveMetadata)?.len() as i32;
-
     let operation = DeploymentCreate::build(DeploymentCreateArguments {
         input: DeploymentCreateInput {
             archive_file_size,
-            branch: None,
+            branch,
             project_id: Id::new(project_metadata.project_id),
         },
     });
@@ -98,7 +86,7 @@ pub async fn deploy() -> Result<(), ApiError> {
                 return Err(ApiError::UploadError);
             }
 
-            Ok(())
+            Ok(payload.deployment.id.into_inner())
         }
         DeploymentCreatePayload::ProjectDoesNotExistError(_) => Err(DeployError::ProjectDoesNotExist.into()),
         DeploymentCreatePayload::ArchiveFileSizeLimitExceededError(ArchiveFileSizeLimitExceededError {
@@ -111,3 +99,121 @@ pub async fn deploy() -> Result<(), ApiError> {
         DeploymentCreatePayload::Unknown => Err(DeployError::Unknown.into()),
     }
 }
+
+/// polls a deployment until it completes, passing new log entries to `on_log_entry` as they come in
+///
+/// # Errors
+///
+/// returns [`DeployError::DeploymentFailed`] if the deployment fails
+///
+/// See [`ApiError`]
+pub async fn follow_deployment(
+    deployment_id: &str,
+    mut on_log_entry: impl FnMut(DeploymentLogEntry),
+) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let mut reported_entries = 0;
+
+    loop {
+        let query = DeploymentQuery::build(DeploymentArguments {
+            id: Id::new(deployment_id),
+        });
+
+        let response = client.post(API_URL).run_graphql(query).await?;
+
+        let deployment = response
+            .data
+            .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+            .deployment
+            .ok_or(DeployError::DeploymentDoesNotExist)?;
+
+        // log entries are only ever appended to
+        for entry in deployment.log_entries.into_iter().skip(reported_entries) {
+            on_log_entry(entry.into());
+            reported_entries += 1;
+        }
+
+        match deployment.status {
+            DeploymentStatus::Queued | DeploymentStatus::InProgress => {}
+            DeploymentStatus::Succeeded => return Ok(()),
+            DeploymentStatus::Failed => return Err(DeployError::DeploymentFailed.into()),
+        }
+
+        tokio::time::sleep(DEPLOYMENT_POLL_INTERVAL).await;
+    }
+}
+
+/// builds a deterministic archive of `package.json` and the grafbase directory in a temporary file,
+/// leaving out the paths matched by `.grafbaseignore` files
+async fn build_archive() -> Result<(TempPath, ArchiveSummary), ApiError> {
+    let project = Project::get();
+
+    let (tar_file, tar_file_path) = tempfile::NamedTempFile::new()
+        .map_err(ApiError::CreateTempFile)?
+        .into_parts();
+
+    let tar_file: tokio::fs::File = tar_file.into();
+    let tar_file = tar_file.compat();
+
+    let mut tar = async_tar::Builder::new(tar_file);
+    tar.mode(async_tar::HeaderMode::Deterministic);
+
+    let mut files = Vec::new();
+
+    let package_json_path = project.path.join(PACKAGE_JSON);
+
+    if package_json_path.exists() {
+        tar.append_path_with_name(&package_json_path, PACKAGE_JSON)
+            .await
+            .map_err(ApiError::AppendToArchive)?;
+
+        files.push(ArchiveFile {
+            path: PathBuf::from(PACKAGE_JSON),
+            size: package_json_path.metadata().map_err(ApiError::AppendToArchive)?.len(),
+        });
+    }
+
+    let walker = WalkBuilder::new(&project.grafbase_directory_path)
+        .standard_filters(false)
+        // also reads a `.grafbaseignore` in the project directory
+        .parents(true)
+        .add_custom_ignore_filename(GRAFBASE_IGNORE_FILE)
+        .sort_by_file_name(Ord::cmp)
+        .build();
+
+    for entry in walker {
+        let entry = entry.map_err(ApiError::ReadProjectFiles)?;
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(&project.grafbase_directory_path)
+            .expect("must be within the grafbase directory");
+
+        let archive_path = Path::new(GRAFBASE_DIRECTORY_NAME).join(relative_path);
+
+        if entry.file_type().map_or(false, |file_type| file_type.is_dir()) {
+            tar.append_dir(&archive_path, entry.path())
+                .await
+                .map_err(ApiError::AppendToArchive)?;
+        } else {
+            tar.append_path_with_name(entry.path(), &archive_path)
+                .await
+                .map_err(ApiError::AppendToArchive)?;
+
+            files.push(ArchiveFile {
+                path: archive_path,
+                size: entry.metadata().map_err(ApiError::ReadProjectFiles)?.len(),
+            });
+        }
+    }
+
+    tar.into_inner().await.map_err(ApiError::AppendToArchive)?;
+
+    let size = tokio::fs::metadata(&tar_file_path)
+        .await
+        .map_err(ApiError::ReadArchiveMetadata)?
+        .len();
+
+    Ok((tar_file_path, ArchiveSummary { files, size }))
+}
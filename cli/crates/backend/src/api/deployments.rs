@@ -0,0 +1,106 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::{ApiError, DeployError};
+use super::graphql::queries::deployment::{DeploymentArguments, DeploymentDiffQuery, DeploymentQuery};
+use super::graphql::queries::deployments::{DeploymentFilter, DeploymentsArguments, DeploymentsQuery};
+use super::types::{Deployment, DeploymentDiffBase, DeploymentLogEntry, DeploymentStatus};
+use super::utils::linked_project;
+use cynic::{http::ReqwestExt, Id, QueryBuilder};
+
+/// lists the most recent deployments of the linked project, newest first
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn list(
+    branch: Option<&str>,
+    statuses: &[DeploymentStatus],
+    limit: i32,
+) -> Result<Vec<Deployment>, ApiError> {
+    let client = create_client().await?;
+
+    let project = linked_project(&client).await?;
+
+    let statuses: Vec<_> = statuses.iter().copied().map(Into::into).collect();
+
+    let query = DeploymentsQuery::build(DeploymentsArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+        first: limit,
+        filter: DeploymentFilter { branch, statuses },
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let mut deployments: Vec<Deployment> = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_by_account_slug
+        .ok_or(ApiError::LinkedProjectDoesNotExist)?
+        .deployments
+        .nodes
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    deployments.sort_by(|left, right| right.created_at.cmp(&left.created_at));
+
+    Ok(deployments)
+}
+
+/// the status and log entries of a deployment
+///
+/// # Errors
+///
+/// returns [`DeployError::DeploymentDoesNotExist`] if there is no deployment with the given ID
+///
+/// See [`ApiError`]
+pub async fn logs(deployment_id: &str) -> Result<(DeploymentStatus, Vec<DeploymentLogEntry>), ApiError> {
+    let client = create_client().await?;
+
+    let query = DeploymentQuery::build(DeploymentArguments {
+        id: Id::new(deployment_id),
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let deployment = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .deployment
+        .ok_or(DeployError::DeploymentDoesNotExist)?;
+
+    Ok((
+        deployment.status.into(),
+        deployment.log_entries.into_iter().map(Into::into).collect(),
+    ))
+}
+
+/// the diff of the schema of a deployment against an earlier deployment,
+/// `None` if there is no earlier deployment to compare to
+///
+/// # Errors
+///
+/// returns [`DeployError::DeploymentDoesNotExist`] if there is no deployment with the given ID
+///
+/// See [`ApiError`]
+pub async fn diff(deployment_id: &str, base: DeploymentDiffBase) -> Result<Option<String>, ApiError> {
+    let client = create_client().await?;
+
+    let query = DeploymentDiffQuery::build(DeploymentArguments {
+        id: Id::new(deployment_id),
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let deployment = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .deployment
+        .ok_or(DeployError::DeploymentDoesNotExist)?;
+
+    Ok(match base {
+        DeploymentDiffBase::PreviousBranchDeployment => deployment.diff_against_previous_branch_deployment,
+        DeploymentDiffBase::LatestProductionDeployment => deployment.diff_against_latest_production_deployment,
+    })
+}
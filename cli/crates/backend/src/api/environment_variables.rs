@@ -0,0 +1,476 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::{ApiError, EnvironmentVariableError};
+use super::graphql::mutations::{
+    EnvironmentVariableCreate, EnvironmentVariableCreateArguments, EnvironmentVariableCreateInput,
+    EnvironmentVariableCreatePayload, EnvironmentVariableDelete, EnvironmentVariableDeleteArguments,
+    EnvironmentVariableDeleteInput, EnvironmentVariableDeletePayload, EnvironmentVariableUpdate,
+    EnvironmentVariableUpdateArguments, EnvironmentVariableUpdateInput, EnvironmentVariableUpdatePayload,
+};
+use super::graphql::queries::environment_variables::{EnvironmentVariablesArguments, EnvironmentVariablesQuery};
+use super::types::{BranchEnvironment, EnvironmentVariable, EnvironmentVariableChange};
+use super::utils::{linked_project, project_metadata};
+use cynic::{http::ReqwestExt, Id, MutationBuilder, QueryBuilder};
+use reqwest::Client;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// the remote environment variables of the linked project
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn list() -> Result<Vec<EnvironmentVariable>, ApiError> {
+    let client = create_client().await?;
+    list_with_client(&client).await
+}
+
+/// creates or updates the remote environment variable with the given name.
+/// `environments` defaults to every environment for new variables and is left as is for existing ones
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`EnvironmentVariableError`]
+pub async fn set(name: &str, value: &str, environments: Option<&[BranchEnvironment]>) -> Result<(), ApiError> {
+    let client = create_client().await?;
+    let variables = list_with_client(&client).await?;
+    set_with_client(&client, &variables, name, value, environments).await
+}
+
+/// deletes the remote environment variable with the given name
+///
+/// # Errors
+///
+/// returns [`EnvironmentVariableError::DoesNotExist`] if there is no variable with the given name
+///
+/// See [`ApiError`]
+pub async fn unset(name: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let variables = list_with_client(&client).await?;
+
+    let variable =
+        find_variable(&variables, name, None).ok_or_else(|| EnvironmentVariableError::DoesNotExist(name.to_owned()))?;
+
+    let operation = EnvironmentVariableDelete::build(EnvironmentVariableDeleteArguments {
+        input: EnvironmentVariableDeleteInput {
+            id: Id::new(&variable.id),
+        },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .environment_variable_delete;
+
+    match payload {
+        EnvironmentVariableDeletePayload::EnvironmentVariableDeleteSuccess(_) => Ok(()),
+        EnvironmentVariableDeletePayload::EnvironmentVariableDoesNotExistError(_) => {
+            Err(EnvironmentVariableError::DoesNotExist(name.to_owned()).into())
+        }
+        EnvironmentVariableDeletePayload::Unknown => Err(EnvironmentVariableError::Unknown.into()),
+    }
+}
+
+/// the changes pulling the remote variables of an environment would make to a local .env file
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`EnvironmentVariableError`]
+pub async fn pull_changes(
+    environment: BranchEnvironment,
+    dot_env_file_path: &Path,
+) -> Result<Vec<EnvironmentVariableChange>, ApiError> {
+    let remote = list().await?;
+
+    let local = if dot_env_file_path.exists() {
+        read_dot_env_file(dot_env_file_path)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(changes(
+        remote
+            .into_iter()
+            .filter(|variable| variable.environments.contains(&environment))
+            .map(|variable| (variable.name, variable.value)),
+        |name| {
+            local
+                .iter()
+                .find(|(local_name, _)| local_name == name)
+                .map(|(_, value)| value.as_str())
+        },
+    ))
+}
+
+/// writes pulled variables to a local .env file, replacing the values of variables already in the file
+/// and keeping the rest of its contents
+///
+/// # Errors
+///
+/// returns [`EnvironmentVariableError::WriteDotEnvFile`] if the file could not be read or written
+pub async fn pull(dot_env_file_path: &Path, changes: &[EnvironmentVariableChange]) -> Result<(), ApiError> {
+    let contents = match tokio::fs::read_to_string(dot_env_file_path).await {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+        Err(error) => {
+            return Err(EnvironmentVariableError::WriteDotEnvFile(dot_env_file_path.to_owned(), error).into())
+        }
+    };
+
+    let mut lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            line_variable_name(line)
+                .and_then(|name| changes.iter().find(|change| change.name() == name))
+                .map_or_else(|| line.to_owned(), dot_env_line)
+        })
+        .collect();
+
+    lines.extend(
+        changes
+            .iter()
+            .filter(|change| matches!(change, EnvironmentVariableChange::Added { .. }))
+            .map(dot_env_line),
+    );
+
+    tokio::fs::write(dot_env_file_path, lines.join("\n") + "\n")
+        .await
+        .map_err(|error| EnvironmentVariableError::WriteDotEnvFile(dot_env_file_path.to_owned(), error).into())
+}
+
+/// the changes pushing the variables of a local .env file would make to the remote variables
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`EnvironmentVariableError`]
+pub async fn push_changes(
+    environments: Option<&[BranchEnvironment]>,
+    dot_env_file_path: &Path,
+) -> Result<Vec<EnvironmentVariableChange>, ApiError> {
+    let local = read_dot_env_file(dot_env_file_path)?;
+    let remote = list().await?;
+
+    Ok(changes(local, |name| {
+        find_variable(&remote, name, environments).map(|variable| variable.value.as_str())
+    }))
+}
+
+/// uploads pushed variables, see [`set`]
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`EnvironmentVariableError`]
+pub async fn push(
+    environments: Option<&[BranchEnvironment]>,
+    changes: &[EnvironmentVariableChange],
+) -> Result<(), ApiError> {
+    let client = create_client().await?;
+    let variables = list_with_client(&client).await?;
+
+    for change in changes {
+        set_with_client(&client, &variables, change.name(), change.value(), environments).await?;
+    }
+
+    Ok(())
+}
+
+async fn list_with_client(client: &Client) -> Result<Vec<EnvironmentVariable>, ApiError> {
+    let project = linked_project(client).await?;
+
+    let query = EnvironmentVariablesQuery::build(EnvironmentVariablesArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let mut variables: Vec<EnvironmentVariable> = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_by_account_slug
+        .ok_or(ApiError::LinkedProjectDoesNotExist)?
+        .environment_variables
+        .nodes
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    variables.sort_by(|left, right| left.name.cmp(&right.name));
+
+    Ok(variables)
+}
+
+async fn set_with_client(
+    client: &Client,
+    variables: &[EnvironmentVariable],
+    name: &str,
+    value: &str,
+    environments: Option<&[BranchEnvironment]>,
+) -> Result<(), ApiError> {
+    let environments: Option<Vec<_>> =
+        environments.map(|environments| environments.iter().copied().map(Into::into).collect());
+
+    let Some(variable) = find_variable(variables, name, None) else {
+        let project_metadata = project_metadata().await?;
+
+        let environments =
+            environments.unwrap_or_else(|| BranchEnvironment::ALL.into_iter().map(Into::into).collect());
+
+        let operation = EnvironmentVariableCreate::build(EnvironmentVariableCreateArguments {
+            input: EnvironmentVariableCreateInput {
+                project_id: Id::new(project_metadata.project_id),
+                name,
+                value,
+                environments,
+            },
+        });
+
+        let response = client.post(API_URL).run_graphql(operation).await?;
+
+        let payload = response
+            .data
+            .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+            .environment_variable_create;
+
+        return match payload {
+            EnvironmentVariableCreatePayload::EnvironmentVariableCreateSuccess(_) => Ok(()),
+            EnvironmentVariableCreatePayload::NameAlreadyExistsError(_) => {
+                Err(EnvironmentVariableError::NameAlreadyExists(name.to_owned()).into())
+            }
+            EnvironmentVariableCreatePayload::NameTooLongError(_) => {
+                Err(EnvironmentVariableError::NameTooLong(name.to_owned()).into())
+            }
+            EnvironmentVariableCreatePayload::NameContainsInvalidCharactersError(_) => {
+                Err(EnvironmentVariableError::NameContainsInvalidCharacters(name.to_owned()).into())
+            }
+            EnvironmentVariableCreatePayload::ValueTooLongError(_) => {
+                Err(EnvironmentVariableError::ValueTooLong(name.to_owned()).into())
+            }
+            EnvironmentVariableCreatePayload::ReservedPrefixError(_) => {
+                Err(EnvironmentVariableError::ReservedPrefix(name.to_owned()).into())
+            }
+            EnvironmentVariableCreatePayload::ProjectDoesNotExistError(_) => {
+                Err(EnvironmentVariableError::ProjectDoesNotExist.into())
+            }
+            EnvironmentVariableCreatePayload::Unknown => Err(EnvironmentVariableError::Unknown.into()),
+        };
+    };
+
+    let operation = EnvironmentVariableUpdate::build(EnvironmentVariableUpdateArguments {
+        input: EnvironmentVariableUpdateInput {
+            id: Id::new(&variable.id),
+            value: Some(value),
+            environments,
+        },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .environment_variable_update;
+
+    match payload {
+        EnvironmentVariableUpdatePayload::EnvironmentVariableUpdateSuccess(_) => Ok(()),
+        EnvironmentVariableUpdatePayload::EnvironmentVariableDoesNotExistError(_) => {
+            Err(EnvironmentVariableError::DoesNotExist(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::NameAlreadyExistsError(_) => {
+            Err(EnvironmentVariableError::NameAlreadyExists(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::NameTooLongError(_) => {
+            Err(EnvironmentVariableError::NameTooLong(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::NameContainsInvalidCharactersError(_) => {
+            Err(EnvironmentVariableError::NameContainsInvalidCharacters(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::ValueTooLongError(_) => {
+            Err(EnvironmentVariableError::ValueTooLong(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::ReservedPrefixError(_) => {
+            Err(EnvironmentVariableError::ReservedPrefix(name.to_owned()).into())
+        }
+        EnvironmentVariableUpdatePayload::Unknown => Err(EnvironmentVariableError::Unknown.into()),
+    }
+}
+
+/// the variable with the given name, preferring one set in any of the given environments
+fn find_variable<'a>(
+    variables: &'a [EnvironmentVariable],
+    name: &str,
+    environments: Option<&[BranchEnvironment]>,
+) -> Option<&'a EnvironmentVariable> {
+    let mut named = variables.iter().filter(|variable| variable.name == name);
+
+    match environments {
+        Some(environments) => named
+            .clone()
+            .find(|variable| {
+                variable
+                    .environments
+                    .iter()
+                    .any(|environment| environments.contains(environment))
+            })
+            .or_else(|| named.next()),
+        None => named.next(),
+    }
+}
+
+fn changes<'a>(
+    incoming: impl IntoIterator<Item = (String, String)>,
+    current: impl Fn(&str) -> Option<&'a str>,
+) -> Vec<EnvironmentVariableChange> {
+    incoming
+        .into_iter()
+        .filter_map(|(name, value)| match current(&name) {
+            None => Some(EnvironmentVariableChange::Added { name, value }),
+            Some(current) if current != value => Some(EnvironmentVariableChange::Changed {
+                name,
+                from: current.to_owned(),
+                to: value,
+            }),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+#[allow(deprecated)] // https://github.com/dotenv-rs/dotenv/pull/54
+fn read_dot_env_file(path: &Path) -> Result<Vec<(String, String)>, EnvironmentVariableError> {
+    dotenv::from_path_iter(path)
+        .and_then(Iterator::collect)
+        .map_err(|error| EnvironmentVariableError::ReadDotEnvFile(path.to_owned(), error))
+}
+
+/// the name of the variable set by a line of a .env file, if any
+fn line_variable_name(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    let (name, _) = line.split_once('=')?;
+    let name = name.trim();
+    (!name.is_empty() && !name.starts_with('#')).then_some(name)
+}
+
+fn dot_env_line(change: &EnvironmentVariableChange) -> String {
+    let value = change
+        .value()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('\n', "\\n");
+    format!("{}=\"{value}\"", change.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changes, find_variable, line_variable_name, pull, read_dot_env_file};
+    use crate::api::types::{BranchEnvironment, EnvironmentVariable, EnvironmentVariableChange};
+
+    fn variable(id: &str, name: &str, value: &str, environments: &[BranchEnvironment]) -> EnvironmentVariable {
+        EnvironmentVariable {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            value: value.to_owned(),
+            environments: environments.to_vec(),
+        }
+    }
+
+    fn incoming(variables: &[(&str, &str)]) -> Vec<(String, String)> {
+        variables
+            .iter()
+            .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn environment_variable_changes() {
+        let current = [("UNCHANGED", "same"), ("CHANGED", "old"), ("ONLY_CURRENT", "kept")];
+
+        let changes = changes(
+            incoming(&[("UNCHANGED", "same"), ("CHANGED", "new"), ("ADDED", "value")]),
+            |name| {
+                current
+                    .iter()
+                    .find(|(current_name, _)| *current_name == name)
+                    .map(|(_, value)| *value)
+            },
+        );
+
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(
+            &changes[0],
+            EnvironmentVariableChange::Changed { name, from, to } if name == "CHANGED" && from == "old" && to == "new"
+        ));
+        assert!(matches!(
+            &changes[1],
+            EnvironmentVariableChange::Added { name, value } if name == "ADDED" && value == "value"
+        ));
+    }
+
+    #[test]
+    fn find_variable_prefers_environments() {
+        let variables = [
+            variable("1", "API_URL", "production", &[BranchEnvironment::Production]),
+            variable("2", "API_URL", "preview", &[BranchEnvironment::Preview]),
+        ];
+
+        let found = |environments: Option<&[BranchEnvironment]>| {
+            find_variable(&variables, "API_URL", environments).map(|variable| variable.id.as_str())
+        };
+
+        assert_eq!(found(None), Some("1"));
+        assert_eq!(found(Some(&[BranchEnvironment::Preview])), Some("2"));
+        assert_eq!(found(Some(&[BranchEnvironment::Production])), Some("1"));
+        assert!(find_variable(&variables, "MISSING", None).is_none());
+    }
+
+    #[test]
+    fn dot_env_line_variable_names() {
+        assert_eq!(line_variable_name("NAME=value"), Some("NAME"));
+        assert_eq!(line_variable_name("  export NAME = value"), Some("NAME"));
+        assert_eq!(line_variable_name("# NAME=value"), None);
+        assert_eq!(line_variable_name("=value"), None);
+        assert_eq!(line_variable_name(""), None);
+    }
+
+    #[tokio::test]
+    async fn pull_into_dot_env_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join(".env");
+
+        std::fs::write(&path, "# comment\nKEPT=kept\nexport CHANGED=old\n").unwrap();
+
+        let changes = [
+            EnvironmentVariableChange::Changed {
+                name: "CHANGED".to_owned(),
+                from: "old".to_owned(),
+                to: "new".to_owned(),
+            },
+            EnvironmentVariableChange::Added {
+                name: "ADDED".to_owned(),
+                value: "quoted \"value\" with $dollar, \\backslash\nand a newline".to_owned(),
+            },
+        ];
+
+        pull(&path, &changes).await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            contents.starts_with("# comment\nKEPT=kept\nCHANGED=\"new\"\n"),
+            "{contents}"
+        );
+
+        // the written values are read back as they were pulled
+        assert_eq!(
+            read_dot_env_file(&path).unwrap(),
+            incoming(&[
+                ("KEPT", "kept"),
+                ("CHANGED", "new"),
+                ("ADDED", "quoted \"value\" with $dollar, \\backslash\nand a newline"),
+            ])
+        );
+    }
+}
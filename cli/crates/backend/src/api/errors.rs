@@ -40,6 +40,44 @@ pub enum ApiError {
     #[error("could not complete the action as this project has not been linked")]
     UnlinkedProject,
 
+    /// returned if an account with the given slug could not be found
+    #[error("could not find an account with the slug '{0}'")]
+    AccountDoesNotExist(String),
+
+    /// returned if a project with the given slug or ID could not be found
+    #[error("could not find the project '{0}'")]
+    ProjectDoesNotExist(String),
+
+    /// returned if the remote project the local project is linked to could not be found
+    #[error("could not find the remote project this project is linked to")]
+    LinkedProjectDoesNotExist,
+
+    /// returned if a profile name contains characters other than alphanumerics, dashes and underscores
+    #[error(
+        "could not use the profile '{0}' as profile names may only contain letters, numbers, dashes and underscores"
+    )]
+    InvalidProfileName(String),
+
+    /// returned if ~/.grafbase/profiles.json could not be read
+    #[error("could not read '~/.grafbase/profiles.json'\ncaused by: {0}")]
+    ReadProfilesFile(io::Error),
+
+    /// returned if ~/.grafbase/profiles.json could not be written
+    #[error("could not write '~/.grafbase/profiles.json'\ncaused by: {0}")]
+    WriteProfilesFile(io::Error),
+
+    /// returned if the contents of ~/.grafbase/profiles.json are corrupt
+    #[error("could not complete the action as '~/.grafbase/profiles.json' is corrupt")]
+    CorruptProfilesFile,
+
+    /// returned if ~/.grafbase/profiles could not be read
+    #[error("could not read '~/.grafbase/profiles'\ncaused by: {0}")]
+    ReadProfilesFolder(io::Error),
+
+    /// returned if the system keyring could not be accessed
+    #[error("could not access the system keyring\ncaused by: {0}")]
+    Keyring(keyring::Error),
+
     /// returned if the contents of the credential file are corrupt
     #[error("could not complete the action as your credential file is corrupt")]
     CorruptCredentialsFile,
@@ -112,10 +150,18 @@ pub enum ApiError {
     #[error("could not append a file or directory to the upload archive\ncaused by: {0}")]
     AppendToArchive(io::Error),
 
+    /// returned if the files of the project could not be listed for the upload archive
+    #[error("could not read the project files\ncaused by: {0}")]
+    ReadProjectFiles(ignore::Error),
+
     /// returned if a temporary file for the upload archive could not be created
     #[error("could not create a temporary file\ncaused by: {0}")]
     CreateTempFile(io::Error),
 
+    /// wraps a [`LoginApiError`]
+    #[error(transparent)]
+    LoginApiError(#[from] LoginApiError),
+
     /// wraps a [`CreateError`]
     #[error(transparent)]
     CreateError(#[from] CreateError),
@@ -123,6 +169,18 @@ pub enum ApiError {
     /// wraps a [`DeployError`]
     #[error(transparent)]
     DeployError(#[from] DeployError),
+
+    /// wraps an [`EnvironmentVariableError`]
+    #[error(transparent)]
+    EnvironmentVariableError(#[from] EnvironmentVariableError),
+
+    /// wraps an [`ApiKeyError`]
+    #[error(transparent)]
+    ApiKeyError(#[from] ApiKeyError),
+
+    /// wraps an [`OrganizationError`]
+    #[error(transparent)]
+    OrganizationError(#[from] OrganizationError),
 }
 
 #[derive(Error, Debug)]
@@ -178,15 +236,174 @@ pub enum DeployError {
     #[error("could not deploy as you have reached the allowed daily deployemnt amount of {limit}")]
     DailyDeploymentCountLimitExceeded { limit: i32 },
 
+    /// returned if a deployment with the given ID could not be found
+    #[error("could not find a deployment with the given ID")]
+    DeploymentDoesNotExist,
+
+    /// returned if the deployment failed after the archive was uploaded
+    #[error("the deployment failed")]
+    DeploymentFailed,
+
     /// returned if an unknown error occurs
     #[error("could not deploy, encountered an unknown error")]
     Unknown,
 }
 
+#[derive(Error, Debug)]
+pub enum EnvironmentVariableError {
+    /// returned if a variable with the given name already exists
+    #[error("could not save the environment variable as a variable named '{0}' already exists")]
+    NameAlreadyExists(String),
+
+    /// returned if the given name is too long
+    #[error("could not save the environment variable as the name '{0}' is too long")]
+    NameTooLong(String),
+
+    /// returned if the given name contains characters other than letters, digits and underscores
+    #[error("could not save the environment variable as the name '{0}' contains invalid characters")]
+    NameContainsInvalidCharacters(String),
+
+    /// returned if the given value is too long
+    #[error("could not save the environment variable '{0}' as its value is too long")]
+    ValueTooLong(String),
+
+    /// returned if the given name starts with a prefix reserved by Grafbase
+    #[error("could not save the environment variable as the name '{0}' starts with a reserved prefix")]
+    ReservedPrefix(String),
+
+    /// returned if the linked project does not exist
+    #[error("could not save the environment variable as the linked project does not exist")]
+    ProjectDoesNotExist,
+
+    /// returned if no variable with the given name exists
+    #[error("could not find an environment variable named '{0}'")]
+    DoesNotExist(String),
+
+    /// returned if a local .env file could not be read
+    #[error("could not read '{0}'\ncaused by: {1}")]
+    ReadDotEnvFile(PathBuf, dotenv::Error),
+
+    /// returned if a local .env file could not be written
+    #[error("could not write '{0}'\ncaused by: {1}")]
+    WriteDotEnvFile(PathBuf, io::Error),
+
+    /// returned if an unknown error occurs
+    #[error("could not save the environment variable, encountered an unknown error")]
+    Unknown,
+}
+
+#[derive(Error, Debug)]
+pub enum ApiKeyError {
+    /// returned if the project already has the maximum amount of API keys
+    #[error("could not create the API key as the project has reached the limit of API keys")]
+    KeyLimitExceeded,
+
+    /// returned if the linked project does not exist
+    #[error("could not create the API key as the linked project does not exist")]
+    ProjectDoesNotExist,
+
+    /// returned if no API key with the given ID exists
+    #[error("could not find an API key with the ID '{0}'")]
+    KeyDoesNotExist(String),
+
+    /// returned if the API key to delete is the last one of its environment
+    #[error("could not delete the API key as it is the last one of its environment")]
+    MustLeaveAtLeastOneKeyForEnvironment,
+
+    /// returned if an unknown error occurs
+    #[error("could not complete the action on the API key, encountered an unknown error")]
+    Unknown,
+}
+
+#[derive(Error, Debug)]
+pub enum OrganizationError {
+    /// returned if the user is not a member of an organization with the given slug
+    #[error("could not find an organization with the slug '{0}' that you are a member of")]
+    OrganizationDoesNotExist(String),
+
+    /// returned if the given slug for a new organization is invalid
+    #[error("could not create the organization as the provided slug is invalid: {0}")]
+    SlugInvalid(String),
+
+    /// returned if the given slug for a new organization is too long
+    #[error("could not create the organization as the provided slug is longer than {max_length} characters")]
+    SlugTooLong { max_length: i32 },
+
+    /// returned if the given slug for a new organization is reserved
+    #[error("could not create the organization as the provided slug is reserved")]
+    SlugReserved,
+
+    /// returned if the given slug for a new organization is already in use
+    #[error("could not create the organization as the provided slug is already in use")]
+    SlugAlreadyExists,
+
+    /// returned if the given name for a new organization is too long
+    #[error("could not create the organization as the provided name is longer than {max_length} characters")]
+    NameTooLong { max_length: i32 },
+
+    /// returned if the user is not allowed to send invites for an organization
+    #[error("could not send the invite as you are not allowed to invite members to the organization")]
+    NotAllowedToSendInvites,
+
+    /// returned if the user is not allowed to cancel invites of an organization
+    #[error("could not cancel the invite as you are not allowed to cancel invites of the organization")]
+    NotAllowedToCancelInvites,
+
+    /// returned if an invite does not exist or is no longer valid
+    #[error("could not find the invite '{0}'")]
+    InviteDoesNotExist(String),
+
+    /// returned if the user accepting an invite is already a member of the organization
+    #[error("could not accept the invite as you are already a member of the organization")]
+    AlreadyMember,
+
+    /// returned if an organization has no member with the given email address
+    #[error("could not find a member with the email address '{0}'")]
+    MemberDoesNotExist(String),
+
+    /// returned if an unknown error occurs
+    #[error("could not complete the action on the organization, encountered an unknown error")]
+    Unknown,
+}
+
 #[derive(Error, Debug)]
 pub enum LoginApiError {
     #[error("could not write '{0}'")]
     WriteCredentialFile(PathBuf),
+
+    /// returned if the access token could not be stored in the system keyring
+    #[error("could not store the access token in the system keyring\ncaused by: {0}")]
+    WriteKeyring(keyring::Error),
+
+    /// returned if no access token was provided when logging in with a token
+    #[error("could not log in as no access token was provided")]
+    EmptyAccessToken,
+
+    /// returned if the API does not accept the access token provided when logging in with a token
+    #[error("could not log in as the provided access token is invalid")]
+    InvalidAccessToken,
+
+    /// returned if the device code expires before the login is confirmed in the browser
+    #[error("could not log in as the device code expired before the login was confirmed")]
+    DeviceCodeExpired,
+
+    /// returned if the login is denied in the browser
+    #[error("could not log in as the login was denied")]
+    DeviceLoginDenied,
+
+    /// returned if the API responds to a device login in an unexpected way
+    #[error("could not log in, encountered an unknown error")]
+    Unknown,
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_connect() {
+            ApiError::ConnectionError
+        } else {
+            ApiError::RequestError
+        }
+    }
 }
 
 impl From<CynicReqwestError> for ApiError {
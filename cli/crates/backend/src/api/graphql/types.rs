@@ -1,5 +1,6 @@
 pub mod mutations {
     use super::schema;
+    use chrono::{DateTime, Utc};
 
     #[derive(cynic::InputObject, Clone, Debug)]
     pub struct ProjectCreateInput<'a> {
@@ -51,8 +52,11 @@ pub mod mutations {
 
     #[derive(cynic::QueryFragment, Debug)]
     pub struct ProjectApiKey {
+        pub id: cynic::Id,
         pub key: String,
         pub name: String,
+        pub environment: BranchEnvironment,
+        pub created_at: DateTime<Utc>,
     }
 
     #[derive(cynic::QueryFragment, Debug)]
@@ -110,61 +114,1039 @@ pub mod mutations {
         Unknown,
     }
 
-    #[derive(cynic::QueryFragment, Debug)]
-    pub struct ProjectDoesNotExistError {
-        pub __typename: String,
-    }
-
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectDoesNotExistError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    #[cynic(rename_all = "camelCase")]
+    pub struct DeploymentCreateInput<'a> {
+        pub archive_file_size: i32,
+        pub branch: Option<&'a str>,
+        pub project_id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct DeploymentCreateArguments<'a> {
+        pub input: DeploymentCreateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "DeploymentCreateArguments")]
+    pub struct DeploymentCreate {
+        #[arguments(input: $input)]
+        pub deployment_create: DeploymentCreatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct DeploymentCreateSuccess {
+        pub __typename: String,
+        pub deployment: Deployment,
+        pub presigned_url: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Deployment {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct DailyDeploymentCountLimitExceededError {
+        pub __typename: String,
+        pub limit: i32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ArchiveFileSizeLimitExceededError {
+        pub __typename: String,
+        pub limit: i32,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum DeploymentCreatePayload {
+        DeploymentCreateSuccess(DeploymentCreateSuccess),
+        ProjectDoesNotExistError(ProjectDoesNotExistError),
+        ArchiveFileSizeLimitExceededError(ArchiveFileSizeLimitExceededError),
+        DailyDeploymentCountLimitExceededError(DailyDeploymentCountLimitExceededError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum BranchEnvironment {
+        Preview,
+        Production,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct EnvironmentVariable {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    #[cynic(rename_all = "camelCase")]
+    pub struct EnvironmentVariableCreateInput<'a> {
+        pub project_id: cynic::Id,
+        pub name: &'a str,
+        pub value: &'a str,
+        pub environments: Vec<BranchEnvironment>,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct EnvironmentVariableCreateArguments<'a> {
+        pub input: EnvironmentVariableCreateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "EnvironmentVariableCreateArguments")]
+    pub struct EnvironmentVariableCreate {
+        #[arguments(input: $input)]
+        pub environment_variable_create: EnvironmentVariableCreatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct EnvironmentVariableCreateSuccess {
+        pub __typename: String,
+        pub environment_variable: EnvironmentVariable,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NameAlreadyExistsError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NameTooLongError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NameContainsInvalidCharactersError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ValueTooLongError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ReservedPrefixError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct EnvironmentVariableDoesNotExistError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum EnvironmentVariableCreatePayload {
+        EnvironmentVariableCreateSuccess(EnvironmentVariableCreateSuccess),
+        NameAlreadyExistsError(NameAlreadyExistsError),
+        NameTooLongError(NameTooLongError),
+        NameContainsInvalidCharactersError(NameContainsInvalidCharactersError),
+        ValueTooLongError(ValueTooLongError),
+        ReservedPrefixError(ReservedPrefixError),
+        ProjectDoesNotExistError(ProjectDoesNotExistError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct EnvironmentVariableUpdateInput<'a> {
+        pub id: cynic::Id,
+        pub value: Option<&'a str>,
+        pub environments: Option<Vec<BranchEnvironment>>,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct EnvironmentVariableUpdateArguments<'a> {
+        pub input: EnvironmentVariableUpdateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "EnvironmentVariableUpdateArguments")]
+    pub struct EnvironmentVariableUpdate {
+        #[arguments(input: $input)]
+        pub environment_variable_update: EnvironmentVariableUpdatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct EnvironmentVariableUpdateSuccess {
+        pub __typename: String,
+        pub environment_variable: EnvironmentVariable,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum EnvironmentVariableUpdatePayload {
+        EnvironmentVariableUpdateSuccess(EnvironmentVariableUpdateSuccess),
+        EnvironmentVariableDoesNotExistError(EnvironmentVariableDoesNotExistError),
+        NameAlreadyExistsError(NameAlreadyExistsError),
+        NameTooLongError(NameTooLongError),
+        NameContainsInvalidCharactersError(NameContainsInvalidCharactersError),
+        ValueTooLongError(ValueTooLongError),
+        ReservedPrefixError(ReservedPrefixError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct EnvironmentVariableDeleteInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct EnvironmentVariableDeleteArguments {
+        pub input: EnvironmentVariableDeleteInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "EnvironmentVariableDeleteArguments")]
+    pub struct EnvironmentVariableDelete {
+        #[arguments(input: $input)]
+        pub environment_variable_delete: EnvironmentVariableDeletePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct EnvironmentVariableDeleteSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum EnvironmentVariableDeletePayload {
+        EnvironmentVariableDeleteSuccess(EnvironmentVariableDeleteSuccess),
+        EnvironmentVariableDoesNotExistError(EnvironmentVariableDoesNotExistError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    #[cynic(rename_all = "camelCase")]
+    pub struct ProjectApiKeyCreateInput<'a> {
+        pub project_id: cynic::Id,
+        pub environment: BranchEnvironment,
+        pub name: &'a str,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct ProjectApiKeyCreateArguments<'a> {
+        pub input: ProjectApiKeyCreateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectApiKeyCreateArguments")]
+    pub struct ProjectApiKeyCreate {
+        #[arguments(input: $input)]
+        pub project_api_key_create: ProjectApiKeyCreatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectApiKeyCreateSuccess {
+        pub __typename: String,
+        pub api_key: ProjectApiKey,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct KeyLimitExceededError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct KeyDoesNotExistError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct MustLeaveAtLeastOneKeyForEnvironmentError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum ProjectApiKeyCreatePayload {
+        ProjectApiKeyCreateSuccess(ProjectApiKeyCreateSuccess),
+        KeyLimitExceededError(KeyLimitExceededError),
+        ProjectDoesNotExistError(ProjectDoesNotExistError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct ProjectApiKeyUpdateInput<'a> {
+        pub id: cynic::Id,
+        pub name: &'a str,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct ProjectApiKeyUpdateArguments<'a> {
+        pub input: ProjectApiKeyUpdateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectApiKeyUpdateArguments")]
+    pub struct ProjectApiKeyUpdate {
+        #[arguments(input: $input)]
+        pub project_api_key_update: ProjectApiKeyUpdatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectApiKeyUpdateSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum ProjectApiKeyUpdatePayload {
+        ProjectApiKeyUpdateSuccess(ProjectApiKeyUpdateSuccess),
+        KeyDoesNotExistError(KeyDoesNotExistError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct ProjectApiKeyDeleteInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct ProjectApiKeyDeleteArguments {
+        pub input: ProjectApiKeyDeleteInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "ProjectApiKeyDeleteArguments")]
+    pub struct ProjectApiKeyDelete {
+        #[arguments(input: $input)]
+        pub project_api_key_delete: ProjectApiKeyDeletePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ProjectApiKeyDeleteSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum ProjectApiKeyDeletePayload {
+        ProjectApiKeyDeleteSuccess(ProjectApiKeyDeleteSuccess),
+        KeyDoesNotExistError(KeyDoesNotExistError),
+        MustLeaveAtLeastOneKeyForEnvironmentError(MustLeaveAtLeastOneKeyForEnvironmentError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum MemberRole {
+        Owner,
+        Member,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Account")]
+    pub struct MemberAccount {
+        pub name: String,
+        pub slug: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Member {
+        pub account: MemberAccount,
+        pub role: MemberRole,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct OrganizationCreateInput<'a> {
+        pub slug: &'a str,
+        pub name: &'a str,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct OrganizationCreateArguments<'a> {
+        pub input: OrganizationCreateInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "OrganizationCreateArguments")]
+    pub struct OrganizationCreate {
+        #[arguments(input: $input)]
+        pub organization_create: OrganizationCreatePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct OrganizationCreateSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct SlugError {
+        pub __typename: String,
+        pub message: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct SlugSizeCheckError {
+        pub __typename: String,
+        pub max_length: i32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NameSizeCheckError {
+        pub __typename: String,
+        pub max_length: i32,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct ReservedSlugsCheckError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum OrganizationCreatePayload {
+        OrganizationCreateSuccess(OrganizationCreateSuccess),
+        SlugError(SlugError),
+        SlugSizeCheckError(SlugSizeCheckError),
+        NameSizeCheckError(NameSizeCheckError),
+        ReservedSlugsCheckError(ReservedSlugsCheckError),
+        SlugAlreadyExistsError(SlugAlreadyExistsError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    #[cynic(rename_all = "camelCase")]
+    pub struct InviteSendInput<'a> {
+        pub organization_id: cynic::Id,
+        pub email: &'a str,
+        pub role: MemberRole,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct InviteSendArguments<'a> {
+        pub input: InviteSendInput<'a>,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "InviteSendArguments")]
+    pub struct InviteSend {
+        #[arguments(input: $input)]
+        pub invite_send: InviteSendPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct Invite {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct InviteSendSuccess {
+        pub __typename: String,
+        pub invite: Invite,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct OrganizationDoesNotExistError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotAllowedToSendInvitesError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum InviteSendPayload {
+        InviteSendSuccess(InviteSendSuccess),
+        OrganizationDoesNotExistError(OrganizationDoesNotExistError),
+        NotAllowedToSendInvitesError(NotAllowedToSendInvitesError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct InviteAcceptInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct InviteCancelInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct InviteDeclineInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct InviteAcceptArguments {
+        pub input: InviteAcceptInput,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct InviteCancelArguments {
+        pub input: InviteCancelInput,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct InviteDeclineArguments {
+        pub input: InviteDeclineInput,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "InviteAcceptArguments")]
+    pub struct InviteAccept {
+        #[arguments(input: $input)]
+        pub invite_accept: InviteAcceptPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "InviteCancelArguments")]
+    pub struct InviteCancel {
+        #[arguments(input: $input)]
+        pub invite_cancel: InviteCancelPayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    #[cynic(graphql_type = "Mutation", variables = "InviteDeclineArguments")]
+    pub struct InviteDecline {
+        #[arguments(input: $input)]
+        pub invite_decline: InviteDeclinePayload,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct InviteAcceptSuccess {
+        pub __typename: String,
+        pub member: Member,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct InviteCancelSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct InviteDeclineSuccess {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct InviteDoesNotExistError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct AlreadyMemberError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct NotAllowedToCancelInvitesError {
+        pub __typename: String,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum InviteAcceptPayload {
+        InviteAcceptSuccess(InviteAcceptSuccess),
+        InviteDoesNotExistError(InviteDoesNotExistError),
+        AlreadyMemberError(AlreadyMemberError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum InviteCancelPayload {
+        InviteCancelSuccess(InviteCancelSuccess),
+        InviteDoesNotExistError(InviteDoesNotExistError),
+        NotAllowedToCancelInvitesError(NotAllowedToCancelInvitesError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
+    #[derive(cynic::InlineFragments, Debug)]
+    pub enum InviteDeclinePayload {
+        InviteDeclineSuccess(InviteDeclineSuccess),
+        InviteDoesNotExistError(InviteDoesNotExistError),
+        #[cynic(fallback)]
+        Unknown,
+    }
+
     #[derive(cynic::InputObject, Clone, Debug)]
-    #[cynic(rename_all = "camelCase")]
-    pub struct DeploymentCreateInput<'a> {
-        pub archive_file_size: i32,
-        pub branch: Option<&'a str>,
-        pub project_id: cynic::Id,
+    pub struct MemberUpdateInput {
+        pub id: cynic::Id,
+        pub role: Option<MemberRole>,
     }
 
     #[derive(cynic::QueryVariables)]
-    pub struct DeploymentCreateArguments<'a> {
-        pub input: DeploymentCreateInput<'a>,
+    pub struct MemberUpdateArguments {
+        pub input: MemberUpdateInput,
     }
 
     #[derive(cynic::QueryFragment, Debug)]
-    #[cynic(graphql_type = "Mutation", variables = "DeploymentCreateArguments")]
-    pub struct DeploymentCreate {
+    #[cynic(graphql_type = "Mutation", variables = "MemberUpdateArguments")]
+    pub struct MemberUpdate {
         #[arguments(input: $input)]
-        pub deployment_create: DeploymentCreatePayload,
+        pub member_update: MemberUpdatePayload,
     }
 
     #[derive(cynic::QueryFragment, Debug)]
-    pub struct DeploymentCreateSuccess {
-        pub __typename: String,
-        pub presigned_url: String,
+    pub struct MemberUpdatePayload {
+        pub member: Member,
     }
 
-    #[derive(cynic::QueryFragment, Debug)]
-    pub struct DailyDeploymentCountLimitExceededError {
-        pub __typename: String,
-        pub limit: i32,
+    #[derive(cynic::InputObject, Clone, Debug)]
+    pub struct MemberDeleteInput {
+        pub id: cynic::Id,
+    }
+
+    #[derive(cynic::QueryVariables)]
+    pub struct MemberDeleteArguments {
+        pub input: MemberDeleteInput,
     }
 
     #[derive(cynic::QueryFragment, Debug)]
-    pub struct ArchiveFileSizeLimitExceededError {
-        pub __typename: String,
-        pub limit: i32,
+    #[cynic(graphql_type = "Mutation", variables = "MemberDeleteArguments")]
+    pub struct MemberDelete {
+        #[arguments(input: $input)]
+        pub member_delete: MemberDeletePayload,
     }
 
-    #[derive(cynic::InlineFragments, Debug)]
-    pub enum DeploymentCreatePayload {
-        DeploymentCreateSuccess(DeploymentCreateSuccess),
-        ProjectDoesNotExistError(ProjectDoesNotExistError),
-        ArchiveFileSizeLimitExceededError(ArchiveFileSizeLimitExceededError),
-        DailyDeploymentCountLimitExceededError(DailyDeploymentCountLimitExceededError),
-        #[cynic(fallback)]
-        Unknown,
+    #[derive(cynic::QueryFragment, Debug)]
+    pub struct MemberDeletePayload {
+        pub deleted_member_id: cynic::Id,
     }
 }
 
 pub mod queries {
+    #[allow(clippy::module_name_repetitions)]
+    pub mod account_by_slug {
+        use super::super::schema;
+
+        #[derive(cynic::QueryVariables)]
+        pub struct AccountBySlugArguments<'a> {
+            pub slug: &'a str,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "AccountBySlugArguments")]
+        pub struct AccountBySlugQuery {
+            #[arguments(slug: $slug)]
+            pub account_by_slug: Option<Account>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Account {
+            pub id: cynic::Id,
+            #[arguments(last: 100)]
+            pub projects: ProjectConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct ProjectConnection {
+            pub nodes: Vec<Project>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Project {
+            pub id: cynic::Id,
+            pub slug: String,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod api_keys {
+        use super::super::mutations::ProjectApiKey;
+        use super::super::schema;
+
+        #[derive(cynic::QueryVariables)]
+        pub struct ApiKeysArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "ApiKeysArguments")]
+        pub struct ApiKeysQuery {
+            #[arguments(accountSlug: $account_slug, projectSlug: $project_slug)]
+            pub project_by_account_slug: Option<Project>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Project {
+            #[arguments(last: 100)]
+            pub api_keys: ProjectApiKeyConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct ProjectApiKeyConnection {
+            pub nodes: Vec<ProjectApiKey>,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod deployment {
+        use super::super::schema;
+        use chrono::{DateTime, Utc};
+
+        #[derive(cynic::QueryVariables)]
+        pub struct DeploymentArguments {
+            pub id: cynic::Id,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "DeploymentArguments")]
+        pub struct DeploymentQuery {
+            #[arguments(id: $id)]
+            pub deployment: Option<Deployment>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Deployment {
+            pub status: DeploymentStatus,
+            pub log_entries: Vec<DeploymentLogEntry>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct DeploymentLogEntry {
+            pub created_at: DateTime<Utc>,
+            pub message: String,
+            pub level: DeploymentLogLevel,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "DeploymentArguments")]
+        pub struct DeploymentDiffQuery {
+            #[arguments(id: $id)]
+            pub deployment: Option<DeploymentDiff>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Deployment")]
+        pub struct DeploymentDiff {
+            pub diff_against_previous_branch_deployment: Option<String>,
+            pub diff_against_latest_production_deployment: Option<String>,
+        }
+
+        #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum DeploymentStatus {
+            Queued,
+            InProgress,
+            Succeeded,
+            Failed,
+        }
+
+        #[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum DeploymentLogLevel {
+            Error,
+            Info,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod deployments {
+        use super::super::schema;
+        use super::deployment::DeploymentStatus;
+        use chrono::{DateTime, Utc};
+
+        #[derive(cynic::InputObject, Clone, Debug)]
+        pub struct DeploymentFilter<'a> {
+            pub branch: Option<&'a str>,
+            pub statuses: Vec<DeploymentStatus>,
+        }
+
+        #[derive(cynic::QueryVariables)]
+        pub struct DeploymentsArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+            pub first: i32,
+            pub filter: DeploymentFilter<'a>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "DeploymentsArguments")]
+        pub struct DeploymentsQuery {
+            #[arguments(accountSlug: $account_slug, projectSlug: $project_slug)]
+            pub project_by_account_slug: Option<Project>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(variables = "DeploymentsArguments")]
+        pub struct Project {
+            #[arguments(first: $first, filter: $filter)]
+            pub deployments: DeploymentConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct DeploymentConnection {
+            pub nodes: Vec<Deployment>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Deployment {
+            pub id: cynic::Id,
+            pub status: DeploymentStatus,
+            pub created_at: DateTime<Utc>,
+            pub duration: Option<i32>,
+            pub branch: Branch,
+            pub commit: Option<GitCommit>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Branch {
+            pub name: String,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct GitCommit {
+            pub sha: String,
+            pub message: String,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod metrics {
+        use super::super::mutations::{BranchEnvironment, ProjectDoesNotExistError};
+        use super::super::schema;
+        use chrono::{DateTime, Utc};
+
+        #[derive(cynic::QueryVariables)]
+        pub struct ProductionBranchArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "ProductionBranchArguments")]
+        pub struct ProductionBranchQuery {
+            #[arguments(accountSlug: $account_slug, projectSlug: $project_slug)]
+            pub project_by_account_slug: Option<ProductionBranchProject>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Project")]
+        pub struct ProductionBranchProject {
+            pub production_branch: Branch,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Branch {
+            pub name: String,
+        }
+
+        #[derive(cynic::QueryVariables)]
+        pub struct MetricsByBranchArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+            pub branch: &'a str,
+            pub start_date: DateTime<Utc>,
+            pub end_date: DateTime<Utc>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "MetricsByBranchArguments")]
+        pub struct MetricsByBranchQuery {
+            #[arguments(
+                accountSlug: $account_slug,
+                projectSlug: $project_slug,
+                branch: $branch,
+                startDate: $start_date,
+                endDate: $end_date
+            )]
+            pub metrics_by_branch: BranchMetricsPayload,
+        }
+
+        #[derive(cynic::InlineFragments, Debug)]
+        pub enum BranchMetricsPayload {
+            BranchMetricsSuccess(BranchMetricsSuccess),
+            ProjectDoesNotExistError(ProjectDoesNotExistError),
+            #[cynic(fallback)]
+            Unknown,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct BranchMetricsSuccess {
+            pub latency: Distribution,
+            pub request: Distribution,
+        }
+
+        #[derive(cynic::InputObject, Clone, Debug)]
+        #[cynic(rename_all = "camelCase")]
+        pub struct DatabaseUsageFilter {
+            pub environment: Option<BranchEnvironment>,
+            pub start_date: Option<DateTime<Utc>>,
+            pub end_date: Option<DateTime<Utc>>,
+        }
+
+        #[derive(cynic::QueryVariables)]
+        pub struct DatabaseUsageArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+            pub filter: DatabaseUsageFilter,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "DatabaseUsageArguments")]
+        pub struct DatabaseUsageQuery {
+            #[arguments(accountSlug: $account_slug, projectSlug: $project_slug)]
+            pub project_by_account_slug: Option<DatabaseUsageProject>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Project", variables = "DatabaseUsageArguments")]
+        pub struct DatabaseUsageProject {
+            #[arguments(filter: $filter)]
+            pub usage: DatabaseUsage,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct DatabaseUsage {
+            pub request_count: Distribution,
+            pub db_reads: Distribution,
+            pub db_writes: Distribution,
+            pub db_size: Distribution,
+            pub granularity: DatabaseUsageGranularity,
+        }
+
+        #[derive(cynic::Enum, Clone, Copy, Debug)]
+        pub enum DatabaseUsageGranularity {
+            Hourly,
+            Daily,
+            Weekly,
+            Monthly,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Distribution {
+            pub unit: UnitType,
+            pub values: Vec<DistributionValue>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct DistributionValue {
+            pub bucket: DateTime<Utc>,
+            pub value: i32,
+        }
+
+        #[derive(cynic::Enum, Clone, Copy, Debug)]
+        pub enum UnitType {
+            NoUnit,
+            MilliSeconds,
+            Bytes,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod organizations {
+        use super::super::mutations::MemberRole;
+        use super::super::schema;
+        use chrono::{DateTime, Utc};
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query")]
+        pub struct OrganizationsQuery {
+            pub viewer: Option<User>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct User {
+            pub email: String,
+            #[arguments(last: 100)]
+            pub organizations: OrganizationConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct OrganizationConnection {
+            pub nodes: Vec<Organization>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Organization {
+            pub id: cynic::Id,
+            pub name: String,
+            pub slug: String,
+            #[arguments(last: 100)]
+            pub members: MemberConnection,
+            #[arguments(last: 100)]
+            pub invites: InviteConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct MemberConnection {
+            pub nodes: Vec<Member>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Member {
+            pub id: cynic::Id,
+            pub role: MemberRole,
+            pub created_at: DateTime<Utc>,
+            pub user: MemberUser,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "User")]
+        pub struct MemberUser {
+            pub name: String,
+            pub email: String,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct InviteConnection {
+            pub nodes: Vec<Invite>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Invite {
+            pub id: cynic::Id,
+            pub email: String,
+            pub role: MemberRole,
+            pub status: InviteStatus,
+            pub created_at: DateTime<Utc>,
+        }
+
+        #[derive(cynic::Enum, Clone, Copy, Debug)]
+        pub enum InviteStatus {
+            Pending,
+            Expired,
+        }
+    }
+
+    #[allow(clippy::module_name_repetitions)]
+    pub mod environment_variables {
+        use super::super::mutations::BranchEnvironment;
+        use super::super::schema;
+
+        #[derive(cynic::QueryVariables)]
+        pub struct EnvironmentVariablesArguments<'a> {
+            pub account_slug: &'a str,
+            pub project_slug: &'a str,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        #[cynic(graphql_type = "Query", variables = "EnvironmentVariablesArguments")]
+        pub struct EnvironmentVariablesQuery {
+            #[arguments(accountSlug: $account_slug, projectSlug: $project_slug)]
+            pub project_by_account_slug: Option<Project>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct Project {
+            #[arguments(last: 100)]
+            pub environment_variables: EnvironmentVariableConnection,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct EnvironmentVariableConnection {
+            pub nodes: Vec<EnvironmentVariable>,
+        }
+
+        #[derive(cynic::QueryFragment, Debug)]
+        pub struct EnvironmentVariable {
+            pub id: cynic::Id,
+            pub name: String,
+            pub value: String,
+            pub environments: Vec<BranchEnvironment>,
+        }
+    }
+
     pub mod viewer_and_regions {
         use super::super::schema;
 
@@ -243,6 +1225,8 @@ pub mod queries {
 
         #[derive(cynic::QueryFragment, Debug)]
         pub struct User {
+            pub name: String,
+            pub email: String,
             pub personal_account: Option<PersonalAccount>,
             #[arguments(last: 100)]
             pub organizations: OrganizationConnection,
@@ -284,3 +1268,5 @@ pub mod queries {
 
 #[cynic::schema("grafbase")]
 mod schema {}
+
+cynic::impl_scalar!(chrono::DateTime<chrono::Utc>, schema::DateTime);
@@ -2,7 +2,10 @@ use super::{
     client::create_client,
     consts::{API_URL, PROJECT_METADATA_FILE},
     errors::ApiError,
-    graphql::queries::viewer::{PersonalAccount, Viewer},
+    graphql::queries::{
+        account_by_slug::{AccountBySlugArguments, AccountBySlugQuery},
+        viewer::{PersonalAccount, Viewer},
+    },
     types::{self, AccountWithProjects, ProjectMetadata},
     utils::project_linked,
 };
@@ -101,3 +104,64 @@ pub async fn link_project(account_id: String, project_id: String) -> Result<(),
     .await
     .map_err(ApiError::WriteProjectMetadataFile)
 }
+
+/// links the local project to the project with the given slug in the account with the given slug,
+/// without prompting
+///
+/// # Errors
+///
+/// see [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn link_project_by_slug(account_slug: &str, project_slug: &str) -> Result<(), ApiError> {
+    if project_linked().await? {
+        return Err(ApiError::ProjectAlreadyLinked);
+    }
+
+    let client = create_client().await?;
+
+    let query = AccountBySlugQuery::build(AccountBySlugArguments { slug: account_slug });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let account = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .account_by_slug
+        .ok_or_else(|| ApiError::AccountDoesNotExist(account_slug.to_owned()))?;
+
+    let project = account
+        .projects
+        .nodes
+        .into_iter()
+        .find(|project| project.slug == project_slug)
+        .ok_or_else(|| ApiError::ProjectDoesNotExist(format!("{account_slug}/{project_slug}")))?;
+
+    link_project(account.id.into_inner(), project.id.into_inner()).await
+}
+
+/// links the local project to the project with the given ID, without prompting.
+/// returns the slug of the project
+///
+/// # Errors
+///
+/// see [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn link_project_by_id(project_id: &str) -> Result<String, ApiError> {
+    let accounts = get_viewer_data_for_link().await?;
+
+    let (account_id, project) = accounts
+        .into_iter()
+        .find_map(|account| {
+            let id = account.id;
+            account
+                .projects
+                .into_iter()
+                .find(|project| project.id == project_id)
+                .map(|project| (id, project))
+        })
+        .ok_or_else(|| ApiError::ProjectDoesNotExist(project_id.to_owned()))?;
+
+    link_project(account_id, project.id).await?;
+
+    Ok(project.slug)
+}
@@ -1,6 +1,10 @@
-use super::consts::{AUTH_URL, CREDENTIALS_FILE};
+use super::client::create_client_with_token;
+use super::consts::{API_URL, AUTH_URL, DEVICE_AUTHORIZATION_URL, DEVICE_LOGIN_SLOW_DOWN_INCREMENT, DEVICE_TOKEN_URL};
 use super::errors::{ApiError, LoginApiError};
-use super::types::{Credentials, LoginMessage};
+use super::graphql::queries::viewer::Viewer;
+use super::profiles;
+use super::types::{CredentialStorage, DeviceAuthorization, LoginMessage};
+use crate::consts::USER_AGENT;
 use axum::{
     extract::{Query, State},
     response::Redirect,
@@ -9,12 +13,15 @@ use axum::{
 };
 use common::environment::Environment;
 use common::{consts::EPHEMERAL_PORT_RANGE, types::LocalAddressType, utils::find_available_port_in_range};
+use cynic::http::{CynicReqwestError, ReqwestExt};
+use cynic::QueryBuilder;
+use reqwest::{header, Client, StatusCode};
 use serde::Deserialize;
 use std::{
     fs::create_dir_all,
     net::{Ipv4Addr, SocketAddr},
-    path::PathBuf,
     sync::mpsc::Sender as MspcSender,
+    time::{Duration, Instant},
 };
 use tokio::sync::mpsc::Sender;
 use tower_http::trace::TraceLayer;
@@ -29,37 +36,32 @@ struct TokenQueryParams {
 async fn token<'a>(
     State(LoginApiState {
         shutdown_sender,
-        user_dot_grafbase_path,
+        profile,
+        storage,
     }): State<LoginApiState>,
     query: Query<TokenQueryParams>,
 ) -> Result<Redirect, Redirect> {
-    let access_token = &query.token;
+    let write_result = write_credentials(profile, query.0.token, storage).await;
 
-    let credentials_path = user_dot_grafbase_path.join(CREDENTIALS_FILE);
-
-    let write_result = tokio::fs::write(&credentials_path, Credentials { access_token }.to_string()).await;
-
-    if write_result.is_ok() {
+    if let Err(error) = write_result {
         // the current connection will still be redirected before closing the server
-        shutdown_sender.send(Ok(())).await.expect("must be open");
-        Ok(Redirect::temporary(&format!("{AUTH_URL}?success=true")))
-    } else {
-        // the current connection will still be redirected before closing the server
-        shutdown_sender
-            .send(Err(LoginApiError::WriteCredentialFile(credentials_path)))
-            .await
-            .expect("must be open");
+        shutdown_sender.send(Err(error)).await.expect("must be open");
         Err(Redirect::temporary(&format!(
             "{AUTH_URL}?success=false&error={}",
-            encode("Could not write ~/.grafbase/credentials.json")
+            encode("Could not store the access token")
         )))
+    } else {
+        // the current connection will still be redirected before closing the server
+        shutdown_sender.send(Ok(())).await.expect("must be open");
+        Ok(Redirect::temporary(&format!("{AUTH_URL}?success=true")))
     }
 }
 
 #[derive(Clone)]
 struct LoginApiState {
     shutdown_sender: Sender<Result<(), LoginApiError>>,
-    user_dot_grafbase_path: PathBuf,
+    profile: String,
+    storage: CredentialStorage,
 }
 
 /// Logs a user in via a browser flow
@@ -75,7 +77,7 @@ struct LoginApiState {
 /// - returns [`BackendError::StartLoginServer`] if the login server could not be started
 #[allow(clippy::needless_pass_by_value)] // &Sender is not Sync
 #[tokio::main]
-pub async fn login(message_sender: MspcSender<LoginMessage>) -> Result<(), ApiError> {
+pub async fn login(message_sender: MspcSender<LoginMessage>, storage: CredentialStorage) -> Result<(), ApiError> {
     let environment = Environment::get();
 
     match environment.user_dot_grafbase_path.try_exists() {
@@ -86,6 +88,8 @@ pub async fn login(message_sender: MspcSender<LoginMessage>) -> Result<(), ApiEr
         Err(error) => return Err(ApiError::ReadUserDotGrafbaseFolder(error)),
     }
 
+    let profile = profiles::current()?;
+
     let port = find_available_port_in_range(EPHEMERAL_PORT_RANGE, LocalAddressType::Localhost)
         .ok_or(ApiError::FindAvailablePort)?;
 
@@ -102,7 +106,8 @@ pub async fn login(message_sender: MspcSender<LoginMessage>) -> Result<(), ApiEr
         .layer(TraceLayer::new_for_http())
         .with_state(LoginApiState {
             shutdown_sender,
-            user_dot_grafbase_path: environment.user_dot_grafbase_path.clone(),
+            profile,
+            storage,
         });
 
     let socket_address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
@@ -122,3 +127,176 @@ pub async fn login(message_sender: MspcSender<LoginMessage>) -> Result<(), ApiEr
 
     Ok(())
 }
+
+/// Logs a user in with an access token, which is checked against the API before being written
+///
+/// # Errors
+///
+/// - returns [`LoginApiError::EmptyAccessToken`] if the token is empty
+///
+/// - returns [`LoginApiError::InvalidAccessToken`] if the API does not accept the token
+///
+/// - returns [`LoginApiError::WriteCredentialFile`] or [`LoginApiError::WriteKeyring`] if the token could not be stored
+///
+/// See [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn login_with_token(access_token: &str, storage: CredentialStorage) -> Result<(), ApiError> {
+    let access_token = access_token.trim();
+
+    if access_token.is_empty() {
+        return Err(LoginApiError::EmptyAccessToken.into());
+    }
+
+    validate_access_token(access_token).await?;
+
+    let profile = profiles::current()?;
+
+    Ok(write_credentials(profile, access_token.to_owned(), storage).await?)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceAuthorizationResponse {
+    device_code: String,
+    user_code: String,
+    verification_url: String,
+    interval: u64,
+    expires_in: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeviceTokenResponse {
+    access_token: Option<String>,
+    error: Option<DeviceTokenError>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DeviceTokenError {
+    AuthorizationPending,
+    SlowDown,
+    ExpiredToken,
+    AccessDenied,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Starts a device login, which is confirmed by entering the returned user code in a browser on any device
+///
+/// # Errors
+///
+/// See [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn start_device_login() -> Result<DeviceAuthorization, ApiError> {
+    let response = Client::new()
+        .post(DEVICE_AUTHORIZATION_URL)
+        .header(header::USER_AGENT, USER_AGENT)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(LoginApiError::Unknown.into());
+    }
+
+    let DeviceAuthorizationResponse {
+        device_code,
+        user_code,
+        verification_url,
+        interval,
+        expires_in,
+    } = response.json().await.map_err(|_| LoginApiError::Unknown)?;
+
+    Ok(DeviceAuthorization {
+        device_code,
+        user_code,
+        verification_url,
+        interval: Duration::from_secs(interval),
+        expires_in: Duration::from_secs(expires_in),
+    })
+}
+
+/// Polls a device login started with [`start_device_login`] until it is confirmed, then writes the received token
+///
+/// # Errors
+///
+/// - returns [`LoginApiError::DeviceCodeExpired`] if the login is not confirmed before the device code expires
+///
+/// - returns [`LoginApiError::DeviceLoginDenied`] if the login is denied
+///
+/// - returns [`LoginApiError::WriteCredentialFile`] or [`LoginApiError::WriteKeyring`] if the token could not be stored
+///
+/// See [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn complete_device_login(
+    authorization: &DeviceAuthorization,
+    storage: CredentialStorage,
+) -> Result<(), ApiError> {
+    let profile = profiles::current()?;
+    let client = Client::new();
+    let expires_at = Instant::now() + authorization.expires_in;
+    let mut interval = authorization.interval;
+
+    loop {
+        tokio::time::sleep(interval).await;
+
+        if Instant::now() >= expires_at {
+            return Err(LoginApiError::DeviceCodeExpired.into());
+        }
+
+        let response: DeviceTokenResponse = client
+            .post(DEVICE_TOKEN_URL)
+            .header(header::USER_AGENT, USER_AGENT)
+            .json(&serde_json::json!({ "deviceCode": authorization.device_code }))
+            .send()
+            .await?
+            .json()
+            .await
+            .map_err(|_| LoginApiError::Unknown)?;
+
+        match response {
+            DeviceTokenResponse {
+                access_token: Some(access_token),
+                ..
+            } => return Ok(write_credentials(profile, access_token, storage).await?),
+            DeviceTokenResponse { error: Some(error), .. } => match error {
+                DeviceTokenError::AuthorizationPending => {}
+                DeviceTokenError::SlowDown => interval += DEVICE_LOGIN_SLOW_DOWN_INCREMENT,
+                DeviceTokenError::ExpiredToken => return Err(LoginApiError::DeviceCodeExpired.into()),
+                DeviceTokenError::AccessDenied => return Err(LoginApiError::DeviceLoginDenied.into()),
+                DeviceTokenError::Unknown => return Err(LoginApiError::Unknown.into()),
+            },
+            DeviceTokenResponse { .. } => return Err(LoginApiError::Unknown.into()),
+        }
+    }
+}
+
+/// checks that an access token belongs to a user by requesting the viewer with it
+async fn validate_access_token(access_token: &str) -> Result<(), ApiError> {
+    let client = create_client_with_token(access_token).map_err(|_| LoginApiError::InvalidAccessToken)?;
+
+    let response = match client.post(API_URL).run_graphql(Viewer::build(())).await {
+        Ok(response) => response,
+        Err(CynicReqwestError::ErrorResponse(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN, _)) => {
+            return Err(LoginApiError::InvalidAccessToken.into())
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    response
+        .data
+        .and_then(|data| data.viewer)
+        .map(|_| ())
+        .ok_or_else(|| LoginApiError::InvalidAccessToken.into())
+}
+
+/// stores an access token for a profile, off the async runtime as writing to the system keyring can block
+async fn write_credentials(
+    profile: String,
+    access_token: String,
+    storage: CredentialStorage,
+) -> Result<(), LoginApiError> {
+    tokio::task::spawn_blocking(move || profiles::write_access_token(&profile, &access_token, storage))
+        .await
+        .expect("must not panic")
+}
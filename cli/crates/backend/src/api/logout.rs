@@ -1,24 +1,18 @@
-use super::{consts::CREDENTIALS_FILE, errors::ApiError};
-use common::environment::Environment;
-use std::fs;
+use super::{errors::ApiError, profiles};
 
-/// Deletes the login credentials file
+/// Deletes the login credentials of the current profile
 ///
 /// # Errors
 ///
 /// - returns [`BackendError::NotLoggedIn`] if the user is not logged in when attempting to log out
 ///
-/// - returns [`BackendError::DeleteCredentialsFile`] if ~/.grafbase/credentials.json could not be deleted
+/// - returns [`BackendError::DeleteCredentialsFile`] if the credentials file of the profile could not be deleted
 ///
-/// - returns [`BackendError::ReadCredentialsFile`] if ~/.grafbase/credentials.json could not be read
+/// - returns [`BackendError::ReadCredentialsFile`] if the credentials file of the profile could not be read
+///
+/// - returns [`BackendError::Keyring`] if the token of the profile could not be deleted from the system keyring
 pub fn logout() -> Result<(), ApiError> {
-    let environment = Environment::get();
-
-    let credentials_path = environment.user_dot_grafbase_path.join(CREDENTIALS_FILE);
+    let profile = profiles::current()?;
 
-    match credentials_path.try_exists() {
-        Ok(true) => fs::remove_file(credentials_path).map_err(ApiError::DeleteCredentialsFile),
-        Ok(false) => Err(ApiError::NotLoggedIn),
-        Err(error) => Err(ApiError::ReadCredentialsFile(error)),
-    }
+    profiles::delete_access_token(&profile)
 }
@@ -0,0 +1,122 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::ApiError;
+use super::graphql::queries::metrics::{
+    BranchMetricsPayload, DatabaseUsageArguments, DatabaseUsageFilter, DatabaseUsageQuery, MetricsByBranchArguments,
+    MetricsByBranchQuery, ProductionBranchArguments, ProductionBranchQuery,
+};
+use super::types::{BranchEnvironment, BranchMetrics, DatabaseUsage, LinkedProject};
+use super::utils::linked_project;
+use chrono::{DateTime, Utc};
+use cynic::{http::ReqwestExt, QueryBuilder};
+use reqwest::Client;
+
+/// fetches the request and latency metrics of a branch of the linked project between two dates,
+/// using the production branch if none is given
+///
+/// # Errors
+///
+/// See [`ApiError`]
+#[allow(clippy::module_name_repetitions)]
+pub async fn branch_metrics(
+    branch: Option<&str>,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Result<BranchMetrics, ApiError> {
+    let client = create_client().await?;
+
+    let project = linked_project(&client).await?;
+
+    let branch = match branch {
+        Some(branch) => branch.to_owned(),
+        None => production_branch(&client, &project).await?,
+    };
+
+    let query = MetricsByBranchQuery::build(MetricsByBranchArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+        branch: &branch,
+        start_date,
+        end_date,
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .metrics_by_branch;
+
+    match payload {
+        BranchMetricsPayload::BranchMetricsSuccess(metrics) => Ok(BranchMetrics {
+            branch,
+            start_date,
+            end_date,
+            requests: metrics.request.into(),
+            latency: metrics.latency.into(),
+        }),
+        BranchMetricsPayload::ProjectDoesNotExistError(_) => Err(ApiError::LinkedProjectDoesNotExist),
+        BranchMetricsPayload::Unknown => Err(ApiError::RequestError),
+    }
+}
+
+/// fetches the database usage of the linked project between two dates, optionally limited to an environment
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn database_usage(
+    environment: Option<BranchEnvironment>,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Result<DatabaseUsage, ApiError> {
+    let client = create_client().await?;
+
+    let project = linked_project(&client).await?;
+
+    let query = DatabaseUsageQuery::build(DatabaseUsageArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+        filter: DatabaseUsageFilter {
+            environment: environment.map(Into::into),
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+        },
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    let usage = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_by_account_slug
+        .ok_or(ApiError::LinkedProjectDoesNotExist)?
+        .usage;
+
+    Ok(DatabaseUsage {
+        start_date,
+        end_date,
+        granularity: usage.granularity.into(),
+        requests: usage.request_count.into(),
+        reads: usage.db_reads.into(),
+        writes: usage.db_writes.into(),
+        size: usage.db_size.into(),
+    })
+}
+
+async fn production_branch(client: &Client, project: &LinkedProject) -> Result<String, ApiError> {
+    let query = ProductionBranchQuery::build(ProductionBranchArguments {
+        account_slug: &project.account_slug,
+        project_slug: &project.project_slug,
+    });
+
+    let response = client.post(API_URL).run_graphql(query).await?;
+
+    Ok(response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .project_by_account_slug
+        .ok_or(ApiError::LinkedProjectDoesNotExist)?
+        .production_branch
+        .name)
+}
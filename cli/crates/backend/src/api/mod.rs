@@ -1,13 +1,21 @@
 mod graphql;
 mod utils;
 
+#[allow(clippy::module_name_repetitions)]
+pub mod api_keys;
 pub mod client;
 pub mod consts;
 pub mod create;
 pub mod deploy;
+pub mod deployments;
+pub mod environment_variables;
 pub mod errors;
 pub mod link;
 pub mod login;
 pub mod logout;
+pub mod metrics;
+pub mod organizations;
+pub mod profiles;
 pub mod types;
 pub mod unlink;
+pub mod whoami;
@@ -0,0 +1,489 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::{ApiError, OrganizationError};
+use super::graphql::mutations::{
+    InviteAccept, InviteAcceptArguments, InviteAcceptInput, InviteAcceptPayload, InviteCancel, InviteCancelArguments,
+    InviteCancelInput, InviteCancelPayload, InviteDecline, InviteDeclineArguments, InviteDeclineInput,
+    InviteDeclinePayload, InviteSend, InviteSendArguments, InviteSendInput, InviteSendPayload, MemberDelete,
+    MemberDeleteArguments, MemberDeleteInput, MemberUpdate, MemberUpdateArguments, MemberUpdateInput,
+    NameSizeCheckError, OrganizationCreate, OrganizationCreateArguments, OrganizationCreateInput,
+    OrganizationCreatePayload, SlugError, SlugSizeCheckError,
+};
+use super::graphql::queries::organizations::{self, OrganizationsQuery};
+use super::types::{Invite, Member, MemberRole, Organization};
+use cynic::{http::ReqwestExt, Id, MutationBuilder, QueryBuilder};
+use reqwest::Client;
+
+/// lists the organizations the current user is a member of
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn list() -> Result<Vec<Organization>, ApiError> {
+    let client = create_client().await?;
+
+    let (viewer_email, organizations) = viewer_organizations(&client).await?;
+
+    let mut organizations: Vec<Organization> = organizations
+        .into_iter()
+        .map(|organization| Organization {
+            role: organization
+                .members
+                .nodes
+                .iter()
+                .find(|member| member.user.email == viewer_email)
+                .map(|member| member.role.into()),
+            member_count: organization.members.nodes.len(),
+            name: organization.name,
+            slug: organization.slug,
+        })
+        .collect();
+
+    organizations.sort_by(|left, right| left.slug.cmp(&right.slug));
+
+    Ok(organizations)
+}
+
+/// lists the members of an organization
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn members(organization_slug: &str) -> Result<Vec<Member>, ApiError> {
+    let client = create_client().await?;
+
+    let organization = organization(&client, organization_slug).await?;
+
+    let mut members: Vec<Member> = organization.members.nodes.into_iter().map(Into::into).collect();
+
+    members.sort_by(|left, right| left.created_at.cmp(&right.created_at));
+
+    Ok(members)
+}
+
+/// lists the invites sent for an organization that have not been accepted or declined
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn invites(organization_slug: &str) -> Result<Vec<Invite>, ApiError> {
+    let client = create_client().await?;
+
+    let organization = organization(&client, organization_slug).await?;
+
+    let mut invites: Vec<Invite> = organization.invites.nodes.into_iter().map(Into::into).collect();
+
+    invites.sort_by(|left, right| left.created_at.cmp(&right.created_at));
+
+    Ok(invites)
+}
+
+/// creates an organization with the current user as its owner
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn create(slug: &str, name: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let operation = OrganizationCreate::build(OrganizationCreateArguments {
+        input: OrganizationCreateInput { slug, name },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .organization_create;
+
+    create_result(payload)
+}
+
+/// invites a user to an organization by email address
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn invite(organization_slug: &str, email: &str, role: MemberRole) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let organization = organization(&client, organization_slug).await?;
+
+    let operation = InviteSend::build(InviteSendArguments {
+        input: InviteSendInput {
+            organization_id: organization.id,
+            email,
+            role: role.into(),
+        },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response.data.ok_or(ApiError::UnauthorizedOrDeletedUser)?.invite_send;
+
+    invite_result(organization_slug, &payload)
+}
+
+/// cancels the invite sent for an organization to an email address
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn cancel_invite(organization_slug: &str, email: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let organization = organization(&client, organization_slug).await?;
+
+    let invite = organization
+        .invites
+        .nodes
+        .into_iter()
+        .find(|invite| invite.email.eq_ignore_ascii_case(email))
+        .ok_or_else(|| OrganizationError::InviteDoesNotExist(email.to_owned()))?;
+
+    let operation = InviteCancel::build(InviteCancelArguments {
+        input: InviteCancelInput { id: invite.id },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response.data.ok_or(ApiError::UnauthorizedOrDeletedUser)?.invite_cancel;
+
+    cancel_invite_result(email, &payload)
+}
+
+/// accepts an invite to an organization, returning the slug of the organization
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn accept_invite(invite_id: &str) -> Result<String, ApiError> {
+    let client = create_client().await?;
+
+    let operation = InviteAccept::build(InviteAcceptArguments {
+        input: InviteAcceptInput { id: Id::new(invite_id) },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response.data.ok_or(ApiError::UnauthorizedOrDeletedUser)?.invite_accept;
+
+    accept_invite_result(invite_id, payload)
+}
+
+/// declines an invite to an organization
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn decline_invite(invite_id: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let operation = InviteDecline::build(InviteDeclineArguments {
+        input: InviteDeclineInput { id: Id::new(invite_id) },
+    });
+
+    let response = client.post(API_URL).run_graphql(operation).await?;
+
+    let payload = response.data.ok_or(ApiError::UnauthorizedOrDeletedUser)?.invite_decline;
+
+    decline_invite_result(invite_id, &payload)
+}
+
+/// changes the role of a member of an organization
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn update_role(organization_slug: &str, email: &str, role: MemberRole) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let member_id = member_id(&client, organization_slug, email).await?;
+
+    let operation = MemberUpdate::build(MemberUpdateArguments {
+        input: MemberUpdateInput {
+            id: member_id,
+            role: Some(role.into()),
+        },
+    });
+
+    client
+        .post(API_URL)
+        .run_graphql(operation)
+        .await?
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?;
+
+    Ok(())
+}
+
+/// removes a member from an organization
+///
+/// # Errors
+///
+/// See [`ApiError`] and [`OrganizationError`]
+pub async fn remove_member(organization_slug: &str, email: &str) -> Result<(), ApiError> {
+    let client = create_client().await?;
+
+    let member_id = member_id(&client, organization_slug, email).await?;
+
+    let operation = MemberDelete::build(MemberDeleteArguments {
+        input: MemberDeleteInput { id: member_id },
+    });
+
+    client
+        .post(API_URL)
+        .run_graphql(operation)
+        .await?
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?;
+
+    Ok(())
+}
+
+/// returns the email address of the current user and the organizations they are a member of
+async fn viewer_organizations(client: &Client) -> Result<(String, Vec<organizations::Organization>), ApiError> {
+    let response = client.post(API_URL).run_graphql(OrganizationsQuery::build(())).await?;
+
+    let viewer = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .viewer
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?;
+
+    Ok((viewer.email, viewer.organizations.nodes))
+}
+
+async fn organization(client: &Client, organization_slug: &str) -> Result<organizations::Organization, ApiError> {
+    let (_, organizations) = viewer_organizations(client).await?;
+
+    organizations
+        .into_iter()
+        .find(|organization| organization.slug == organization_slug)
+        .ok_or_else(|| OrganizationError::OrganizationDoesNotExist(organization_slug.to_owned()).into())
+}
+
+async fn member_id(client: &Client, organization_slug: &str, email: &str) -> Result<Id, ApiError> {
+    let organization = organization(client, organization_slug).await?;
+
+    organization
+        .members
+        .nodes
+        .into_iter()
+        .find(|member| member.user.email.eq_ignore_ascii_case(email))
+        .map(|member| member.id)
+        .ok_or_else(|| OrganizationError::MemberDoesNotExist(email.to_owned()).into())
+}
+
+fn create_result(payload: OrganizationCreatePayload) -> Result<(), ApiError> {
+    match payload {
+        OrganizationCreatePayload::OrganizationCreateSuccess(_) => Ok(()),
+        OrganizationCreatePayload::SlugError(SlugError { message, .. }) => {
+            Err(OrganizationError::SlugInvalid(message).into())
+        }
+        OrganizationCreatePayload::SlugSizeCheckError(SlugSizeCheckError { max_length, .. }) => {
+            Err(OrganizationError::SlugTooLong { max_length }.into())
+        }
+        OrganizationCreatePayload::NameSizeCheckError(NameSizeCheckError { max_length, .. }) => {
+            Err(OrganizationError::NameTooLong { max_length }.into())
+        }
+        OrganizationCreatePayload::ReservedSlugsCheckError(_) => Err(OrganizationError::SlugReserved.into()),
+        OrganizationCreatePayload::SlugAlreadyExistsError(_) => Err(OrganizationError::SlugAlreadyExists.into()),
+        OrganizationCreatePayload::Unknown => Err(OrganizationError::Unknown.into()),
+    }
+}
+
+fn invite_result(organization_slug: &str, payload: &InviteSendPayload) -> Result<(), ApiError> {
+    match payload {
+        InviteSendPayload::InviteSendSuccess(_) => Ok(()),
+        InviteSendPayload::OrganizationDoesNotExistError(_) => {
+            Err(OrganizationError::OrganizationDoesNotExist(organization_slug.to_owned()).into())
+        }
+        InviteSendPayload::NotAllowedToSendInvitesError(_) => Err(OrganizationError::NotAllowedToSendInvites.into()),
+        InviteSendPayload::Unknown => Err(OrganizationError::Unknown.into()),
+    }
+}
+
+fn cancel_invite_result(email: &str, payload: &InviteCancelPayload) -> Result<(), ApiError> {
+    match payload {
+        InviteCancelPayload::InviteCancelSuccess(_) => Ok(()),
+        InviteCancelPayload::InviteDoesNotExistError(_) => {
+            Err(OrganizationError::InviteDoesNotExist(email.to_owned()).into())
+        }
+        InviteCancelPayload::NotAllowedToCancelInvitesError(_) => {
+            Err(OrganizationError::NotAllowedToCancelInvites.into())
+        }
+        InviteCancelPayload::Unknown => Err(OrganizationError::Unknown.into()),
+    }
+}
+
+fn accept_invite_result(invite_id: &str, payload: InviteAcceptPayload) -> Result<String, ApiError> {
+    match payload {
+        InviteAcceptPayload::InviteAcceptSuccess(payload) => Ok(payload.member.account.slug),
+        InviteAcceptPayload::InviteDoesNotExistError(_) => {
+            Err(OrganizationError::InviteDoesNotExist(invite_id.to_owned()).into())
+        }
+        InviteAcceptPayload::AlreadyMemberError(_) => Err(OrganizationError::AlreadyMember.into()),
+        InviteAcceptPayload::Unknown => Err(OrganizationError::Unknown.into()),
+    }
+}
+
+fn decline_invite_result(invite_id: &str, payload: &InviteDeclinePayload) -> Result<(), ApiError> {
+    match payload {
+        InviteDeclinePayload::InviteDeclineSuccess(_) => Ok(()),
+        InviteDeclinePayload::InviteDoesNotExistError(_) => {
+            Err(OrganizationError::InviteDoesNotExist(invite_id.to_owned()).into())
+        }
+        InviteDeclinePayload::Unknown => Err(OrganizationError::Unknown.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        accept_invite_result, cancel_invite_result, create_result, decline_invite_result, invite_result, ApiError,
+        InviteAccept, InviteCancel, InviteDecline, InviteSend, OrganizationCreate, OrganizationError,
+    };
+    use serde_json::json;
+
+    fn create(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: OrganizationCreate = serde_json::from_value(json!({ "organizationCreate": payload })).unwrap();
+        create_result(data.organization_create)
+    }
+
+    fn invite(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: InviteSend = serde_json::from_value(json!({ "inviteSend": payload })).unwrap();
+        invite_result("acme", &data.invite_send)
+    }
+
+    fn cancel_invite(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: InviteCancel = serde_json::from_value(json!({ "inviteCancel": payload })).unwrap();
+        cancel_invite_result("user@example.com", &data.invite_cancel)
+    }
+
+    fn accept_invite(payload: &serde_json::Value) -> Result<String, ApiError> {
+        let data: InviteAccept = serde_json::from_value(json!({ "inviteAccept": payload })).unwrap();
+        accept_invite_result("invite-id", data.invite_accept)
+    }
+
+    fn decline_invite(payload: &serde_json::Value) -> Result<(), ApiError> {
+        let data: InviteDecline = serde_json::from_value(json!({ "inviteDecline": payload })).unwrap();
+        decline_invite_result("invite-id", &data.invite_decline)
+    }
+
+    #[test]
+    fn organization_create_errors() {
+        assert!(create(&json!({ "__typename": "OrganizationCreateSuccess" })).is_ok());
+
+        let error = create(&json!({ "__typename": "SlugError", "message": "must be lowercase" })).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::OrganizationError(OrganizationError::SlugInvalid(message)) if message == "must be lowercase"),
+            "{error:?}"
+        );
+
+        let error = create(&json!({ "__typename": "SlugSizeCheckError", "maxLength": 48 })).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ApiError::OrganizationError(OrganizationError::SlugTooLong { max_length: 48 })
+            ),
+            "{error:?}"
+        );
+
+        let error = create(&json!({ "__typename": "NameSizeCheckError", "maxLength": 64 })).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ApiError::OrganizationError(OrganizationError::NameTooLong { max_length: 64 })
+            ),
+            "{error:?}"
+        );
+
+        let error = create(&json!({ "__typename": "ReservedSlugsCheckError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::OrganizationError(OrganizationError::SlugReserved)),
+            "{error:?}"
+        );
+
+        let error = create(&json!({ "__typename": "SlugAlreadyExistsError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::OrganizationError(OrganizationError::SlugAlreadyExists)),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn invite_errors() {
+        let error = invite(&json!({ "__typename": "OrganizationDoesNotExistError" })).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::OrganizationError(OrganizationError::OrganizationDoesNotExist(slug)) if slug == "acme"),
+            "{error:?}"
+        );
+
+        let error = invite(&json!({ "__typename": "NotAllowedToSendInvitesError" })).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ApiError::OrganizationError(OrganizationError::NotAllowedToSendInvites)
+            ),
+            "{error:?}"
+        );
+
+        let error = cancel_invite(&json!({ "__typename": "InviteDoesNotExistError" })).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::OrganizationError(OrganizationError::InviteDoesNotExist(email)) if email == "user@example.com"),
+            "{error:?}"
+        );
+
+        let error = cancel_invite(&json!({ "__typename": "NotAllowedToCancelInvitesError" })).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ApiError::OrganizationError(OrganizationError::NotAllowedToCancelInvites)
+            ),
+            "{error:?}"
+        );
+
+        let error = accept_invite(&json!({ "__typename": "AlreadyMemberError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::OrganizationError(OrganizationError::AlreadyMember)),
+            "{error:?}"
+        );
+
+        let error = decline_invite(&json!({ "__typename": "InviteDoesNotExistError" })).unwrap_err();
+        assert!(
+            matches!(&error, ApiError::OrganizationError(OrganizationError::InviteDoesNotExist(id)) if id == "invite-id"),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn accepted_invite() {
+        let organization_slug = accept_invite(&json!({
+            "__typename": "InviteAcceptSuccess",
+            "member": {
+                "account": { "name": "Acme", "slug": "acme" },
+                "role": "MEMBER"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(organization_slug, "acme");
+    }
+
+    #[test]
+    fn unknown_organization_payloads() {
+        let error = create(&json!({ "__typename": "SomeNewError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::OrganizationError(OrganizationError::Unknown)),
+            "{error:?}"
+        );
+
+        let error = invite(&json!({ "__typename": "SomeNewError" })).unwrap_err();
+        assert!(
+            matches!(error, ApiError::OrganizationError(OrganizationError::Unknown)),
+            "{error:?}"
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use super::consts::{
+    CREDENTIALS_FILE, DEFAULT_PROFILE, GRAFBASE_PROFILE_ENV_VAR, KEYRING_SERVICE, PROFILES_DIRECTORY, PROFILES_FILE,
+};
+use super::errors::{ApiError, LoginApiError};
+use super::types::{CredentialStorage, Credentials, Profile};
+use common::environment::Environment;
+use keyring::Entry;
+use serde::{Deserialize, Serialize};
+use std::{env, fs, io::ErrorKind, path::PathBuf, sync::OnceLock};
+
+/// the profile selected for the current invocation, overriding the active profile
+static SELECTED_PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct ProfilesFile {
+    active: Option<String>,
+}
+
+/// selects the profile used by the current invocation instead of the active profile
+///
+/// # Errors
+///
+/// - returns [`ApiError::InvalidProfileName`] if the profile name is invalid
+///
+/// # Panics
+///
+/// panics if a profile was already selected
+pub fn select(profile: Option<String>) -> Result<(), ApiError> {
+    if let Some(profile) = &profile {
+        validate_profile_name(profile)?;
+    }
+
+    SELECTED_PROFILE.set(profile).expect("cannot select a profile twice");
+
+    Ok(())
+}
+
+/// returns the name of the profile used by the current invocation, which is the selected profile,
+/// the profile in `GRAFBASE_PROFILE` or the active profile, in that order
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub fn current() -> Result<String, ApiError> {
+    resolve_profile(
+        SELECTED_PROFILE.get().cloned().flatten(),
+        env::var(GRAFBASE_PROFILE_ENV_VAR).ok(),
+        || Ok(read_profiles_file()?.active),
+    )
+}
+
+/// picks the selected profile, the profile from the environment or the active profile, in that order,
+/// defaulting to the default profile. the active profile is only read if needed
+fn resolve_profile(
+    selected: Option<String>,
+    from_environment: Option<String>,
+    active: impl FnOnce() -> Result<Option<String>, ApiError>,
+) -> Result<String, ApiError> {
+    if let Some(profile) = selected {
+        return Ok(profile);
+    }
+
+    if let Some(profile) = from_environment {
+        validate_profile_name(&profile)?;
+        return Ok(profile);
+    }
+
+    Ok(active()?.unwrap_or_else(|| DEFAULT_PROFILE.to_owned()))
+}
+
+/// lists the default profile and every profile that has been logged into
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub fn list() -> Result<Vec<Profile>, ApiError> {
+    let environment = Environment::get();
+
+    let active = read_profiles_file()?
+        .active
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_owned());
+
+    let mut names = match fs::read_dir(environment.user_dot_grafbase_path.join(PROFILES_DIRECTORY)) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ApiError::ReadProfilesFolder)?,
+        Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+        Err(error) => return Err(ApiError::ReadProfilesFolder(error)),
+    };
+
+    names.retain(|name| validate_profile_name(name).is_ok() && name != DEFAULT_PROFILE);
+    names.sort();
+
+    // keyring entries cannot be enumerated, so an active profile only stored there is added explicitly
+    if active != DEFAULT_PROFILE && !names.contains(&active) {
+        names.push(active.clone());
+    }
+
+    names.insert(0, DEFAULT_PROFILE.to_owned());
+
+    names
+        .into_iter()
+        .map(|name| {
+            Ok(Profile {
+                active: name == active,
+                storage: storage(&name)?,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// makes a profile the active profile, used by commands unless another one is selected
+///
+/// # Errors
+///
+/// - returns [`ApiError::InvalidProfileName`] if the profile name is invalid
+///
+/// - returns [`ApiError::WriteProfilesFile`] if ~/.grafbase/profiles.json could not be written
+#[allow(clippy::module_name_repetitions)]
+pub fn use_profile(name: &str) -> Result<(), ApiError> {
+    validate_profile_name(name)?;
+
+    let environment = Environment::get();
+
+    fs::create_dir_all(&environment.user_dot_grafbase_path).map_err(ApiError::CreateUserDotGrafbaseFolder)?;
+
+    let profiles_file = ProfilesFile {
+        active: (name != DEFAULT_PROFILE).then(|| name.to_owned()),
+    };
+
+    fs::write(
+        environment.user_dot_grafbase_path.join(PROFILES_FILE),
+        serde_json::to_string(&profiles_file).expect("must serialize"),
+    )
+    .map_err(ApiError::WriteProfilesFile)
+}
+
+/// returns where the access token of a profile is stored, if it is logged in
+pub(crate) fn storage(profile: &str) -> Result<Option<CredentialStorage>, ApiError> {
+    match credentials_path(profile).try_exists() {
+        Ok(true) => return Ok(Some(CredentialStorage::File)),
+        Ok(false) => {}
+        Err(error) => return Err(ApiError::ReadCredentialsFile(error)),
+    }
+
+    match keyring_entry(profile)?.get_password() {
+        Ok(_) => Ok(Some(CredentialStorage::Keyring)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(error) => Err(ApiError::Keyring(error)),
+    }
+}
+
+/// reads the access token of a profile from its credentials file or the system keyring
+pub(crate) fn read_access_token(profile: &str) -> Result<String, ApiError> {
+    match storage(profile)? {
+        Some(CredentialStorage::File) => {
+            let credentials_file =
+                fs::read_to_string(credentials_path(profile)).map_err(ApiError::ReadCredentialsFile)?;
+
+            let credentials: Credentials<'_> =
+                serde_json::from_str(&credentials_file).map_err(|_| ApiError::CorruptCredentialsFile)?;
+
+            Ok(credentials.access_token.to_owned())
+        }
+        Some(CredentialStorage::Keyring) => keyring_entry(profile)?.get_password().map_err(ApiError::Keyring),
+        None => Err(ApiError::NotLoggedIn),
+    }
+}
+
+/// stores the access token of a profile, removing any copy of it from the other storage
+pub(crate) fn write_access_token(
+    profile: &str,
+    access_token: &str,
+    storage: CredentialStorage,
+) -> Result<(), LoginApiError> {
+    let credentials_path = credentials_path(profile);
+
+    match storage {
+        CredentialStorage::File => {
+            credentials_path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&credentials_path, Credentials { access_token }.to_string()))
+                .map_err(|_| LoginApiError::WriteCredentialFile(credentials_path))?;
+
+            // a stale token left in the keyring is harmless as the credentials file takes precedence
+            if let Ok(entry) = Entry::new(KEYRING_SERVICE, profile) {
+                let _: Result<_, _> = entry.delete_password();
+            }
+        }
+        CredentialStorage::Keyring => {
+            Entry::new(KEYRING_SERVICE, profile)
+                .and_then(|entry| entry.set_password(access_token))
+                .map_err(LoginApiError::WriteKeyring)?;
+
+            if let Err(error) = fs::remove_file(&credentials_path) {
+                if error.kind() != ErrorKind::NotFound {
+                    return Err(LoginApiError::WriteCredentialFile(credentials_path));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// deletes the access token of a profile from wherever it is stored
+pub(crate) fn delete_access_token(profile: &str) -> Result<(), ApiError> {
+    match storage(profile)? {
+        Some(CredentialStorage::File) => {
+            fs::remove_file(credentials_path(profile)).map_err(ApiError::DeleteCredentialsFile)
+        }
+        Some(CredentialStorage::Keyring) => keyring_entry(profile)?.delete_password().map_err(ApiError::Keyring),
+        None => Err(ApiError::NotLoggedIn),
+    }
+}
+
+/// the path of the credentials file of a profile. the default profile keeps using ~/.grafbase/credentials.json
+fn credentials_path(profile: &str) -> PathBuf {
+    let environment = Environment::get();
+
+    if profile == DEFAULT_PROFILE {
+        environment.user_dot_grafbase_path.join(CREDENTIALS_FILE)
+    } else {
+        environment
+            .user_dot_grafbase_path
+            .join(PROFILES_DIRECTORY)
+            .join(profile)
+            .join(CREDENTIALS_FILE)
+    }
+}
+
+fn keyring_entry(profile: &str) -> Result<Entry, ApiError> {
+    Entry::new(KEYRING_SERVICE, profile).map_err(ApiError::Keyring)
+}
+
+fn read_profiles_file() -> Result<ProfilesFile, ApiError> {
+    let environment = Environment::get();
+
+    match fs::read_to_string(environment.user_dot_grafbase_path.join(PROFILES_FILE)) {
+        Ok(profiles_file) => serde_json::from_str(&profiles_file).map_err(|_| ApiError::CorruptProfilesFile),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(ProfilesFile::default()),
+        Err(error) => Err(ApiError::ReadProfilesFile(error)),
+    }
+}
+
+fn validate_profile_name(name: &str) -> Result<(), ApiError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
+    {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidProfileName(name.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_profile, validate_profile_name, DEFAULT_PROFILE};
+    use crate::api::errors::ApiError;
+
+    #[test]
+    fn profile_precedence() {
+        let active = || Ok(Some("active".to_owned()));
+
+        assert_eq!(
+            resolve_profile(Some("selected".to_owned()), Some("env".to_owned()), active).unwrap(),
+            "selected"
+        );
+        assert_eq!(resolve_profile(None, Some("env".to_owned()), active).unwrap(), "env");
+        assert_eq!(resolve_profile(None, None, active).unwrap(), "active");
+        assert_eq!(resolve_profile(None, None, || Ok(None)).unwrap(), DEFAULT_PROFILE);
+
+        // a corrupt profiles file is only an error when the active profile is needed
+        assert_eq!(
+            resolve_profile(Some("selected".to_owned()), None, || Err(ApiError::CorruptProfilesFile)).unwrap(),
+            "selected"
+        );
+        assert!(matches!(
+            resolve_profile(None, None, || Err(ApiError::CorruptProfilesFile)),
+            Err(ApiError::CorruptProfilesFile)
+        ));
+    }
+
+    #[test]
+    fn profile_from_environment_is_validated() {
+        assert!(matches!(
+            resolve_profile(None, Some("../other".to_owned()), || Ok(None)),
+            Err(ApiError::InvalidProfileName(name)) if name == "../other"
+        ));
+    }
+
+    #[test]
+    fn profile_names() {
+        assert!(validate_profile_name("work_2-staging").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("with space").is_err());
+        assert!(validate_profile_name("../escape").is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use super::{errors::LoginApiError, graphql};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::time::Duration;
 
 pub enum LoginMessage {
     CallbackUrl(String),
@@ -8,6 +11,54 @@ pub enum LoginMessage {
     Error(LoginApiError),
 }
 
+/// where the access token of a profile is stored
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CredentialStorage {
+    /// in plaintext in the `credentials.json` file of the profile
+    File,
+    /// in the system keyring, encrypted at rest by the operating system
+    Keyring,
+}
+
+impl Display for CredentialStorage {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File => formatter.write_str("credentials file"),
+            Self::Keyring => formatter.write_str("system keyring"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Profile {
+    pub name: String,
+    /// whether commands use this profile unless another one is selected
+    pub active: bool,
+    /// where the access token of the profile is stored, if logged in
+    pub storage: Option<CredentialStorage>,
+}
+
+/// the user an access token belongs to
+#[derive(Debug)]
+pub struct Viewer {
+    pub name: String,
+    pub email: String,
+    pub personal_account_slug: Option<String>,
+    pub organization_slugs: Vec<String>,
+}
+
+/// a pending device login, confirmed by entering `user_code` at `verification_url`
+#[derive(Debug)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_url: String,
+    /// the interval at which the login is checked for completion
+    pub interval: Duration,
+    /// the time after which `device_code` can no longer be used
+    pub expires_in: Duration,
+}
+
 #[derive(Debug)]
 pub struct Account {
     pub id: String,
@@ -56,6 +107,13 @@ impl ToString for ProjectMetadata {
     }
 }
 
+/// the slugs of the remote project the local project is linked to
+#[derive(Debug)]
+pub struct LinkedProject {
+    pub account_slug: String,
+    pub project_slug: String,
+}
+
 #[derive(Clone)]
 pub struct DatabaseRegion {
     pub name: String,
@@ -76,3 +134,505 @@ impl From<graphql::queries::viewer_and_regions::DatabaseRegion> for DatabaseRegi
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeploymentStatus {
+    Queued,
+    InProgress,
+    Succeeded,
+    Failed,
+}
+
+impl Display for DeploymentStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Queued => "queued",
+            Self::InProgress => "in progress",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        })
+    }
+}
+
+impl From<graphql::queries::deployment::DeploymentStatus> for DeploymentStatus {
+    fn from(api_status: graphql::queries::deployment::DeploymentStatus) -> Self {
+        match api_status {
+            graphql::queries::deployment::DeploymentStatus::Queued => Self::Queued,
+            graphql::queries::deployment::DeploymentStatus::InProgress => Self::InProgress,
+            graphql::queries::deployment::DeploymentStatus::Succeeded => Self::Succeeded,
+            graphql::queries::deployment::DeploymentStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<DeploymentStatus> for graphql::queries::deployment::DeploymentStatus {
+    fn from(status: DeploymentStatus) -> Self {
+        match status {
+            DeploymentStatus::Queued => Self::Queued,
+            DeploymentStatus::InProgress => Self::InProgress,
+            DeploymentStatus::Succeeded => Self::Succeeded,
+            DeploymentStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+/// a file in the archive uploaded by `grafbase deploy`
+#[derive(Debug)]
+pub struct ArchiveFile {
+    /// the path of the file within the archive
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug)]
+pub struct ArchiveSummary {
+    pub files: Vec<ArchiveFile>,
+    /// the size of the whole archive in bytes
+    pub size: u64,
+}
+
+#[derive(Debug)]
+pub struct Deployment {
+    pub id: String,
+    pub branch: String,
+    pub status: DeploymentStatus,
+    pub created_at: DateTime<Utc>,
+    /// the duration of the deployment in milliseconds, once completed
+    pub duration: Option<i32>,
+    /// the sha and message of the deployed commit, for deployments triggered by a push
+    pub commit: Option<(String, String)>,
+}
+
+impl From<graphql::queries::deployments::Deployment> for Deployment {
+    fn from(api_deployment: graphql::queries::deployments::Deployment) -> Self {
+        Self {
+            id: api_deployment.id.into_inner(),
+            branch: api_deployment.branch.name,
+            status: api_deployment.status.into(),
+            created_at: api_deployment.created_at,
+            duration: api_deployment.duration,
+            commit: api_deployment.commit.map(|commit| (commit.sha, commit.message)),
+        }
+    }
+}
+
+/// the deployment a deployment's schema is compared to
+#[derive(Clone, Copy, Debug)]
+pub enum DeploymentDiffBase {
+    /// the previous deployment of the same branch
+    PreviousBranchDeployment,
+    /// the latest deployment of the production branch
+    LatestProductionDeployment,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeploymentLogLevel {
+    Error,
+    Info,
+}
+
+#[derive(Debug)]
+pub struct DeploymentLogEntry {
+    pub created_at: DateTime<Utc>,
+    pub message: String,
+    pub level: DeploymentLogLevel,
+}
+
+impl From<graphql::queries::deployment::DeploymentLogEntry> for DeploymentLogEntry {
+    fn from(api_entry: graphql::queries::deployment::DeploymentLogEntry) -> Self {
+        Self {
+            created_at: api_entry.created_at,
+            message: api_entry.message,
+            level: match api_entry.level {
+                graphql::queries::deployment::DeploymentLogLevel::Error => DeploymentLogLevel::Error,
+                graphql::queries::deployment::DeploymentLogLevel::Info => DeploymentLogLevel::Info,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BranchEnvironment {
+    Preview,
+    Production,
+}
+
+impl BranchEnvironment {
+    pub const ALL: [Self; 2] = [Self::Production, Self::Preview];
+}
+
+impl Display for BranchEnvironment {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Preview => "preview",
+            Self::Production => "production",
+        })
+    }
+}
+
+impl From<graphql::mutations::BranchEnvironment> for BranchEnvironment {
+    fn from(api_environment: graphql::mutations::BranchEnvironment) -> Self {
+        match api_environment {
+            graphql::mutations::BranchEnvironment::Preview => Self::Preview,
+            graphql::mutations::BranchEnvironment::Production => Self::Production,
+        }
+    }
+}
+
+impl From<BranchEnvironment> for graphql::mutations::BranchEnvironment {
+    fn from(environment: BranchEnvironment) -> Self {
+        match environment {
+            BranchEnvironment::Preview => Self::Preview,
+            BranchEnvironment::Production => Self::Production,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EnvironmentVariable {
+    pub id: String,
+    pub name: String,
+    pub value: String,
+    pub environments: Vec<BranchEnvironment>,
+}
+
+impl From<graphql::queries::environment_variables::EnvironmentVariable> for EnvironmentVariable {
+    fn from(api_variable: graphql::queries::environment_variables::EnvironmentVariable) -> Self {
+        Self {
+            id: api_variable.id.into_inner(),
+            name: api_variable.name,
+            value: api_variable.value,
+            environments: api_variable.environments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// a change to an environment variable made by pulling or pushing variables
+#[derive(Debug)]
+pub enum EnvironmentVariableChange {
+    Added { name: String, value: String },
+    Changed { name: String, from: String, to: String },
+}
+
+impl EnvironmentVariableChange {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Added { name, .. } | Self::Changed { name, .. } => name,
+        }
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Added { value, .. } | Self::Changed { to: value, .. } => value,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    pub key: String,
+    pub environment: BranchEnvironment,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<graphql::mutations::ProjectApiKey> for ApiKey {
+    fn from(api_key: graphql::mutations::ProjectApiKey) -> Self {
+        Self {
+            id: api_key.id.into_inner(),
+            name: api_key.name,
+            key: api_key.key,
+            environment: api_key.environment.into(),
+            created_at: api_key.created_at,
+        }
+    }
+}
+
+/// the unit of the values of a [`Metric`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricUnit {
+    Count,
+    Milliseconds,
+    Bytes,
+}
+
+impl From<graphql::queries::metrics::UnitType> for MetricUnit {
+    fn from(unit: graphql::queries::metrics::UnitType) -> Self {
+        match unit {
+            graphql::queries::metrics::UnitType::NoUnit => Self::Count,
+            graphql::queries::metrics::UnitType::MilliSeconds => Self::Milliseconds,
+            graphql::queries::metrics::UnitType::Bytes => Self::Bytes,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricValue {
+    /// the start of the time bucket the value was aggregated over
+    pub bucket: DateTime<Utc>,
+    pub value: i32,
+}
+
+/// a series of values over time
+#[derive(Debug, Serialize)]
+pub struct Metric {
+    pub unit: MetricUnit,
+    pub values: Vec<MetricValue>,
+}
+
+impl From<graphql::queries::metrics::Distribution> for Metric {
+    fn from(distribution: graphql::queries::metrics::Distribution) -> Self {
+        Self {
+            unit: distribution.unit.into(),
+            values: distribution
+                .values
+                .into_iter()
+                .map(|value| MetricValue {
+                    bucket: value.bucket,
+                    value: value.value,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// the request metrics of a branch over a time window
+#[derive(Debug, Serialize)]
+pub struct BranchMetrics {
+    pub branch: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub requests: Metric,
+    pub latency: Metric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageGranularity {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Display for UsageGranularity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hourly => formatter.write_str("hourly"),
+            Self::Daily => formatter.write_str("daily"),
+            Self::Weekly => formatter.write_str("weekly"),
+            Self::Monthly => formatter.write_str("monthly"),
+        }
+    }
+}
+
+impl From<graphql::queries::metrics::DatabaseUsageGranularity> for UsageGranularity {
+    fn from(granularity: graphql::queries::metrics::DatabaseUsageGranularity) -> Self {
+        match granularity {
+            graphql::queries::metrics::DatabaseUsageGranularity::Hourly => Self::Hourly,
+            graphql::queries::metrics::DatabaseUsageGranularity::Daily => Self::Daily,
+            graphql::queries::metrics::DatabaseUsageGranularity::Weekly => Self::Weekly,
+            graphql::queries::metrics::DatabaseUsageGranularity::Monthly => Self::Monthly,
+        }
+    }
+}
+
+/// the database usage of a project over a time window
+#[derive(Debug, Serialize)]
+pub struct DatabaseUsage {
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub granularity: UsageGranularity,
+    pub requests: Metric,
+    pub reads: Metric,
+    pub writes: Metric,
+    pub size: Metric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemberRole {
+    Owner,
+    Member,
+}
+
+impl Display for MemberRole {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owner => formatter.write_str("owner"),
+            Self::Member => formatter.write_str("member"),
+        }
+    }
+}
+
+impl From<graphql::mutations::MemberRole> for MemberRole {
+    fn from(role: graphql::mutations::MemberRole) -> Self {
+        match role {
+            graphql::mutations::MemberRole::Owner => Self::Owner,
+            graphql::mutations::MemberRole::Member => Self::Member,
+        }
+    }
+}
+
+impl From<MemberRole> for graphql::mutations::MemberRole {
+    fn from(role: MemberRole) -> Self {
+        match role {
+            MemberRole::Owner => Self::Owner,
+            MemberRole::Member => Self::Member,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Organization {
+    pub name: String,
+    pub slug: String,
+    /// the role of the current user in the organization
+    pub role: Option<MemberRole>,
+    pub member_count: usize,
+}
+
+#[derive(Debug)]
+pub struct Member {
+    pub name: String,
+    pub email: String,
+    pub role: MemberRole,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<graphql::queries::organizations::Member> for Member {
+    fn from(member: graphql::queries::organizations::Member) -> Self {
+        Self {
+            name: member.user.name,
+            email: member.user.email,
+            role: member.role.into(),
+            created_at: member.created_at,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InviteStatus {
+    Pending,
+    Expired,
+}
+
+impl Display for InviteStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => formatter.write_str("pending"),
+            Self::Expired => formatter.write_str("expired"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Invite {
+    pub id: String,
+    pub email: String,
+    pub role: MemberRole,
+    pub status: InviteStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<graphql::queries::organizations::Invite> for Invite {
+    fn from(invite: graphql::queries::organizations::Invite) -> Self {
+        Self {
+            id: invite.id.into_inner(),
+            email: invite.email,
+            role: invite.role.into(),
+            status: match invite.status {
+                graphql::queries::organizations::InviteStatus::Pending => InviteStatus::Pending,
+                graphql::queries::organizations::InviteStatus::Expired => InviteStatus::Expired,
+            },
+            created_at: invite.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BranchMetrics, DatabaseUsage, Metric, MetricUnit, MetricValue, UsageGranularity};
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    fn date(date: &str) -> DateTime<Utc> {
+        date.parse().unwrap()
+    }
+
+    fn metric(unit: MetricUnit, values: &[(&str, i32)]) -> Metric {
+        Metric {
+            unit,
+            values: values
+                .iter()
+                .map(|(bucket, value)| MetricValue {
+                    bucket: date(bucket),
+                    value: *value,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn branch_metrics_json() {
+        let metrics = BranchMetrics {
+            branch: "main".to_owned(),
+            start_date: date("2023-06-01T00:00:00Z"),
+            end_date: date("2023-06-02T00:00:00Z"),
+            requests: metric(MetricUnit::Count, &[("2023-06-01T00:00:00Z", 12)]),
+            latency: metric(MetricUnit::Milliseconds, &[]),
+        };
+
+        assert_eq!(
+            serde_json::to_value(metrics).unwrap(),
+            json!({
+                "branch": "main",
+                "start_date": "2023-06-01T00:00:00Z",
+                "end_date": "2023-06-02T00:00:00Z",
+                "requests": {
+                    "unit": "count",
+                    "values": [{ "bucket": "2023-06-01T00:00:00Z", "value": 12 }]
+                },
+                "latency": { "unit": "milliseconds", "values": [] }
+            })
+        );
+    }
+
+    #[test]
+    fn database_usage_json() {
+        let usage = DatabaseUsage {
+            start_date: date("2023-05-01T00:00:00Z"),
+            end_date: date("2023-06-01T00:00:00Z"),
+            granularity: UsageGranularity::Daily,
+            requests: metric(MetricUnit::Count, &[("2023-05-01T00:00:00Z", 3)]),
+            reads: metric(MetricUnit::Count, &[("2023-05-01T00:00:00Z", 2)]),
+            writes: metric(MetricUnit::Count, &[]),
+            size: metric(MetricUnit::Bytes, &[("2023-05-01T00:00:00Z", 1024)]),
+        };
+
+        assert_eq!(
+            serde_json::to_value(usage).unwrap(),
+            json!({
+                "start_date": "2023-05-01T00:00:00Z",
+                "end_date": "2023-06-01T00:00:00Z",
+                "granularity": "daily",
+                "requests": {
+                    "unit": "count",
+                    "values": [{ "bucket": "2023-05-01T00:00:00Z", "value": 3 }]
+                },
+                "reads": {
+                    "unit": "count",
+                    "values": [{ "bucket": "2023-05-01T00:00:00Z", "value": 2 }]
+                },
+                "writes": { "unit": "count", "values": [] },
+                "size": {
+                    "unit": "bytes",
+                    "values": [{ "bucket": "2023-05-01T00:00:00Z", "value": 1024 }]
+                }
+            })
+        );
+    }
+}
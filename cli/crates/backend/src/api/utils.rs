@@ -1,5 +1,11 @@
-use super::{consts::PROJECT_METADATA_FILE, errors::ApiError};
+use super::consts::{API_URL, PROJECT_METADATA_FILE};
+use super::errors::ApiError;
+use super::graphql::queries::viewer::Viewer;
+use super::types::{LinkedProject, ProjectMetadata};
 use common::environment::Project;
+use cynic::{http::ReqwestExt, QueryBuilder};
+use reqwest::Client;
+use std::iter;
 use tokio::fs;
 
 pub async fn project_linked() -> Result<bool, ApiError> {
@@ -8,3 +14,56 @@ pub async fn project_linked() -> Result<bool, ApiError> {
         .await
         .map_err(ApiError::ReadProjectMetadataFile)
 }
+
+/// reads the metadata of the remote project the local project is linked to
+pub async fn project_metadata() -> Result<ProjectMetadata, ApiError> {
+    let project = Project::get();
+
+    let project_metadata_file_path = project.dot_grafbase_directory_path.join(PROJECT_METADATA_FILE);
+
+    match project_metadata_file_path.try_exists() {
+        Ok(true) => {}
+        Ok(false) => return Err(ApiError::UnlinkedProject),
+        Err(error) => return Err(ApiError::ReadProjectMetadataFile(error)),
+    }
+
+    let project_metadata_file = fs::read_to_string(project_metadata_file_path)
+        .await
+        .map_err(ApiError::ReadProjectMetadataFile)?;
+
+    serde_json::from_str(&project_metadata_file).map_err(|_| ApiError::CorruptProjectMetadataFile)
+}
+
+/// resolves the slugs of the linked project, which most queries of the API are keyed by
+pub async fn linked_project(client: &Client) -> Result<LinkedProject, ApiError> {
+    let ProjectMetadata { project_id, .. } = project_metadata().await?;
+
+    let response = client.post(API_URL).run_graphql(Viewer::build(())).await?;
+
+    let viewer = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .viewer
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?;
+
+    let personal_account = viewer.personal_account.ok_or(ApiError::IncorrectlyScopedToken)?;
+
+    iter::once((personal_account.slug, personal_account.projects.nodes))
+        .chain(
+            viewer
+                .organizations
+                .nodes
+                .into_iter()
+                .map(|organization| (organization.slug, organization.projects.nodes)),
+        )
+        .find_map(|(account_slug, projects)| {
+            projects
+                .into_iter()
+                .find(|project| project.id.inner() == project_id)
+                .map(|project| LinkedProject {
+                    account_slug,
+                    project_slug: project.slug,
+                })
+        })
+        .ok_or(ApiError::LinkedProjectDoesNotExist)
+}
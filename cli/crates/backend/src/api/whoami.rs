@@ -0,0 +1,35 @@
+use super::client::create_client;
+use super::consts::API_URL;
+use super::errors::ApiError;
+use super::graphql::queries::viewer::Viewer as ViewerQuery;
+use super::types::Viewer;
+use cynic::{http::ReqwestExt, QueryBuilder};
+
+/// returns the user the access token of the current profile belongs to
+///
+/// # Errors
+///
+/// See [`ApiError`]
+pub async fn whoami() -> Result<Viewer, ApiError> {
+    let client = create_client().await?;
+
+    let response = client.post(API_URL).run_graphql(ViewerQuery::build(())).await?;
+
+    let viewer = response
+        .data
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?
+        .viewer
+        .ok_or(ApiError::UnauthorizedOrDeletedUser)?;
+
+    Ok(Viewer {
+        name: viewer.name,
+        email: viewer.email,
+        personal_account_slug: viewer.personal_account.map(|account| account.slug),
+        organization_slugs: viewer
+            .organizations
+            .nodes
+            .into_iter()
+            .map(|organization| organization.slug)
+            .collect(),
+    })
+}
@@ -1,5 +1,6 @@
 pub use server::errors::ServerError;
-use std::{io, path::PathBuf};
+use server::types::DevServerInfo;
+use std::{io, net::SocketAddr, path::PathBuf};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -106,4 +107,24 @@ pub enum BackendError {
     /// returned if the request to get the information for a repository returned a response that could not be parsed
     #[error("could not read the repository information for {0}")]
     ReadRepositoryInformation(String),
+
+    /// returned if another development server is running in the current project
+    #[error("a development server is already running in this project on port {} (pid {})", .0.port, .0.pid)]
+    DevServerRunning(DevServerInfo),
+
+    /// returned if another development server is starting in the current project
+    #[error("a development server is already starting in this project")]
+    DevServerStarting,
+
+    /// returned if no development server is running in the current project
+    #[error("no development server is running in this project")]
+    DevServerNotRunning,
+
+    /// returned if no development server answers at the given address
+    #[error("could not reach a development server at {0}")]
+    DevServerUnreachable(SocketAddr),
+
+    /// returned if a request to the admin API of the development server fails
+    #[error("the development server could not handle the request\ncaused by: {0}")]
+    DevServerRequest(reqwest::Error),
 }
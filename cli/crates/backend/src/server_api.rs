@@ -1,26 +1,265 @@
 use crate::errors::BackendError;
-use crate::types::ServerMessage;
+use crate::types::{
+    DevServerInfo, EnvironmentOptions, HttpsOptions, ResolverFixture, ResolverMessage, ResolverTestReport,
+    ServerMessage, ServerStatus, ShutdownHandle, WatchOptions,
+};
+use chrono::Utc;
+use common::consts::{
+    ADMIN_API_PATH, ADMIN_REQUEST_HEADER, CERTIFICATES_DIRECTORY_NAME, DEFAULT_PORT, LOCAL_CA_CERTIFICATE_FILE_NAME,
+};
+use common::environment::Environment;
 use common::types::LocalAddressType;
 use common::utils::find_available_port;
+use server::dev_lock::DevServerLock;
 use server::errors::ServerError;
-use std::sync::mpsc::Receiver;
+use std::net::SocketAddr;
+use std::sync::mpsc::{self, Receiver};
 use std::thread;
+use std::time::Duration;
+use tokio_stream::StreamExt;
 
-type ServerInfo = (thread::JoinHandle<Result<(), ServerError>>, Receiver<ServerMessage>);
+pub use server::reserve_stdout;
 
-/// starts the server if an available port can be found
+type ServerInfo = (
+    thread::JoinHandle<Result<(), ServerError>>,
+    Receiver<ServerMessage>,
+    ShutdownHandle,
+);
+
+type FollowInfo = (thread::JoinHandle<Result<(), BackendError>>, Receiver<ServerMessage>);
+
+type ResolverTestInfo = (
+    thread::JoinHandle<Result<Vec<ResolverTestReport>, ServerError>>,
+    Receiver<ServerMessage>,
+);
+
+/// starts the server on the address of `start_address` if an available port can be found
 ///
 /// # Errors
 ///
 /// returns [`BackendError::AvailablePort`] if no available port can  be found
 ///
 /// returns [`BackendError::PortInUse`] if search is off and the supplied port is in use
-pub fn start_server(start_port: u16, search: bool, watch: bool, tracing: bool) -> Result<ServerInfo, BackendError> {
-    let port = find_available_port(search, start_port, LocalAddressType::Localhost).ok_or(if search {
+///
+/// returns [`BackendError::DevServerRunning`] if another development server is running in the current project
+///
+/// returns [`BackendError::DevServerStarting`] if another development server is starting in the current project
+pub fn start_server(
+    start_address: SocketAddr,
+    search: bool,
+    watch: Option<WatchOptions>,
+    https: Option<HttpsOptions>,
+    environment_options: EnvironmentOptions,
+    resolver_timeout: Duration,
+    tracing: bool,
+) -> Result<ServerInfo, BackendError> {
+    let lock = lock_project()?;
+
+    let start_port = start_address.port();
+
+    let port = find_available_port(search, start_port, start_address.ip()).ok_or(if search {
         BackendError::AvailablePort
     } else {
         BackendError::PortInUse(start_port)
     })?;
 
-    Ok(server::start(port, watch, tracing))
+    lock.record(&DevServerInfo {
+        pid: std::process::id(),
+        host: start_address.ip(),
+        port,
+        https: https.is_some(),
+        started_at: Utc::now(),
+    })
+    .map_err(BackendError::ServerError)?;
+
+    Ok(server::start(
+        lock,
+        SocketAddr::new(start_address.ip(), port),
+        watch,
+        https,
+        environment_options,
+        resolver_timeout,
+        tracing,
+    ))
+}
+
+/// finds the resolver fixtures of the current project, optionally only the ones of a specific resolver
+///
+/// # Errors
+///
+/// returns [`BackendError::ServerError`] if the fixtures directory cannot be read
+pub fn find_resolver_fixtures(resolver_name: Option<&str>) -> Result<Vec<ResolverFixture>, BackendError> {
+    server::resolver_tests::discover_fixtures(resolver_name).map_err(BackendError::ServerError)
+}
+
+/// starts the server on an available port and runs the given resolver fixtures against it
+///
+/// # Errors
+///
+/// returns [`BackendError::AvailablePort`] if no available port can be found
+///
+/// returns [`BackendError::DevServerRunning`] if a development server is running in the current project
+///
+/// returns [`BackendError::DevServerStarting`] if a development server is starting in the current project
+pub fn test_resolvers(
+    fixtures: Vec<ResolverFixture>,
+    update: bool,
+    resolver_timeout: Duration,
+    tracing: bool,
+) -> Result<ResolverTestInfo, BackendError> {
+    let lock = lock_project()?;
+
+    let port =
+        find_available_port(true, DEFAULT_PORT, LocalAddressType::Localhost).ok_or(BackendError::AvailablePort)?;
+
+    Ok(server::test_resolvers(
+        lock,
+        port,
+        fixtures,
+        update,
+        resolver_timeout,
+        tracing,
+    ))
+}
+
+/// reads the most recent log entries recorded by the development server, optionally only the ones of a specific resolver
+///
+/// # Errors
+///
+/// returns [`BackendError::ServerError`] if the resolver logs cannot be read
+pub fn resolver_logs(resolver_name: Option<&str>) -> Result<Vec<(String, Vec<ResolverMessage>)>, BackendError> {
+    server::resolver_logs::read_entries(resolver_name).map_err(BackendError::ServerError)
+}
+
+/// locks the current project, as two servers sharing a project would share its database
+fn lock_project() -> Result<DevServerLock, BackendError> {
+    DevServerLock::acquire().map_err(|error| match error {
+        ServerError::ProjectLocked => match running_server() {
+            Ok(Some(info)) => BackendError::DevServerRunning(info),
+            Ok(None) => BackendError::DevServerStarting,
+            Err(error) => error,
+        },
+        error => BackendError::ServerError(error),
+    })
+}
+
+/// the development server running in the current project, if any
+///
+/// # Errors
+///
+/// returns [`BackendError::ServerError`] if the project lock cannot be checked
+pub fn running_server() -> Result<Option<DevServerInfo>, BackendError> {
+    server::dev_lock::running_server().map_err(BackendError::ServerError)
+}
+
+/// follows the messages of the development server reachable at `address`, e.g. to attach to the server running in the
+/// current project, until the server stops
+///
+/// # Errors
+///
+/// see [`server_status`]
+pub fn follow_server(address: SocketAddr, https: bool) -> Result<FollowInfo, BackendError> {
+    let (sender, receiver) = mpsc::channel();
+
+    // manual implementation of #[tokio::main] due to a rust analyzer issue
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("must succeed");
+
+    // connecting upfront reports an unreachable server before following it
+    let response = runtime.block_on(admin_request(address, https, reqwest::Method::GET, "messages"))?;
+
+    let handle = thread::spawn(move || {
+        runtime.block_on(async move {
+            let mut stream = response.bytes_stream();
+            let mut buffer = Vec::new();
+
+            while let Some(chunk) = stream.next().await {
+                buffer.extend_from_slice(&chunk.map_err(BackendError::DevServerRequest)?);
+
+                while let Some(position) = buffer.iter().position(|byte| *byte == b'\n') {
+                    let line: Vec<_> = buffer.drain(..=position).collect();
+
+                    if let Ok(message) = serde_json::from_slice::<ServerMessage>(&line) {
+                        if sender.send(message).is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    });
+
+    Ok((handle, receiver))
+}
+
+/// fetches the status of the development server reachable at `address` through its admin API
+///
+/// # Errors
+///
+/// returns [`BackendError::DevServerUnreachable`] if no development server is reachable at `address`
+///
+/// returns [`BackendError::DevServerRequest`] if the request fails
+pub async fn server_status(address: SocketAddr, https: bool) -> Result<ServerStatus, BackendError> {
+    admin_request(address, https, reqwest::Method::GET, "status")
+        .await?
+        .json()
+        .await
+        .map_err(BackendError::DevServerRequest)
+}
+
+/// rebuilds the project of the development server reachable at `address` through its admin API
+///
+/// # Errors
+///
+/// see [`server_status`]
+pub async fn reload_server(address: SocketAddr, https: bool) -> Result<(), BackendError> {
+    admin_request(address, https, reqwest::Method::POST, "reload").await?;
+
+    Ok(())
+}
+
+async fn admin_request(
+    address: SocketAddr,
+    https: bool,
+    method: reqwest::Method,
+    path: &str,
+) -> Result<reqwest::Response, BackendError> {
+    let mut client = reqwest::Client::builder();
+
+    if https {
+        // trusts the local certificate authority used by `grafbase dev --https`
+        let certificate_authority_path = Environment::get()
+            .user_dot_grafbase_path
+            .join(CERTIFICATES_DIRECTORY_NAME)
+            .join(LOCAL_CA_CERTIFICATE_FILE_NAME);
+
+        if let Ok(certificate_authority) = tokio::fs::read(&certificate_authority_path).await {
+            client = client.add_root_certificate(
+                reqwest::Certificate::from_pem(&certificate_authority).map_err(BackendError::DevServerRequest)?,
+            );
+        }
+    }
+
+    let client = client.build().map_err(BackendError::DevServerRequest)?;
+
+    let scheme = if https { "https" } else { "http" };
+
+    client
+        .request(method, format!("{scheme}://{address}{ADMIN_API_PATH}/{path}"))
+        .header(ADMIN_REQUEST_HEADER, "1")
+        .send()
+        .await
+        .map_err(|error| {
+            if error.is_connect() {
+                BackendError::DevServerUnreachable(address)
+            } else {
+                BackendError::DevServerRequest(error)
+            }
+        })?
+        .error_for_status()
+        .map_err(BackendError::DevServerRequest)
 }
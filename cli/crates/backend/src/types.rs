@@ -1 +1,5 @@
-pub use server::types::ServerMessage;
+pub use server::resolver_tests::{ResolverFixture, ResolverMessage, ResolverTestOutcome, ResolverTestReport};
+pub use server::types::{
+    DevServerInfo, EnvironmentOptions, HttpsOptions, ResolverBuild, ServerMessage, ServerState, ServerStatus,
+    ShutdownHandle, WatchOptions,
+};
@@ -0,0 +1,50 @@
+use crate::{errors::CliError, output::report, prompts::confirm};
+use backend::api::{api_keys, types::BranchEnvironment};
+
+#[tokio::main]
+pub async fn list(reveal: bool) -> Result<(), CliError> {
+    let api_keys = api_keys::list().await.map_err(CliError::BackendApiError)?;
+
+    if api_keys.is_empty() {
+        report::no_api_keys();
+    }
+
+    for api_key in &api_keys {
+        report::api_key(api_key, reveal);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn create(name: &str, environment: BranchEnvironment) -> Result<(), CliError> {
+    let api_key = api_keys::create(name, environment)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::api_key_created(&api_key);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn rename(id: &str, name: &str) -> Result<(), CliError> {
+    api_keys::rename(id, name).await.map_err(CliError::BackendApiError)?;
+
+    report::api_key_renamed(name);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn delete(id: &str, yes: bool) -> Result<(), CliError> {
+    if !yes && !confirm("Delete this API key? Requests using it will be rejected")? {
+        return Ok(());
+    }
+
+    api_keys::delete(id).await.map_err(CliError::BackendApiError)?;
+
+    report::api_key_deleted();
+
+    Ok(())
+}
@@ -1,22 +1,89 @@
 use crate::create::CreateArguments;
-use clap::{arg, command, CommandFactory, Parser};
+use crate::errors::CliError;
+use crate::link::LinkTarget;
+use crate::login::LoginMethod;
+use backend::api::types::{BranchEnvironment, CredentialStorage, DeploymentDiffBase, DeploymentStatus, MemberRole};
+use backend::errors::BackendError;
+use backend::server_api::running_server;
+use backend::types::{HttpsOptions, WatchOptions};
+use clap::{arg, command, CommandFactory, Parser, ValueEnum};
 use clap_complete::{shells, Generator};
-use common::consts::{DEFAULT_LOG_FILTER, TRACE_LOG_FILTER};
+use common::consts::{
+    DEFAULT_GRAFBASE_ENV, DEFAULT_LOG_FILTER, DEFAULT_RESOLVER_TIMEOUT_SECONDS, DEFAULT_WATCH_DEBOUNCE_MILLISECONDS,
+    LOCALHOST, TRACE_LOG_FILTER,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
 use std::{fmt, path::PathBuf};
 
 const DEFAULT_PORT: u16 = 4000;
+const DEFAULT_DEPLOYMENTS_LIMIT: i32 = 10;
+const DEFAULT_METRICS_WINDOW_HOURS: i64 = 24;
+const DEFAULT_USAGE_WINDOW_DAYS: i64 = 30;
 
 #[derive(Debug, Parser)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DevCommand {
     /// Use a specific port
     #[arg(short, long, default_value_t = DEFAULT_PORT)]
     pub port: u16,
+    /// The IPv4 or IPv6 address to listen on, e.g. 0.0.0.0 or :: to be reachable from other devices
+    #[arg(long, visible_alias = "listen", value_name = "ADDRESS", default_value = LOCALHOST)]
+    pub host: IpAddr,
     /// If a given port is unavailable, search for another
     #[arg(short, long)]
     pub search: bool,
+    /// Follow the output of the development server already running in this project instead of failing
+    #[arg(long)]
+    pub attach: bool,
+    /// Serve over HTTPS with a certificate issued by a local certificate authority created in ~/.grafbase
+    #[arg(long)]
+    pub https: bool,
+    /// Serve over HTTPS with the given PEM certificate rather than one issued by the local certificate authority
+    #[arg(long, value_name = "PATH", requires = "https_key")]
+    pub https_cert: Option<PathBuf>,
+    /// The PEM private key of the certificate passed with --https-cert
+    #[arg(long, value_name = "PATH", requires = "https_cert")]
+    pub https_key: Option<PathBuf>,
     /// Do not listen for schema changes and reload
     #[arg(long)]
     pub disable_watch: bool,
+    /// Also reload on changes to files matching a glob relative to the grafbase directory.
+    /// Can be passed multiple times
+    #[arg(long, value_name = "GLOB")]
+    pub watch_include: Vec<String>,
+    /// Do not reload on changes to files matching a glob relative to the grafbase directory,
+    /// taking precedence over --watch-include. Can be passed multiple times
+    #[arg(long, value_name = "GLOB")]
+    pub watch_exclude: Vec<String>,
+    /// The amount of milliseconds to wait for further changes before reloading
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = DEFAULT_WATCH_DEBOUNCE_MILLISECONDS)]
+    pub watch_debounce: u64,
+    /// Poll for changes rather than relying on file system notifications (e.g. on network or container file systems)
+    #[arg(long)]
+    pub watch_poll: bool,
+    /// The amount of seconds a resolver invocation may take before timing out
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_RESOLVER_TIMEOUT_SECONDS)]
+    pub resolver_timeout: u64,
+    /// The value of GRAFBASE_ENV, also selecting the grafbase/.env.<mode> files to load
+    /// (.env.development for the default mode)
+    #[arg(long, default_value = DEFAULT_GRAFBASE_ENV)]
+    pub mode: String,
+    /// Load environment variables from an additional file, taking precedence over the files in the grafbase directory.
+    /// Can be passed multiple times, later files taking precedence
+    #[arg(long, value_name = "PATH")]
+    pub env_file: Vec<PathBuf>,
+    /// The format of the output, json emits one event per line for tools consuming the output
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable output
+    Text,
+    /// JSON Lines, one event per line
+    Json,
 }
 
 #[derive(Debug, Parser, Clone, Copy)]
@@ -66,6 +133,27 @@ pub struct InitCommand {
     pub template: Option<String>,
 }
 
+impl DevCommand {
+    pub fn watch_options(&self) -> Option<WatchOptions> {
+        (!self.disable_watch).then(|| WatchOptions {
+            include: self.watch_include.clone(),
+            exclude: self.watch_exclude.clone(),
+            debounce: Duration::from_millis(self.watch_debounce),
+            poll: self.watch_poll,
+        })
+    }
+
+    pub fn https_options(&self) -> Option<HttpsOptions> {
+        match (&self.https_cert, &self.https_key) {
+            (Some(certificate_path), Some(key_path)) => Some(HttpsOptions::Certificate {
+                certificate_path: certificate_path.clone(),
+                key_path: key_path.clone(),
+            }),
+            _ => self.https.then_some(HttpsOptions::LocalCertificateAuthority),
+        }
+    }
+}
+
 impl InitCommand {
     pub fn name(&self) -> Option<&str> {
         self.name.as_deref()
@@ -104,6 +192,608 @@ impl CreateCommand {
     }
 }
 
+/// a project referenced as `<account-slug>/<project-slug>`
+#[derive(Clone, Debug)]
+pub struct ProjectReference {
+    pub account_slug: String,
+    pub project_slug: String,
+}
+
+fn parse_project_reference(value: &str) -> Result<ProjectReference, String> {
+    match value.split_once('/') {
+        Some((account_slug, project_slug)) if !account_slug.is_empty() && !project_slug.is_empty() => {
+            Ok(ProjectReference {
+                account_slug: account_slug.to_owned(),
+                project_slug: project_slug.to_owned(),
+            })
+        }
+        _ => Err("expected <account-slug>/<project-slug>".to_owned()),
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct LinkCommand {
+    /// The project to link to, skipping the prompts
+    #[arg(long, value_name = "ACCOUNT_SLUG/PROJECT_SLUG", value_parser = parse_project_reference)]
+    pub project: Option<ProjectReference>,
+    /// The ID of the project to link to, skipping the prompts
+    #[arg(long, value_name = "ID", conflicts_with = "project")]
+    pub project_id: Option<String>,
+}
+
+impl LinkCommand {
+    pub fn link_target(&self) -> Option<LinkTarget<'_>> {
+        match (&self.project, &self.project_id) {
+            (Some(project), _) => Some(LinkTarget::Slug(project)),
+            (None, Some(project_id)) => Some(LinkTarget::Id(project_id)),
+            (None, None) => None,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct LoginCommand {
+    /// Read an access token from stdin instead of logging in via a browser
+    #[arg(long, conflicts_with = "device")]
+    pub token: bool,
+    /// Log in by confirming a code in a browser on any device, without a local callback server
+    #[arg(long)]
+    pub device: bool,
+    /// Store the access token in the system keyring rather than in plaintext in ~/.grafbase
+    #[arg(long)]
+    pub keyring: bool,
+}
+
+impl LoginCommand {
+    pub fn login_method(&self) -> LoginMethod {
+        if self.token {
+            LoginMethod::Token
+        } else if self.device {
+            LoginMethod::Device
+        } else {
+            LoginMethod::Browser
+        }
+    }
+
+    pub fn credential_storage(&self) -> CredentialStorage {
+        if self.keyring {
+            CredentialStorage::Keyring
+        } else {
+            CredentialStorage::File
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct DeployCommand {
+    /// The branch to deploy to, defaults to the production branch of the project
+    #[arg(short, long)]
+    pub branch: Option<String>,
+    /// Build the archive that would be uploaded and list its files without deploying
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DeploymentStatusFilter {
+    Queued,
+    InProgress,
+    Succeeded,
+    Failed,
+}
+
+impl From<DeploymentStatusFilter> for DeploymentStatus {
+    fn from(status: DeploymentStatusFilter) -> Self {
+        match status {
+            DeploymentStatusFilter::Queued => Self::Queued,
+            DeploymentStatusFilter::InProgress => Self::InProgress,
+            DeploymentStatusFilter::Succeeded => Self::Succeeded,
+            DeploymentStatusFilter::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct DeploymentsListCommand {
+    /// Only list deployments of the given branch
+    #[arg(short, long)]
+    pub branch: Option<String>,
+    /// Only list deployments with the given status, can be passed multiple times
+    #[arg(short, long, value_enum)]
+    pub status: Vec<DeploymentStatusFilter>,
+    /// The maximum amount of deployments to list
+    #[arg(short, long, default_value_t = DEFAULT_DEPLOYMENTS_LIMIT)]
+    pub limit: i32,
+}
+
+impl DeploymentsListCommand {
+    pub fn statuses(&self) -> Vec<DeploymentStatus> {
+        self.status.iter().copied().map(Into::into).collect()
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct DeploymentsLogsCommand {
+    /// The ID of the deployment, as listed by 'grafbase deployments list'
+    pub id: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct DeploymentsDiffCommand {
+    /// The ID of the deployment, as listed by 'grafbase deployments list'
+    pub id: String,
+    /// Compare against the latest production deployment rather than the previous deployment of the same branch
+    #[arg(long)]
+    pub production: bool,
+}
+
+impl DeploymentsDiffCommand {
+    pub fn base(&self) -> DeploymentDiffBase {
+        if self.production {
+            DeploymentDiffBase::LatestProductionDeployment
+        } else {
+            DeploymentDiffBase::PreviousBranchDeployment
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub enum DeploymentsSubCommand {
+    /// List the most recent deployments of the linked project
+    List(DeploymentsListCommand),
+    /// Print the status and log entries of a deployment
+    Logs(DeploymentsLogsCommand),
+    /// Print the changes to the schema made by a deployment
+    Diff(DeploymentsDiffCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct DeploymentsCommand {
+    /// The deployments command to run
+    #[command(subcommand)]
+    pub command: DeploymentsSubCommand,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum EnvironmentArgument {
+    Production,
+    Preview,
+}
+
+impl From<EnvironmentArgument> for BranchEnvironment {
+    fn from(environment: EnvironmentArgument) -> Self {
+        match environment {
+            EnvironmentArgument::Production => Self::Production,
+            EnvironmentArgument::Preview => Self::Preview,
+        }
+    }
+}
+
+/// converts the environments passed to a command, `None` if none were passed
+fn branch_environments(environments: &[EnvironmentArgument]) -> Option<Vec<BranchEnvironment>> {
+    (!environments.is_empty()).then(|| environments.iter().copied().map(Into::into).collect())
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvListCommand {
+    /// Only list the variables of the given environment
+    #[arg(short, long, value_enum)]
+    pub environment: Option<EnvironmentArgument>,
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvSetCommand {
+    /// The name of the variable
+    pub name: String,
+    /// The value of the variable
+    pub value: String,
+    /// The environment the variable is used in, can be passed multiple times.
+    /// Defaults to every environment for new variables
+    #[arg(short, long, value_enum)]
+    pub environment: Vec<EnvironmentArgument>,
+}
+
+impl EnvSetCommand {
+    pub fn environments(&self) -> Option<Vec<BranchEnvironment>> {
+        branch_environments(&self.environment)
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvUnsetCommand {
+    /// The name of the variable
+    pub name: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvPullCommand {
+    /// The environment to pull the variables of
+    #[arg(short, long, value_enum, default_value_t = EnvironmentArgument::Production)]
+    pub environment: EnvironmentArgument,
+    /// The .env file to write the variables to, defaults to grafbase/.env
+    #[arg(short, long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+    /// Do not ask for confirmation before writing the file
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvPushCommand {
+    /// The environment to push the variables to, can be passed multiple times.
+    /// Defaults to every environment for new variables
+    #[arg(short, long, value_enum)]
+    pub environment: Vec<EnvironmentArgument>,
+    /// The .env file to read the variables from, defaults to grafbase/.env
+    #[arg(short, long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+    /// Do not ask for confirmation before uploading the variables
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+impl EnvPushCommand {
+    pub fn environments(&self) -> Option<Vec<BranchEnvironment>> {
+        branch_environments(&self.environment)
+    }
+}
+
+#[derive(Debug, Parser)]
+pub enum EnvSubCommand {
+    /// List the environment variables of the linked project
+    List(EnvListCommand),
+    /// Create or update an environment variable of the linked project
+    Set(EnvSetCommand),
+    /// Delete an environment variable of the linked project
+    Unset(EnvUnsetCommand),
+    /// Write the environment variables of the linked project to a local .env file
+    Pull(EnvPullCommand),
+    /// Upload the variables of a local .env file to the linked project
+    Push(EnvPushCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct EnvCommand {
+    /// The env command to run
+    #[command(subcommand)]
+    pub command: EnvSubCommand,
+}
+
+#[derive(Debug, Parser)]
+pub struct ApiKeysListCommand {
+    /// Show the keys in full rather than only their last characters
+    #[arg(long)]
+    pub reveal: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct ApiKeysCreateCommand {
+    /// The name of the new key
+    pub name: String,
+    /// The environment the key can be used in
+    #[arg(short, long, value_enum, default_value_t = EnvironmentArgument::Production)]
+    pub environment: EnvironmentArgument,
+}
+
+#[derive(Debug, Parser)]
+pub struct ApiKeysRenameCommand {
+    /// The ID of the key, as listed by 'grafbase api-keys list'
+    pub id: String,
+    /// The new name of the key
+    pub name: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct ApiKeysDeleteCommand {
+    /// The ID of the key, as listed by 'grafbase api-keys list'
+    pub id: String,
+    /// Do not ask for confirmation before deleting the key
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Parser)]
+pub enum ApiKeysSubCommand {
+    /// List the API keys of the linked project
+    List(ApiKeysListCommand),
+    /// Create an API key for the linked project
+    Create(ApiKeysCreateCommand),
+    /// Rename an API key of the linked project
+    Rename(ApiKeysRenameCommand),
+    /// Delete an API key of the linked project
+    Delete(ApiKeysDeleteCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct ApiKeysCommand {
+    /// The api-keys command to run
+    #[command(subcommand)]
+    pub command: ApiKeysSubCommand,
+}
+
+/// parses a time window such as `30m`, `24h`, `7d` or `2w`
+fn parse_time_window(value: &str) -> Result<chrono::Duration, String> {
+    let error = || "expected a number followed by m, h, d or w, e.g. 24h".to_owned();
+
+    let unit_start = value
+        .find(|character: char| !character.is_ascii_digit())
+        .ok_or_else(error)?;
+    let (amount, unit) = value.split_at(unit_start);
+
+    let amount: i64 = amount
+        .parse()
+        .ok()
+        .filter(|amount| (1..=100_000).contains(amount))
+        .ok_or_else(error)?;
+
+    match unit {
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        _ => Err(error()),
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct MetricsCommand {
+    /// The branch to show the metrics of, defaults to the production branch of the project
+    #[arg(short, long)]
+    pub branch: Option<String>,
+    /// How far back to show the metrics for, e.g. 30m, 24h, 7d or 2w, defaults to 24h
+    #[arg(long, value_name = "WINDOW", value_parser = parse_time_window)]
+    pub since: Option<chrono::Duration>,
+    /// Print the metrics as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl MetricsCommand {
+    pub fn since(&self) -> chrono::Duration {
+        self.since
+            .unwrap_or_else(|| chrono::Duration::hours(DEFAULT_METRICS_WINDOW_HOURS))
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct UsageCommand {
+    /// Only show the usage of the given environment
+    #[arg(short, long, value_enum)]
+    pub environment: Option<EnvironmentArgument>,
+    /// How far back to show the usage for, e.g. 12h, 7d or 4w, defaults to 30d
+    #[arg(long, value_name = "WINDOW", value_parser = parse_time_window)]
+    pub since: Option<chrono::Duration>,
+    /// Print the usage as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl UsageCommand {
+    pub fn since(&self) -> chrono::Duration {
+        self.since
+            .unwrap_or_else(|| chrono::Duration::days(DEFAULT_USAGE_WINDOW_DAYS))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MemberRoleArgument {
+    Owner,
+    Member,
+}
+
+impl From<MemberRoleArgument> for MemberRole {
+    fn from(role: MemberRoleArgument) -> Self {
+        match role {
+            MemberRoleArgument::Owner => Self::Owner,
+            MemberRoleArgument::Member => Self::Member,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgCreateCommand {
+    /// The slug of the new organization
+    pub slug: String,
+    /// The name of the new organization, defaults to the slug
+    #[arg(short, long)]
+    pub name: Option<String>,
+}
+
+impl OrgCreateCommand {
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.slug)
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgSlugCommand {
+    /// The slug of the organization
+    pub organization: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgInviteCommand {
+    /// The slug of the organization
+    pub organization: String,
+    /// The email address to send the invite to
+    pub email: String,
+    /// The role the invited user will have in the organization
+    #[arg(short, long, value_enum, default_value_t = MemberRoleArgument::Member)]
+    pub role: MemberRoleArgument,
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgMemberCommand {
+    /// The slug of the organization
+    pub organization: String,
+    /// The email address of the member or invited user
+    pub email: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgRoleCommand {
+    /// The slug of the organization
+    pub organization: String,
+    /// The email address of the member
+    pub email: String,
+    /// The new role of the member
+    #[arg(value_enum)]
+    pub role: MemberRoleArgument,
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgRemoveCommand {
+    /// The slug of the organization
+    pub organization: String,
+    /// The email address of the member
+    pub email: String,
+    /// Do not ask for confirmation before removing the member
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgInviteIdCommand {
+    /// The ID of the invite, as found in the invite email
+    pub id: String,
+}
+
+#[derive(Debug, Parser)]
+pub enum OrgSubCommand {
+    /// List the organizations you are a member of
+    List,
+    /// Create an organization with you as its owner
+    Create(OrgCreateCommand),
+    /// List the members of an organization
+    Members(OrgSlugCommand),
+    /// List the pending and expired invites of an organization
+    Invites(OrgSlugCommand),
+    /// Invite a user to an organization by email address
+    Invite(OrgInviteCommand),
+    /// Cancel the invite sent to an email address
+    CancelInvite(OrgMemberCommand),
+    /// Accept an invite to an organization
+    Accept(OrgInviteIdCommand),
+    /// Decline an invite to an organization
+    Decline(OrgInviteIdCommand),
+    /// Change the role of a member of an organization
+    Role(OrgRoleCommand),
+    /// Remove a member from an organization
+    Remove(OrgRemoveCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct OrgCommand {
+    /// The org command to run
+    #[command(subcommand)]
+    pub command: OrgSubCommand,
+}
+
+#[derive(Debug, Parser)]
+pub struct ProfilesUseCommand {
+    /// The name of the profile, 'default' for the profile stored directly in ~/.grafbase
+    pub name: String,
+}
+
+#[derive(Debug, Parser)]
+pub enum ProfilesSubCommand {
+    /// List the profiles that have been logged into
+    List,
+    /// Use a profile for subsequent commands
+    Use(ProfilesUseCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct ProfilesCommand {
+    /// The profiles command to run
+    #[command(subcommand)]
+    pub command: ProfilesSubCommand,
+}
+
+#[derive(Debug, Parser)]
+pub struct ResolversTestCommand {
+    /// The name of the resolver to test, tests every resolver with fixtures if omitted
+    pub name: Option<String>,
+    /// Overwrite snapshots that do not match the current results
+    #[arg(short, long)]
+    pub update: bool,
+    /// The amount of seconds a resolver invocation may take before timing out
+    #[arg(long, value_name = "SECONDS", default_value_t = DEFAULT_RESOLVER_TIMEOUT_SECONDS)]
+    pub timeout: u64,
+}
+
+impl ResolversTestCommand {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct ResolversLogsCommand {
+    /// The name of the resolver to print the logs of, prints the logs of every resolver if omitted
+    pub name: Option<String>,
+}
+
+impl ResolversLogsCommand {
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+#[derive(Debug, Parser)]
+pub enum ResolversSubCommand {
+    /// Invoke resolvers with the fixture payloads in grafbase/resolvers/__fixtures__
+    /// and compare the results to the stored snapshots
+    Test(ResolversTestCommand),
+    /// Print the most recent log entries of resolvers invoked by 'grafbase dev'
+    Logs(ResolversLogsCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct ResolversCommand {
+    /// The resolvers command to run
+    #[command(subcommand)]
+    pub command: ResolversSubCommand,
+}
+
+/// selects the running development server to talk to
+#[derive(Debug, clap::Args)]
+pub struct DevServerArguments {
+    /// The port the development server is running on (on the loopback address), defaults to the server running in
+    /// this project
+    #[arg(short, long)]
+    pub port: Option<u16>,
+    /// The development server is running with --https
+    #[arg(long)]
+    pub https: bool,
+}
+
+impl DevServerArguments {
+    /// the address of the development server and whether it uses https,
+    /// falling back to the server running in the current project if no port is given
+    pub fn resolve(&self) -> Result<(SocketAddr, bool), CliError> {
+        match self.port {
+            Some(port) => Ok((SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port), self.https)),
+            None => match running_server().map_err(CliError::BackendError)? {
+                Some(info) => Ok((info.local_address(), self.https || info.https)),
+                None => Err(CliError::BackendError(BackendError::DevServerNotRunning)),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct StatusCommand {
+    #[command(flatten)]
+    pub server: DevServerArguments,
+}
+
+#[derive(Debug, Parser)]
+pub struct ReloadCommand {
+    #[command(flatten)]
+    pub server: DevServerArguments,
+}
+
 #[derive(Debug, Parser)]
 pub enum SubCommand {
     /// Run your Grafbase project locally
@@ -116,17 +806,39 @@ pub enum SubCommand {
     /// Resets the local database for the current project
     Reset,
     /// Logs into your Grafbase account
-    Login,
+    Login(LoginCommand),
     /// Logs out of your Grafbase account
     Logout,
+    /// Shows the account you are logged into
+    Whoami,
+    /// Manage the profiles used to log into multiple accounts
+    Profiles(ProfilesCommand),
+    /// Manage your organizations, their members and invites
+    Org(OrgCommand),
     /// Set up and deploy a new project
     Create(CreateCommand),
-    /// Deploy your project
-    Deploy,
+    /// Deploy your project and follow the deployment until it completes
+    Deploy(DeployCommand),
+    /// Inspect the deployments of your project
+    Deployments(DeploymentsCommand),
+    /// Manage the environment variables of your project
+    Env(EnvCommand),
+    /// Manage the API keys of your project
+    ApiKeys(ApiKeysCommand),
+    /// Show the request and latency metrics of a branch of your project
+    Metrics(MetricsCommand),
+    /// Show the database usage of your project
+    Usage(UsageCommand),
     /// Connect a local project to a remote project
-    Link,
+    Link(LinkCommand),
     /// Disconnect a local project from a remote project
     Unlink,
+    /// Work with the resolvers of your project
+    Resolvers(ResolversCommand),
+    /// Show the status of a running development server
+    Status(StatusCommand),
+    /// Rebuild the project of a running development server
+    Reload(ReloadCommand),
 }
 
 // TODO see if there's a way to do this automatically (https://github.com/clap-rs/clap/discussions/4921)
@@ -152,8 +864,27 @@ impl ArgumentNames for DevCommand {
     fn argument_names(&self) -> Option<Vec<&'static str>> {
         filter_existing_arguments(&[
             (self.port != DEFAULT_PORT, "port"),
+            (self.host != IpAddr::V4(Ipv4Addr::LOCALHOST), "host"),
             (self.search, "search"),
+            (self.attach, "attach"),
+            (self.https, "https"),
+            (self.https_cert.is_some(), "https-cert"),
+            (self.https_key.is_some(), "https-key"),
             (self.disable_watch, "disable-watch"),
+            (!self.watch_include.is_empty(), "watch-include"),
+            (!self.watch_exclude.is_empty(), "watch-exclude"),
+            (
+                self.watch_debounce != DEFAULT_WATCH_DEBOUNCE_MILLISECONDS,
+                "watch-debounce",
+            ),
+            (self.watch_poll, "watch-poll"),
+            (
+                self.resolver_timeout != DEFAULT_RESOLVER_TIMEOUT_SECONDS,
+                "resolver-timeout",
+            ),
+            (self.mode != DEFAULT_GRAFBASE_ENV, "mode"),
+            (!self.env_file.is_empty(), "env-file"),
+            (self.output != OutputFormat::Text, "output"),
         ])
     }
 }
@@ -179,17 +910,163 @@ impl ArgumentNames for CreateCommand {
     }
 }
 
+impl ArgumentNames for LinkCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[
+            (self.project.is_some(), "project"),
+            (self.project_id.is_some(), "project-id"),
+        ])
+    }
+}
+
+impl ArgumentNames for OrgCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        match &self.command {
+            OrgSubCommand::Create(command) => filter_existing_arguments(&[(command.name.is_some(), "name")]),
+            OrgSubCommand::Invite(command) => {
+                filter_existing_arguments(&[(command.role != MemberRoleArgument::Member, "role")])
+            }
+            OrgSubCommand::Remove(command) => filter_existing_arguments(&[(command.yes, "yes")]),
+            OrgSubCommand::List
+            | OrgSubCommand::Members(_)
+            | OrgSubCommand::Invites(_)
+            | OrgSubCommand::CancelInvite(_)
+            | OrgSubCommand::Accept(_)
+            | OrgSubCommand::Decline(_)
+            | OrgSubCommand::Role(_) => None,
+        }
+    }
+}
+
+impl ArgumentNames for MetricsCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[
+            (self.branch.is_some(), "branch"),
+            (self.since.is_some(), "since"),
+            (self.json, "json"),
+        ])
+    }
+}
+
+impl ArgumentNames for UsageCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[
+            (self.environment.is_some(), "environment"),
+            (self.since.is_some(), "since"),
+            (self.json, "json"),
+        ])
+    }
+}
+
+impl ArgumentNames for LoginCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[
+            (self.token, "token"),
+            (self.device, "device"),
+            (self.keyring, "keyring"),
+        ])
+    }
+}
+
+impl ArgumentNames for DeployCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[(self.branch.is_some(), "branch"), (self.dry_run, "dry-run")])
+    }
+}
+
+impl ArgumentNames for DeploymentsCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        match &self.command {
+            DeploymentsSubCommand::List(command) => filter_existing_arguments(&[
+                (command.branch.is_some(), "branch"),
+                (!command.status.is_empty(), "status"),
+                (command.limit != DEFAULT_DEPLOYMENTS_LIMIT, "limit"),
+            ]),
+            DeploymentsSubCommand::Logs(_) => None,
+            DeploymentsSubCommand::Diff(command) => filter_existing_arguments(&[(command.production, "production")]),
+        }
+    }
+}
+
+impl ArgumentNames for EnvCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        match &self.command {
+            EnvSubCommand::List(command) => {
+                filter_existing_arguments(&[(command.environment.is_some(), "environment")])
+            }
+            EnvSubCommand::Set(command) => {
+                filter_existing_arguments(&[(!command.environment.is_empty(), "environment")])
+            }
+            EnvSubCommand::Unset(_) => None,
+            EnvSubCommand::Pull(command) => filter_existing_arguments(&[
+                (command.environment != EnvironmentArgument::Production, "environment"),
+                (command.file.is_some(), "file"),
+                (command.yes, "yes"),
+            ]),
+            EnvSubCommand::Push(command) => filter_existing_arguments(&[
+                (!command.environment.is_empty(), "environment"),
+                (command.file.is_some(), "file"),
+                (command.yes, "yes"),
+            ]),
+        }
+    }
+}
+
+impl ArgumentNames for ApiKeysCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        match &self.command {
+            ApiKeysSubCommand::List(command) => filter_existing_arguments(&[(command.reveal, "reveal")]),
+            ApiKeysSubCommand::Create(command) => {
+                filter_existing_arguments(&[(command.environment != EnvironmentArgument::Production, "environment")])
+            }
+            ApiKeysSubCommand::Rename(_) => None,
+            ApiKeysSubCommand::Delete(command) => filter_existing_arguments(&[(command.yes, "yes")]),
+        }
+    }
+}
+
+impl ArgumentNames for ResolversCommand {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        match &self.command {
+            ResolversSubCommand::Test(command) => filter_existing_arguments(&[
+                (command.name.is_some(), "name"),
+                (command.update, "update"),
+                (command.timeout != DEFAULT_RESOLVER_TIMEOUT_SECONDS, "timeout"),
+            ]),
+            ResolversSubCommand::Logs(command) => filter_existing_arguments(&[(command.name.is_some(), "name")]),
+        }
+    }
+}
+
+impl ArgumentNames for DevServerArguments {
+    fn argument_names(&self) -> Option<Vec<&'static str>> {
+        filter_existing_arguments(&[(self.port.is_some(), "port"), (self.https, "https")])
+    }
+}
+
 impl ArgumentNames for SubCommand {
     fn argument_names(&self) -> Option<Vec<&'static str>> {
         match self {
             SubCommand::Dev(command) => command.argument_names(),
             SubCommand::Init(command) => command.argument_names(),
             SubCommand::Create(command) => command.argument_names(),
+            SubCommand::Deploy(command) => command.argument_names(),
+            SubCommand::Link(command) => command.argument_names(),
+            SubCommand::Login(command) => command.argument_names(),
+            SubCommand::Deployments(command) => command.argument_names(),
+            SubCommand::Env(command) => command.argument_names(),
+            SubCommand::ApiKeys(command) => command.argument_names(),
+            SubCommand::Metrics(command) => command.argument_names(),
+            SubCommand::Org(command) => command.argument_names(),
+            SubCommand::Usage(command) => command.argument_names(),
+            SubCommand::Resolvers(command) => command.argument_names(),
+            SubCommand::Status(StatusCommand { server }) | SubCommand::Reload(ReloadCommand { server }) => {
+                server.argument_names()
+            }
             SubCommand::Reset
-            | SubCommand::Login
             | SubCommand::Logout
-            | SubCommand::Deploy
-            | SubCommand::Link
+            | SubCommand::Whoami
+            | SubCommand::Profiles(_)
             | SubCommand::Unlink
             | SubCommand::Completions(_) => None,
         }
@@ -200,7 +1077,24 @@ impl SubCommand {
     pub(crate) fn in_project_context(&self) -> bool {
         matches!(
             self,
-            Self::Dev(_) | Self::Create(_) | Self::Deploy | Self::Link | Self::Unlink | Self::Reset
+            Self::Dev(_)
+                | Self::Create(_)
+                | Self::Deploy(_)
+                | Self::Deployments(_)
+                | Self::Env(_)
+                | Self::ApiKeys(_)
+                | Self::Metrics(_)
+                | Self::Usage(_)
+                | Self::Link(_)
+                | Self::Unlink
+                | Self::Reset
+                | Self::Resolvers(_)
+                | Self::Status(StatusCommand {
+                    server: DevServerArguments { port: None, .. }
+                })
+                | Self::Reload(ReloadCommand {
+                    server: DevServerArguments { port: None, .. }
+                })
         )
     }
 }
@@ -212,12 +1106,23 @@ impl AsRef<str> for SubCommand {
             SubCommand::Completions(_) => "completions",
             SubCommand::Init(_) => "init",
             SubCommand::Reset => "reset",
-            SubCommand::Login => "login",
+            SubCommand::Login(_) => "login",
             SubCommand::Logout => "logout",
+            SubCommand::Whoami => "whoami",
+            SubCommand::Profiles(_) => "profiles",
+            SubCommand::Org(_) => "org",
             SubCommand::Create(_) => "create",
-            SubCommand::Deploy => "deploy",
-            SubCommand::Link => "link",
+            SubCommand::Deploy(_) => "deploy",
+            SubCommand::Deployments(_) => "deployments",
+            SubCommand::Env(_) => "env",
+            SubCommand::ApiKeys(_) => "api-keys",
+            SubCommand::Metrics(_) => "metrics",
+            SubCommand::Usage(_) => "usage",
+            SubCommand::Link(_) => "link",
             SubCommand::Unlink => "unlink",
+            SubCommand::Resolvers(_) => "resolvers",
+            SubCommand::Status(_) => "status",
+            SubCommand::Reload(_) => "reload",
         }
     }
 }
@@ -240,6 +1145,13 @@ pub struct Args {
     /// An optional replacement path for the home directory
     #[arg(long)]
     pub home: Option<PathBuf>,
+    /// The profile to use instead of the active one, see 'grafbase profiles'
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    /// Redact matches of a regular expression in traces and resolver output, in addition to the values
+    /// loaded from .env files. Can be passed multiple times
+    #[arg(long, global = true, value_name = "PATTERN")]
+    pub redact: Vec<String>,
 }
 
 impl Args {
@@ -250,4 +1162,59 @@ impl Args {
             DEFAULT_LOG_FILTER
         }
     }
+
+    pub fn output_format(&self) -> OutputFormat {
+        match &self.command {
+            SubCommand::Dev(command) => command.output,
+            SubCommand::Metrics(MetricsCommand { json: true, .. })
+            | SubCommand::Usage(UsageCommand { json: true, .. }) => OutputFormat::Json,
+            _ => OutputFormat::Text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Args, DeploymentStatus, DeploymentsCommand, DeploymentsListCommand, DeploymentsSubCommand, Parser, SubCommand,
+        DEFAULT_DEPLOYMENTS_LIMIT,
+    };
+
+    fn deployments_list(arguments: &[&str]) -> Option<DeploymentsListCommand> {
+        let arguments = ["grafbase", "deployments", "list"].iter().chain(arguments);
+
+        match Args::try_parse_from(arguments).ok()?.command {
+            SubCommand::Deployments(DeploymentsCommand {
+                command: DeploymentsSubCommand::List(command),
+            }) => Some(command),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn deployment_filters() {
+        let command = deployments_list(&["-s", "in-progress", "--status", "failed", "-b", "main", "-l", "3"]).unwrap();
+
+        assert_eq!(command.branch.as_deref(), Some("main"));
+        assert_eq!(
+            command.statuses(),
+            [DeploymentStatus::InProgress, DeploymentStatus::Failed]
+        );
+        assert_eq!(command.limit, 3);
+    }
+
+    #[test]
+    fn deployment_filters_default_to_all() {
+        let command = deployments_list(&[]).unwrap();
+
+        assert_eq!(command.branch, None);
+        assert!(command.statuses().is_empty());
+        assert_eq!(command.limit, DEFAULT_DEPLOYMENTS_LIMIT);
+    }
+
+    #[test]
+    fn deployment_filters_reject_unknown_statuses() {
+        assert!(deployments_list(&["--status", "done"]).is_none());
+        assert!(deployments_list(&["--status", "in_progress"]).is_none());
+    }
 }
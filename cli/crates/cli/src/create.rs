@@ -1,4 +1,8 @@
-use crate::{errors::CliError, output::report, prompts::handle_inquire_error};
+use crate::{
+    errors::CliError,
+    output::report,
+    prompts::{handle_inquire_error, require_terminal},
+};
 use backend::api::{
     create,
     types::{Account, DatabaseRegion},
@@ -65,6 +69,8 @@ async fn from_arguments(arguments: &CreateArguments<'_>) -> Result<(), CliError>
 }
 
 async fn interactive() -> Result<(), CliError> {
+    require_terminal("--name, --account and --regions")?;
+
     let project = Project::get();
 
     let (accounts, available_regions, closest_region) = create::get_viewer_data_for_creation()
@@ -2,9 +2,24 @@ use crate::{errors::CliError, output::report};
 use backend::api::deploy;
 
 #[tokio::main]
-pub async fn deploy() -> Result<(), CliError> {
-    report::deploy();
-    deploy::deploy().await.map_err(CliError::BackendApiError)?;
+pub async fn deploy(branch: Option<&str>, dry_run: bool) -> Result<(), CliError> {
+    if dry_run {
+        let summary = deploy::dry_run().await.map_err(CliError::BackendApiError)?;
+        report::archive_summary(&summary);
+        return Ok(());
+    }
+
+    report::deploy(branch);
+
+    let deployment_id = deploy::deploy(branch).await.map_err(CliError::BackendApiError)?;
+
+    report::deploy_uploaded();
+
+    deploy::follow_deployment(&deployment_id, |entry| report::deployment_log_entry(&entry))
+        .await
+        .map_err(CliError::BackendApiError)?;
+
     report::deploy_success();
+
     Ok(())
 }
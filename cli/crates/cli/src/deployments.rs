@@ -0,0 +1,50 @@
+use crate::{errors::CliError, output::report};
+use backend::api::{
+    deployments,
+    types::{DeploymentDiffBase, DeploymentStatus},
+};
+
+#[tokio::main]
+pub async fn list(branch: Option<&str>, statuses: &[DeploymentStatus], limit: i32) -> Result<(), CliError> {
+    let deployments = deployments::list(branch, statuses, limit)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if deployments.is_empty() {
+        report::no_deployments();
+    }
+
+    for deployment in &deployments {
+        report::deployment(deployment);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn logs(deployment_id: &str) -> Result<(), CliError> {
+    let (status, entries) = deployments::logs(deployment_id)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::deployment_status(status);
+
+    for entry in &entries {
+        report::deployment_log_entry(entry);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn diff(deployment_id: &str, base: DeploymentDiffBase) -> Result<(), CliError> {
+    match deployments::diff(deployment_id, base)
+        .await
+        .map_err(CliError::BackendApiError)?
+    {
+        Some(diff) => report::schema_diff(&diff),
+        None => report::no_schema_diff(base),
+    }
+
+    Ok(())
+}
@@ -1,52 +1,83 @@
-use crate::output::report;
+use crate::cli_input::OutputFormat;
+use crate::output::{json, report};
 use crate::CliError;
-use backend::server_api::start_server;
-use backend::types::ServerMessage;
+use backend::errors::BackendError;
+use backend::server_api::{follow_server, start_server};
+use backend::types::{DevServerInfo, EnvironmentOptions, HttpsOptions, ServerMessage, ShutdownHandle, WatchOptions};
 use common::utils::get_thread_panic_message;
-use std::sync::Once;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::{Once, OnceLock};
 use std::thread;
+use std::time::Duration;
 
 static READY: Once = Once::new();
 
+static SHUTDOWN: OnceLock<ShutdownHandle> = OnceLock::new();
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
 /// cli wrapper for [`backend::server_api::start_server`]
 ///
 /// # Errors
 ///
 /// returns [`CliError::BackendError`] if the the local gateway returns an error
 ///
+/// returns [`CliError::ReadEnvFile`] if one of the additional environment variable files cannot be read
+///
 /// returns [`CliError::ServerPanic`] if the development server panics
-pub fn dev(search: bool, watch: bool, external_port: u16, tracing: bool) -> Result<(), CliError> {
+#[allow(clippy::too_many_arguments)]
+pub fn dev(
+    search: bool,
+    attach: bool,
+    watch: Option<WatchOptions>,
+    https: Option<HttpsOptions>,
+    external_address: SocketAddr,
+    mode: String,
+    env_files: &[PathBuf],
+    resolver_timeout_seconds: u64,
+    output: OutputFormat,
+    tracing: bool,
+) -> Result<(), CliError> {
     trace!("attempting to start server");
 
-    let (server_handle, receiver) =
-        start_server(external_port, search, watch, tracing).map_err(CliError::BackendError)?;
+    handle_interrupts(output);
+
+    // resolved upfront to report missing files before starting and to be independent of the working directory
+    let env_files = env_files
+        .iter()
+        .map(|path| {
+            path.canonicalize()
+                .map_err(|error| CliError::ReadEnvFile(path.clone(), error))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let environment_options = EnvironmentOptions { mode, env_files };
+
+    let resolver_timeout = Duration::from_secs(resolver_timeout_seconds);
+
+    let reported_https = https.clone();
+
+    let (server_handle, receiver, shutdown) = match start_server(
+        external_address,
+        search,
+        watch,
+        https,
+        environment_options,
+        resolver_timeout,
+        tracing,
+    ) {
+        Ok(server_info) => server_info,
+        Err(BackendError::DevServerRunning(info)) if attach => return attach_to_server(&info, output),
+        Err(error) => return Err(CliError::BackendError(error)),
+    };
+
+    SHUTDOWN.set(shutdown).expect("must only be set once");
 
     let reporter_handle = thread::spawn(move || {
-        let mut resolvers_reported = false;
-
-        while let Ok(message) = receiver.recv() {
-            match message {
-                ServerMessage::Ready(port) => {
-                    READY.call_once(|| report::start_server(resolvers_reported, port, external_port));
-                }
-                ServerMessage::Reload(path) => report::reload(path),
-                ServerMessage::StartResolverBuild(resolver_name) => {
-                    report::start_resolver_build(&resolver_name);
-                }
-                ServerMessage::CompleteResolverBuild { name, duration } => {
-                    resolvers_reported = true;
-                    report::complete_resolver_build(&name, duration);
-                }
-                ServerMessage::ResolverMessage {
-                    resolver_name,
-                    message,
-                    level,
-                } => {
-                    report::resolver_message(&resolver_name, &message, level);
-                }
-                ServerMessage::CompilationError(error) => report::error(&CliError::CompilationError(error)),
-            }
-        }
+        report_messages(&receiver, reported_https.as_ref(), external_address, output);
     });
 
     server_handle
@@ -59,5 +90,109 @@ pub fn dev(search: bool, watch: bool, external_port: u16, tracing: bool) -> Resu
 
     reporter_handle.join().expect("cannot panic");
 
+    if output == OutputFormat::Text && SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+        report::goodbye();
+    }
+
+    Ok(())
+}
+
+/// the first Ctrl-C stops the server gracefully, a second one (or one while attached or before the server started)
+/// exits right away
+fn handle_interrupts(output: OutputFormat) {
+    // ignoring any errors to fall back to the normal handler if there's an issue
+    let _set_handler_result = ctrlc::set_handler(move || match SHUTDOWN.get() {
+        Some(shutdown) if !SHUTDOWN_REQUESTED.swap(true, Ordering::SeqCst) => {
+            if output == OutputFormat::Text {
+                report::shutting_down();
+            }
+            shutdown.shutdown();
+        }
+        _ => {
+            if output == OutputFormat::Text {
+                report::goodbye();
+            }
+            process::exit(exitcode::OK);
+        }
+    });
+}
+
+/// follows the output of the development server already running in the current project
+fn attach_to_server(info: &DevServerInfo, output: OutputFormat) -> Result<(), CliError> {
+    let (follow_handle, receiver) = follow_server(info.local_address(), info.https).map_err(CliError::BackendError)?;
+
+    let external_address = SocketAddr::new(info.host, info.port);
+
+    // the server already reported its readiness, so the reachable urls are reported upfront
+    let https = info.https.then_some(HttpsOptions::LocalCertificateAuthority);
+
+    if output == OutputFormat::Text {
+        report::attach(info);
+        READY.call_once(|| {
+            report::start_server(false, https.as_ref(), external_address.ip(), info.port, info.port);
+        });
+    }
+
+    let reporter_handle = thread::spawn(move || {
+        report_messages(&receiver, https.as_ref(), external_address, output);
+    });
+
+    follow_handle
+        .join()
+        .map_err(|parameter| match get_thread_panic_message(&parameter) {
+            Some(message) => CliError::ServerPanic(message),
+            None => CliError::ServerPanic("unknown error".to_owned()),
+        })?
+        .map_err(CliError::BackendError)?;
+
+    reporter_handle.join().expect("cannot panic");
+
     Ok(())
 }
+
+fn report_messages(
+    receiver: &Receiver<ServerMessage>,
+    https: Option<&HttpsOptions>,
+    external_address: SocketAddr,
+    output: OutputFormat,
+) {
+    let mut resolvers_reported = false;
+
+    while let Ok(message) = receiver.recv() {
+        if output == OutputFormat::Json {
+            json::server_message(&message);
+            continue;
+        }
+
+        match message {
+            ServerMessage::Ready(port) => {
+                READY.call_once(|| {
+                    report::start_server(
+                        resolvers_reported,
+                        https,
+                        external_address.ip(),
+                        port,
+                        external_address.port(),
+                    );
+                });
+            }
+            ServerMessage::Reload(paths) => report::reload(&paths),
+            ServerMessage::StartResolverBuild(resolver_name) => {
+                report::start_resolver_build(&resolver_name);
+            }
+            ServerMessage::CompleteResolverBuild { name, duration } => {
+                resolvers_reported = true;
+                report::complete_resolver_build(&name, duration);
+            }
+            ServerMessage::ResolverMessage {
+                resolver_name,
+                message,
+                level,
+            } => {
+                report::resolver_message(&resolver_name, &message, level);
+            }
+            ServerMessage::CompilationError(error) => report::error(&CliError::CompilationError(error)),
+            ServerMessage::Warnings(warnings) => report::warnings(&warnings),
+        }
+    }
+}
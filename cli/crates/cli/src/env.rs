@@ -0,0 +1,109 @@
+use crate::{errors::CliError, output::report, prompts::confirm};
+use backend::api::{environment_variables, types::BranchEnvironment};
+use common::{consts::GRAFBASE_ENV_FILE_NAME, environment::Project};
+use std::path::{Path, PathBuf};
+
+#[tokio::main]
+pub async fn list(environment: Option<BranchEnvironment>) -> Result<(), CliError> {
+    let variables = environment_variables::list().await.map_err(CliError::BackendApiError)?;
+
+    let variables: Vec<_> = variables
+        .iter()
+        .filter(|variable| environment.map_or(true, |environment| variable.environments.contains(&environment)))
+        .collect();
+
+    if variables.is_empty() {
+        report::no_environment_variables();
+    }
+
+    for variable in variables {
+        report::environment_variable(variable);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn set(name: &str, value: &str, environments: Option<&[BranchEnvironment]>) -> Result<(), CliError> {
+    environment_variables::set(name, value, environments)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::environment_variable_set(name);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn unset(name: &str) -> Result<(), CliError> {
+    environment_variables::unset(name)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::environment_variable_unset(name);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn pull(environment: BranchEnvironment, file: Option<&Path>, yes: bool) -> Result<(), CliError> {
+    let path = dot_env_file_path(file);
+
+    let changes = environment_variables::pull_changes(environment, &path)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if changes.is_empty() {
+        report::environment_variables_up_to_date();
+        return Ok(());
+    }
+
+    report::environment_variable_changes(&changes);
+
+    if !yes && !confirm(&format!("Write these changes to {}?", path.display()))? {
+        return Ok(());
+    }
+
+    environment_variables::pull(&path, &changes)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::environment_variables_pulled(&path);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn push(environments: Option<&[BranchEnvironment]>, file: Option<&Path>, yes: bool) -> Result<(), CliError> {
+    let path = dot_env_file_path(file);
+
+    let changes = environment_variables::push_changes(environments, &path)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if changes.is_empty() {
+        report::environment_variables_up_to_date();
+        return Ok(());
+    }
+
+    report::environment_variable_changes(&changes);
+
+    if !yes && !confirm("Upload these changes to the linked project?")? {
+        return Ok(());
+    }
+
+    environment_variables::push(environments, &changes)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::environment_variables_pushed();
+
+    Ok(())
+}
+
+fn dot_env_file_path(file: Option<&Path>) -> PathBuf {
+    file.map_or_else(
+        || Project::get().grafbase_directory_path.join(GRAFBASE_ENV_FILE_NAME),
+        Path::to_owned,
+    )
+}
@@ -1,7 +1,10 @@
-use backend::api::errors::{ApiError, CreateError, DeployError, LoginApiError};
+use backend::api::errors::{
+    ApiError, ApiKeyError, CreateError, DeployError, EnvironmentVariableError, LoginApiError, OrganizationError,
+};
 use backend::errors::{BackendError, ServerError};
 use common::errors::CommonError;
 use std::io::{self, ErrorKind};
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -29,9 +32,15 @@ pub enum CliError {
     /// returned if the login server panics
     #[error("{0}")]
     LoginPanic(String),
+    /// returned if the access token passed to `login --token` could not be read from stdin
+    #[error("could not read the access token from stdin\ncaused by: {0}")]
+    ReadAccessToken(io::Error),
     /// returned if an interactive prompt fails due to the input device not being a TTY
     #[error("could not show an interactive prompt due to the input device not being a TTY")]
     PromptNotTTY,
+    /// returned if a command would prompt for input while not being run in a terminal
+    #[error("could not prompt for input as the CLI is not running in an interactive terminal")]
+    NotInteractive(&'static str),
     /// returned if an IO error is encountered when trying to display an interactive prompt
     #[error("encountered an IO error while showing an interactive prompt\ncaused by: {0}")]
     PromptIoError(io::Error),
@@ -44,6 +53,12 @@ pub enum CliError {
     /// returned if the schema parser failed to compile a file
     #[error("{0}")]
     CompilationError(String),
+    /// returned if an environment variables file passed via `--env-file` cannot be read
+    #[error("could not read the environment variables file {0}\ncaused by: {1}")]
+    ReadEnvFile(PathBuf, io::Error),
+    /// returned if any of the resolver tests failed
+    #[error("{0} resolver test(s) failed")]
+    ResolverTestsFailed(usize),
 }
 
 #[cfg(target_family = "windows")]
@@ -71,6 +86,10 @@ impl CliError {
             }
             Self::BackendError(BackendError::DownloadRepoArchive(_)) => Some("this may be caused by an incorrect URL or trying to use a private repository as a template".to_owned()),
             Self::BackendError(BackendError::TemplateNotFound) => Some("this is likely to be caused by an incorrect template name or URL, or by an external template directory not containing a grafbase directory".to_owned()),
+            Self::BackendError(BackendError::DevServerRunning(_)) => Some("try 'grafbase dev --attach' to follow its output, or stop it before starting another one".to_owned()),
+            Self::BackendError(BackendError::DevServerStarting) => Some("wait for it to start and try again with 'grafbase dev --attach' to follow its output".to_owned()),
+            Self::BackendError(BackendError::DevServerNotRunning) => Some("start one with 'grafbase dev', or pass the --port of a development server running elsewhere".to_owned()),
+            Self::BackendError(BackendError::DevServerUnreachable(_)) => Some("make sure 'grafbase dev' is running, try passing the --port it is running on".to_owned()),
             Self::BackendError(BackendError::PortInUse(_)) => Some("try using a different --port number or supplying the --search flag".to_owned()),
             Self::BackendError(BackendError::ProjectDirectoryExists(_)) => Some("try using a different name for your new project".to_owned()),
             Self::BackendError(BackendError::StartDownloadRepoArchive(_, _)) => Some("this may be caused by connection issues".to_owned()),
@@ -81,10 +100,32 @@ impl CliError {
             Self::ServerError(ServerError::OutdatedNode(_, min_version)) => Some(format!("please update your Node.js version to {min_version} or higher to continue (https://nodejs.org/en/download)")),
             Self::BackendApiError(ApiError::RequestError |
             ApiError::CreateError(CreateError::Unknown) |
-            ApiError::DeployError(DeployError::Unknown)) => Some("you may be using an older version of the Grafbase CLI, try updating".to_owned()),
+            ApiError::DeployError(DeployError::Unknown) |
+            ApiError::EnvironmentVariableError(EnvironmentVariableError::Unknown) |
+            ApiError::ApiKeyError(ApiKeyError::Unknown) |
+            ApiError::LoginApiError(LoginApiError::Unknown) |
+            ApiError::OrganizationError(OrganizationError::Unknown)) => Some("you may be using an older version of the Grafbase CLI, try updating".to_owned()),
             Self::BackendApiError(ApiError::NotLoggedIn | ApiError::CorruptCredentialsFile) => Some("try running 'grafbase login'".to_owned()),
+            Self::BackendApiError(ApiError::LoginApiError(LoginApiError::EmptyAccessToken)) => Some("try piping the token into the command, e.g. 'echo $TOKEN | grafbase login --token'".to_owned()),
+            Self::BackendApiError(ApiError::LoginApiError(LoginApiError::DeviceCodeExpired)) => Some("try running 'grafbase login --device' again and confirming the login before the code expires".to_owned()),
+            Self::BackendApiError(ApiError::CorruptProfilesFile) => Some("try running 'grafbase profiles use <name>' to reset the active profile".to_owned()),
+            Self::BackendApiError(ApiError::LoginApiError(LoginApiError::WriteKeyring(_))) => Some("try logging in without --keyring to store the access token in ~/.grafbase instead".to_owned()),
             Self::BackendApiError(ApiError::ProjectAlreadyLinked) => Some("try running 'grafbase deploy'".to_owned()),
-            Self::BackendApiError(ApiError::CorruptProjectMetadataFile | ApiError::UnlinkedProject) => Some("try running 'grafbase link'".to_owned()),
+            Self::BackendApiError(ApiError::CorruptProjectMetadataFile | ApiError::UnlinkedProject | ApiError::LinkedProjectDoesNotExist) => Some("try running 'grafbase link'".to_owned()),
+            Self::BackendApiError(ApiError::AccountDoesNotExist(_) | ApiError::ProjectDoesNotExist(_)) => Some("check that you are logged in to an account that has access to the project with 'grafbase login'".to_owned()),
+            Self::BackendApiError(ApiError::DeployError(DeployError::DeploymentFailed)) => Some("see the deployment logs above for the cause of the failure".to_owned()),
+            Self::BackendApiError(ApiError::DeployError(DeployError::DeploymentDoesNotExist)) => Some("try running 'grafbase deployments list' to find the ID of a deployment".to_owned()),
+            Self::BackendApiError(ApiError::EnvironmentVariableError(EnvironmentVariableError::DoesNotExist(_))) => Some("try running 'grafbase env list' to see the existing variables".to_owned()),
+            Self::BackendApiError(ApiError::ApiKeyError(ApiKeyError::KeyDoesNotExist(_))) => Some("try running 'grafbase api-keys list' to find the ID of a key".to_owned()),
+            Self::BackendApiError(ApiError::ApiKeyError(ApiKeyError::KeyLimitExceeded)) => Some("try deleting an unused key with 'grafbase api-keys delete'".to_owned()),
+            Self::BackendApiError(ApiError::OrganizationError(OrganizationError::OrganizationDoesNotExist(_))) => Some("try running 'grafbase org list' to find the slug of an organization".to_owned()),
+            Self::BackendApiError(ApiError::OrganizationError(OrganizationError::MemberDoesNotExist(_))) => Some("try running 'grafbase org members <organization>' to find the email address of a member".to_owned()),
+            Self::BackendApiError(ApiError::OrganizationError(OrganizationError::InviteDoesNotExist(_))) => Some("the invite may have expired or been cancelled, try running 'grafbase org invites <organization>'".to_owned()),
+            Self::BackendApiError(ApiError::OrganizationError(OrganizationError::SlugAlreadyExists | OrganizationError::SlugReserved)) => Some("try using a different slug".to_owned()),
+            Self::BackendApiError(ApiError::OrganizationError(OrganizationError::NotAllowedToSendInvites | OrganizationError::NotAllowedToCancelInvites)) => Some("ask an owner of the organization to manage its invites".to_owned()),
+            Self::BackendApiError(ApiError::ApiKeyError(ApiKeyError::MustLeaveAtLeastOneKeyForEnvironment)) => Some("try creating a new key for the environment with 'grafbase api-keys create' first".to_owned()),
+            Self::NotInteractive(arguments) => Some(format!("try passing {arguments} to run the command without prompts")),
+            Self::ResolverTestsFailed(_) => Some("if the new results are correct, try running 'grafbase resolvers test --update'".to_owned()),
             _ => None,
         }
     }
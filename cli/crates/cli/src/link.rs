@@ -1,4 +1,9 @@
-use crate::{errors::CliError, output::report, prompts::handle_inquire_error};
+use crate::{
+    cli_input::ProjectReference,
+    errors::CliError,
+    output::report,
+    prompts::{handle_inquire_error, require_terminal},
+};
 use backend::api::{
     link,
     types::{AccountWithProjects, Project},
@@ -23,8 +28,46 @@ impl Display for ProjectSelection {
     }
 }
 
+/// the project to link to when passed as an argument
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum LinkTarget<'a> {
+    Slug(&'a ProjectReference),
+    Id(&'a str),
+}
+
 #[tokio::main]
-pub async fn link() -> Result<(), CliError> {
+pub async fn link(target: Option<LinkTarget<'_>>) -> Result<(), CliError> {
+    match target {
+        Some(target) => from_arguments(target).await,
+        None => interactive().await,
+    }
+}
+
+async fn from_arguments(target: LinkTarget<'_>) -> Result<(), CliError> {
+    let project_slug = match target {
+        LinkTarget::Slug(ProjectReference {
+            account_slug,
+            project_slug,
+        }) => {
+            link::link_project_by_slug(account_slug, project_slug)
+                .await
+                .map_err(CliError::BackendApiError)?;
+            project_slug.clone()
+        }
+        LinkTarget::Id(project_id) => link::link_project_by_id(project_id)
+            .await
+            .map_err(CliError::BackendApiError)?,
+    };
+
+    report::linked(&project_slug);
+
+    Ok(())
+}
+
+async fn interactive() -> Result<(), CliError> {
+    require_terminal("--project or --project-id")?;
+
     let accounts = link::get_viewer_data_for_link()
         .await
         .map_err(CliError::BackendApiError)?;
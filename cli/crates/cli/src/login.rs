@@ -1,17 +1,94 @@
 use crate::{errors::CliError, output::report};
-use backend::api::{login, types::LoginMessage};
+use backend::api::{
+    login,
+    types::{CredentialStorage, LoginMessage},
+};
 use common::utils::get_thread_panic_message;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::{
+    io::{self, IsTerminal},
     sync::mpsc::{channel, RecvTimeoutError},
     thread::{sleep, spawn},
     time::Duration,
 };
 
-pub fn login() -> Result<(), CliError> {
+/// how the access token is obtained when logging in
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum LoginMethod {
+    /// via a browser redirecting to a local callback server
+    Browser,
+    /// via a token read from stdin
+    Token,
+    /// via a code confirmed in a browser on any device while the CLI polls for the token
+    Device,
+}
+
+pub fn login(method: LoginMethod, storage: CredentialStorage) -> Result<(), CliError> {
+    match method {
+        LoginMethod::Browser => browser_login(storage),
+        LoginMethod::Token => token_login(storage),
+        LoginMethod::Device => device_login(storage),
+    }
+}
+
+#[tokio::main]
+async fn token_login(storage: CredentialStorage) -> Result<(), CliError> {
+    let stdin = io::stdin();
+
+    if stdin.is_terminal() {
+        report::login_token_prompt();
+    }
+
+    let mut access_token = String::new();
+
+    stdin.read_line(&mut access_token).map_err(CliError::ReadAccessToken)?;
+
+    login::login_with_token(&access_token, storage)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::login_success();
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn device_login(storage: CredentialStorage) -> Result<(), CliError> {
+    let authorization = login::start_device_login().await.map_err(CliError::BackendApiError)?;
+
+    report::device_login(&authorization.verification_url, &authorization.user_code);
+
+    let spinner = waiting_spinner();
+    spinner.enable_steady_tick(Duration::from_millis(250));
+
+    match login::complete_device_login(&authorization, storage).await {
+        Ok(()) => {
+            spinner.finish_with_message("Token received");
+            report::login_success();
+            Ok(())
+        }
+        Err(error) => {
+            spinner.finish_and_clear();
+            Err(CliError::BackendApiError(error))
+        }
+    }
+}
+
+fn waiting_spinner() -> ProgressBar {
+    ProgressBar::new_spinner()
+        .with_message("Waiting for authentication to be completed")
+        .with_style(
+            ProgressStyle::with_template("{spinner} {wide_msg:.dim}")
+                .expect("must parse")
+                .tick_chars("🕛🕐🕑🕒🕓🕔🕕🕖🕗🕘🕙🕚✅"),
+        )
+}
+
+fn browser_login(storage: CredentialStorage) -> Result<(), CliError> {
     let (message_sender, message_receiver) = channel();
 
-    let login_handle = spawn(|| login::login(message_sender).map_err(CliError::BackendApiError));
+    let login_handle = spawn(move || login::login(message_sender, storage).map_err(CliError::BackendApiError));
 
     if let Ok(LoginMessage::CallbackUrl(url)) = message_receiver.recv() {
         report::login(&url);
@@ -22,13 +99,7 @@ pub fn login() -> Result<(), CliError> {
         let _: Result<_, _> = webbrowser::open(&url);
     };
 
-    let spinner = ProgressBar::new_spinner()
-        .with_message("Waiting for authentication to be completed")
-        .with_style(
-            ProgressStyle::with_template("{spinner} {wide_msg:.dim}")
-                .expect("must parse")
-                .tick_chars("🕛🕐🕑🕒🕓🕔🕕🕖🕗🕘🕙🕚✅"),
-        );
+    let spinner = waiting_spinner();
 
     loop {
         match message_receiver.recv_timeout(Duration::from_millis(250)) {
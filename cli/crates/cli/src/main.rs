@@ -1,26 +1,39 @@
 #![forbid(unsafe_code)]
 
+mod api_keys;
 mod cli_input;
 mod create;
 mod deploy;
+mod deployments;
 mod dev;
+mod env;
 mod errors;
 mod init;
 mod link;
 mod login;
 mod logout;
+mod metrics;
+mod org;
 mod output;
 mod panic_hook;
+mod profiles;
 mod prompts;
+mod reload;
 mod reset;
+mod resolvers;
+mod status;
 mod unlink;
 mod watercolor;
+mod whoami;
 
 #[macro_use]
 extern crate log;
 
 use crate::{
-    cli_input::{Args, ArgumentNames, SubCommand},
+    cli_input::{
+        ApiKeysSubCommand, Args, ArgumentNames, DeploymentsSubCommand, EnvSubCommand, OrgSubCommand, OutputFormat,
+        ProfilesSubCommand, ResolversSubCommand, SubCommand,
+    },
     create::create,
     deploy::deploy,
     dev::dev,
@@ -28,13 +41,22 @@ use crate::{
     link::link,
     login::login,
     logout::logout,
+    reload::reload,
     reset::reset,
+    status::status,
     unlink::unlink,
+    whoami::whoami,
 };
 use clap::Parser;
-use common::{analytics::Analytics, environment::Environment};
+use common::{
+    analytics::Analytics,
+    environment::Environment,
+    redaction::{self, RedactedWriter},
+};
 use errors::CliError;
-use output::report;
+use output::{json, report};
+use std::io::Write;
+use std::net::SocketAddr;
 use std::process;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 use watercolor::ShouldColorize;
@@ -56,12 +78,35 @@ fn main() {
     process::exit(exit_code);
 }
 
+#[allow(clippy::too_many_lines)]
 fn try_main(args: Args) -> Result<(), CliError> {
     let filter = EnvFilter::builder().parse_lossy(args.log_filter());
 
-    tracing_subscriber::registry().with(fmt::layer()).with(filter).init();
+    redaction::add_patterns(&args.redact).map_err(CliError::CommonError)?;
+
+    // stdout only contains events in json mode
+    let json_output = args.output_format() == OutputFormat::Json;
+
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_writer(move || {
+            let writer: Box<dyn Write> = if json_output {
+                Box::new(std::io::stderr())
+            } else {
+                Box::new(std::io::stdout())
+            };
+            RedactedWriter::new(writer)
+        }))
+        .with(filter)
+        .init();
+
+    if json_output {
+        backend::server_api::reserve_stdout();
+    }
     trace!("subcommand: {}", args.command);
-    report::cli_header();
+
+    if !json_output {
+        report::cli_header();
+    }
 
     if args.command.in_project_context() {
         Environment::try_init_with_project(args.home).map_err(CliError::CommonError)?;
@@ -69,10 +114,16 @@ fn try_main(args: Args) -> Result<(), CliError> {
         Environment::try_init(args.home).map_err(CliError::CommonError)?;
     }
 
+    backend::api::profiles::select(args.profile).map_err(CliError::BackendApiError)?;
+
     Analytics::init().map_err(CliError::CommonError)?;
     Analytics::command_executed(args.command.as_ref(), args.command.argument_names());
 
-    report::warnings(&Environment::get().warnings);
+    if json_output {
+        json::warnings(&Environment::get().warnings);
+    } else {
+        report::warnings(&Environment::get().warnings);
+    }
 
     match args.command {
         SubCommand::Completions(cmd) => {
@@ -80,22 +131,74 @@ fn try_main(args: Args) -> Result<(), CliError> {
 
             Ok(())
         }
-        SubCommand::Dev(cmd) => {
-            // ignoring any errors to fall back to the normal handler if there's an issue
-            let _set_handler_result = ctrlc::set_handler(|| {
-                report::goodbye();
-                process::exit(exitcode::OK);
-            });
-
-            dev(cmd.search, !cmd.disable_watch, cmd.port, args.trace >= 2)
-        }
+        SubCommand::Dev(cmd) => dev(
+            cmd.search,
+            cmd.attach,
+            cmd.watch_options(),
+            cmd.https_options(),
+            SocketAddr::new(cmd.host, cmd.port),
+            cmd.mode,
+            &cmd.env_file,
+            cmd.resolver_timeout,
+            cmd.output,
+            args.trace >= 2,
+        ),
         SubCommand::Init(cmd) => init(cmd.name(), cmd.template()),
         SubCommand::Reset => reset(),
-        SubCommand::Login => login(),
+        SubCommand::Login(cmd) => login(cmd.login_method(), cmd.credential_storage()),
         SubCommand::Logout => logout(),
+        SubCommand::Whoami => whoami(),
+        SubCommand::Profiles(cmd) => match cmd.command {
+            ProfilesSubCommand::List => profiles::list(),
+            ProfilesSubCommand::Use(cmd) => profiles::use_profile(&cmd.name),
+        },
         SubCommand::Create(cmd) => create(&cmd.create_arguments()),
-        SubCommand::Deploy => deploy(),
-        SubCommand::Link => link(),
+        SubCommand::Deploy(cmd) => deploy(cmd.branch.as_deref(), cmd.dry_run),
+        SubCommand::Deployments(cmd) => match cmd.command {
+            DeploymentsSubCommand::List(cmd) => deployments::list(cmd.branch.as_deref(), &cmd.statuses(), cmd.limit),
+            DeploymentsSubCommand::Logs(cmd) => deployments::logs(&cmd.id),
+            DeploymentsSubCommand::Diff(cmd) => deployments::diff(&cmd.id, cmd.base()),
+        },
+        SubCommand::Env(cmd) => match cmd.command {
+            EnvSubCommand::List(cmd) => env::list(cmd.environment.map(Into::into)),
+            EnvSubCommand::Set(cmd) => env::set(&cmd.name, &cmd.value, cmd.environments().as_deref()),
+            EnvSubCommand::Unset(cmd) => env::unset(&cmd.name),
+            EnvSubCommand::Pull(cmd) => env::pull(cmd.environment.into(), cmd.file.as_deref(), cmd.yes),
+            EnvSubCommand::Push(cmd) => env::push(cmd.environments().as_deref(), cmd.file.as_deref(), cmd.yes),
+        },
+        SubCommand::ApiKeys(cmd) => match cmd.command {
+            ApiKeysSubCommand::List(cmd) => api_keys::list(cmd.reveal),
+            ApiKeysSubCommand::Create(cmd) => api_keys::create(&cmd.name, cmd.environment.into()),
+            ApiKeysSubCommand::Rename(cmd) => api_keys::rename(&cmd.id, &cmd.name),
+            ApiKeysSubCommand::Delete(cmd) => api_keys::delete(&cmd.id, cmd.yes),
+        },
+        SubCommand::Org(cmd) => match cmd.command {
+            OrgSubCommand::List => org::list(),
+            OrgSubCommand::Create(cmd) => org::create(&cmd.slug, cmd.name()),
+            OrgSubCommand::Members(cmd) => org::members(&cmd.organization),
+            OrgSubCommand::Invites(cmd) => org::invites(&cmd.organization),
+            OrgSubCommand::Invite(cmd) => org::invite(&cmd.organization, &cmd.email, cmd.role.into()),
+            OrgSubCommand::CancelInvite(cmd) => org::cancel_invite(&cmd.organization, &cmd.email),
+            OrgSubCommand::Accept(cmd) => org::accept_invite(&cmd.id),
+            OrgSubCommand::Decline(cmd) => org::decline_invite(&cmd.id),
+            OrgSubCommand::Role(cmd) => org::update_role(&cmd.organization, &cmd.email, cmd.role.into()),
+            OrgSubCommand::Remove(cmd) => org::remove_member(&cmd.organization, &cmd.email, cmd.yes),
+        },
+        SubCommand::Metrics(cmd) => metrics::metrics(cmd.branch.as_deref(), cmd.since(), cmd.json),
+        SubCommand::Usage(cmd) => metrics::usage(cmd.environment.map(Into::into), cmd.since(), cmd.json),
+        SubCommand::Link(cmd) => link(cmd.link_target()),
         SubCommand::Unlink => unlink(),
+        SubCommand::Resolvers(cmd) => match cmd.command {
+            ResolversSubCommand::Test(cmd) => resolvers::test(cmd.name(), cmd.update, cmd.timeout, args.trace >= 2),
+            ResolversSubCommand::Logs(cmd) => resolvers::logs(cmd.name()),
+        },
+        SubCommand::Status(cmd) => {
+            let (address, https) = cmd.server.resolve()?;
+            status(address, https)
+        }
+        SubCommand::Reload(cmd) => {
+            let (address, https) = cmd.server.resolve()?;
+            reload(address, https)
+        }
     }
 }
@@ -0,0 +1,37 @@
+use crate::{errors::CliError, output::report};
+use backend::api::{metrics, types::BranchEnvironment};
+use chrono::{Duration, Utc};
+
+#[tokio::main]
+pub async fn metrics(branch: Option<&str>, since: Duration, json: bool) -> Result<(), CliError> {
+    let end_date = Utc::now();
+
+    let metrics = metrics::branch_metrics(branch, end_date - since, end_date)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if json {
+        println!("{}", serde_json::to_string(&metrics).expect("must serialise"));
+    } else {
+        report::metrics(&metrics);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn usage(environment: Option<BranchEnvironment>, since: Duration, json: bool) -> Result<(), CliError> {
+    let end_date = Utc::now();
+
+    let usage = metrics::database_usage(environment, end_date - since, end_date)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if json {
+        println!("{}", serde_json::to_string(&usage).expect("must serialise"));
+    } else {
+        report::usage(&usage);
+    }
+
+    Ok(())
+}
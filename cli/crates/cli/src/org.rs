@@ -0,0 +1,132 @@
+use crate::{errors::CliError, output::report, prompts::confirm};
+use backend::api::{organizations, types::MemberRole};
+
+#[tokio::main]
+pub async fn list() -> Result<(), CliError> {
+    let organizations = organizations::list().await.map_err(CliError::BackendApiError)?;
+
+    if organizations.is_empty() {
+        report::no_organizations();
+    }
+
+    for organization in &organizations {
+        report::organization(organization);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn create(slug: &str, name: &str) -> Result<(), CliError> {
+    organizations::create(slug, name)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::organization_created(slug);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn members(organization: &str) -> Result<(), CliError> {
+    let members = organizations::members(organization)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    for member in &members {
+        report::member(member);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn invites(organization: &str) -> Result<(), CliError> {
+    let invites = organizations::invites(organization)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    if invites.is_empty() {
+        report::no_invites();
+    }
+
+    for invite in &invites {
+        report::invite(invite);
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn invite(organization: &str, email: &str, role: MemberRole) -> Result<(), CliError> {
+    organizations::invite(organization, email, role)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::invite_sent(organization, email);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn cancel_invite(organization: &str, email: &str) -> Result<(), CliError> {
+    organizations::cancel_invite(organization, email)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::invite_cancelled(email);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn accept_invite(id: &str) -> Result<(), CliError> {
+    let organization = organizations::accept_invite(id)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::invite_accepted(&organization);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn decline_invite(id: &str) -> Result<(), CliError> {
+    organizations::decline_invite(id)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::invite_declined();
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn update_role(organization: &str, email: &str, role: MemberRole) -> Result<(), CliError> {
+    organizations::update_role(organization, email, role)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::member_role_updated(email, role);
+
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn remove_member(organization: &str, email: &str, yes: bool) -> Result<(), CliError> {
+    if !yes
+        && !confirm(&format!(
+            "Remove {email} from {organization}? They will lose access to its projects"
+        ))?
+    {
+        return Ok(());
+    }
+
+    organizations::remove_member(organization, email)
+        .await
+        .map_err(CliError::BackendApiError)?;
+
+    report::member_removed(organization, email);
+
+    Ok(())
+}
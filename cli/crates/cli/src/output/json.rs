@@ -0,0 +1,73 @@
+//! JSON Lines output for `grafbase dev --output json`, one event per line on stdout
+
+use backend::types::ServerMessage;
+use common::environment::Warning;
+use common::types::ResolverMessageLevel;
+use serde_derive::Serialize;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    Ready {
+        port: u16,
+    },
+    Reload {
+        paths: &'a [PathBuf],
+    },
+    ResolverBuildStarted {
+        resolver_name: &'a str,
+    },
+    ResolverBuildCompleted {
+        resolver_name: &'a str,
+        duration_ms: u128,
+    },
+    ResolverMessage {
+        resolver_name: &'a str,
+        level: ResolverMessageLevel,
+        message: &'a str,
+    },
+    CompilationError {
+        message: &'a str,
+    },
+    Warning {
+        message: &'a str,
+        hint: Option<&'a str>,
+    },
+}
+
+fn emit(event: &Event<'_>) {
+    println!("{}", serde_json::to_string(event).expect("must serialise"));
+}
+
+pub fn server_message(message: &ServerMessage) {
+    match message {
+        ServerMessage::Ready(port) => emit(&Event::Ready { port: *port }),
+        ServerMessage::Reload(paths) => emit(&Event::Reload { paths }),
+        ServerMessage::StartResolverBuild(resolver_name) => emit(&Event::ResolverBuildStarted { resolver_name }),
+        ServerMessage::CompleteResolverBuild { name, duration } => emit(&Event::ResolverBuildCompleted {
+            resolver_name: name,
+            duration_ms: duration.as_millis(),
+        }),
+        ServerMessage::ResolverMessage {
+            resolver_name,
+            level,
+            message,
+        } => emit(&Event::ResolverMessage {
+            resolver_name,
+            level: *level,
+            message,
+        }),
+        ServerMessage::CompilationError(message) => emit(&Event::CompilationError { message }),
+        ServerMessage::Warnings(warnings) => self::warnings(warnings),
+    }
+}
+
+pub fn warnings(warnings: &[Warning]) {
+    for warning in warnings {
+        emit(&Event::Warning {
+            message: warning.message(),
+            hint: warning.hint(),
+        });
+    }
+}
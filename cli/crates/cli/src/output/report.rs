@@ -2,13 +2,35 @@ use crate::{
     errors::CliError,
     watercolor::{self, watercolor},
 };
-use colored::Colorize;
+use backend::api::types::{
+    ApiKey, ArchiveSummary, BranchMetrics, DatabaseUsage, Deployment, DeploymentDiffBase, DeploymentLogEntry,
+    DeploymentLogLevel, DeploymentStatus, EnvironmentVariable, EnvironmentVariableChange, Invite, Member, MemberRole,
+    Metric, MetricUnit, Organization, Profile, Viewer,
+};
+use backend::types::{
+    DevServerInfo, HttpsOptions, ResolverFixture, ResolverMessage, ResolverTestOutcome, ResolverTestReport,
+    ServerState, ServerStatus,
+};
+use colored::{ColoredString, Colorize};
 use common::types::ResolverMessageLevel;
 use common::{
-    consts::{GRAFBASE_DIRECTORY_NAME, GRAFBASE_SCHEMA_FILE_NAME, LOCALHOST},
-    environment::Warning,
+    consts::{
+        CERTIFICATES_DIRECTORY_NAME, GRAFBASE_DIRECTORY_NAME, GRAFBASE_SCHEMA_FILE_NAME,
+        LOCAL_CA_CERTIFICATE_FILE_NAME, RESOLVERS_DIRECTORY_NAME, RESOLVER_FIXTURES_DIRECTORY_NAME,
+    },
+    environment::{Environment, Warning},
+    utils::network_addresses,
 };
-use std::path::Path;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+const STATUS_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S UTC";
+
+/// the amount of trailing characters of API keys shown unless revealed
+const API_KEY_VISIBLE_CHARACTERS: usize = 6;
+
+/// the width of the bar of the largest value in a metric chart
+const METRIC_BAR_WIDTH: i64 = 40;
 
 /// reports to stdout that the server has started
 pub fn cli_header() {
@@ -18,7 +40,7 @@ pub fn cli_header() {
 }
 
 /// reports to stdout that the server has started
-pub fn start_server(resolvers_reported: bool, port: u16, start_port: u16) {
+pub fn start_server(resolvers_reported: bool, https: Option<&HttpsOptions>, host: IpAddr, port: u16, start_port: u16) {
     if resolvers_reported {
         println!();
     }
@@ -30,15 +52,46 @@ pub fn start_server(resolvers_reported: bool, port: u16, start_port: u16) {
         );
     }
     println!("📡 Listening on port {}\n", watercolor!("{port}", @BrightBlue));
+
+    let local_address = match host {
+        IpAddr::V4(address) if address.is_unspecified() => SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port),
+        IpAddr::V6(address) if address.is_unspecified() => SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), port),
+        address => SocketAddr::new(address, port),
+    };
+
+    let scheme = if https.is_some() { "https" } else { "http" };
+
     println!(
         "- Pathfinder: {}",
-        watercolor!("http://{LOCALHOST}:{port}", @BrightBlue)
+        watercolor!("{scheme}://{local_address}", @BrightBlue)
     );
     // TODO: use proper formatting here
     println!(
-        "- Endpoint:   {}\n",
-        watercolor!("http://{LOCALHOST}:{port}/graphql", @BrightBlue)
+        "- Endpoint:   {}",
+        watercolor!("{scheme}://{local_address}/graphql", @BrightBlue)
     );
+
+    for network_address in network_addresses(host) {
+        let network_address = SocketAddr::new(network_address, port);
+        println!(
+            "- Network:    {}",
+            watercolor!("{scheme}://{network_address}/graphql", @BrightBlue)
+        );
+    }
+
+    if let Some(HttpsOptions::LocalCertificateAuthority) = https {
+        let certificate_authority_path = Environment::get()
+            .user_dot_grafbase_path
+            .join(CERTIFICATES_DIRECTORY_NAME)
+            .join(LOCAL_CA_CERTIFICATE_FILE_NAME);
+        let certificate_authority_path = certificate_authority_path.display();
+        println!(
+            "\nThe certificate is issued by a local certificate authority, trust {} to avoid browser warnings",
+            watercolor!("{certificate_authority_path}", @BrightBlue)
+        );
+    }
+
+    println!();
 }
 
 pub fn project_created(name: Option<&str>) {
@@ -86,6 +139,10 @@ pub fn warnings(warnings: &[Warning]) {
     }
 }
 
+pub fn shutting_down() {
+    watercolor::output!("\n⏳ Shutting down, press Ctrl-C again to stop right away", @BrightBlue);
+}
+
 pub fn goodbye() {
     watercolor::output!("\n👋 See you next time!", @BrightBlue);
 }
@@ -118,11 +175,19 @@ pub fn resolver_message(resolver_name: &str, message: &str, level: ResolverMessa
     }
 }
 
-pub fn reload<P: AsRef<Path>>(path: P) {
-    println!(
-        "🔄 Detected a change in {path}, reloading",
-        path = path.as_ref().display()
-    );
+pub fn reload(paths: &[PathBuf]) {
+    // requested through the admin API (e.g. by `grafbase reload`) rather than a change
+    if paths.is_empty() {
+        println!("🔄 Reloading");
+        return;
+    }
+
+    let paths = paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("🔄 Detected a change in {paths}, reloading");
 }
 
 pub fn project_reset() {
@@ -138,6 +203,18 @@ pub fn login(url: &str) {
     );
 }
 
+pub fn login_token_prompt() {
+    println!("Paste your access token and press enter:");
+}
+
+pub fn device_login(verification_url: &str, user_code: &str) {
+    println!(
+        "Please continue by opening the following URL on any device:\n{}\n\nand entering the code {}\n",
+        watercolor!("{verification_url}", @BrightBlue),
+        watercolor!("{user_code}", @BrightBlue)
+    );
+}
+
 pub fn login_success() {
     watercolor::output!("\n\n✨ Successfully logged in!", @BrightBlue);
 }
@@ -154,9 +231,397 @@ pub fn logout() {
     watercolor::output!("✨ Successfully logged out!", @BrightBlue);
 }
 
+pub fn whoami(profile: &str, viewer: &Viewer) {
+    println!(
+        "{} {}",
+        watercolor!("{}", viewer.name, @BrightBlue),
+        watercolor!("<{}>", viewer.email, @BrightBlack)
+    );
+    if let Some(slug) = &viewer.personal_account_slug {
+        println!("Personal account: {slug}");
+    }
+    if !viewer.organization_slugs.is_empty() {
+        println!("Organizations: {}", viewer.organization_slugs.join(", "));
+    }
+    println!("Profile: {profile}");
+}
+
+pub fn profile(profile: &Profile) {
+    let marker = if profile.active { "*" } else { " " };
+    let status = match profile.storage {
+        Some(storage) => format!("logged in, {storage}"),
+        None => "logged out".to_owned(),
+    };
+
+    println!(
+        "{marker} {} {}",
+        watercolor!("{}", profile.name, @BrightBlue),
+        watercolor!("({status})", @BrightBlack)
+    );
+}
+
+pub fn profile_used(name: &str) {
+    watercolor::output!("✨ Now using the profile {name}!", @BrightBlue);
+}
+
 // TODO change this to a spinner that is removed on success
-pub fn deploy() {
-    watercolor::output!("🕒 Your project is being deployed", @BrightBlue);
+pub fn deploy(branch: Option<&str>) {
+    if let Some(branch) = branch {
+        watercolor::output!("🕒 Your project is being deployed to the {branch} branch", @BrightBlue);
+    } else {
+        watercolor::output!("🕒 Your project is being deployed", @BrightBlue);
+    }
+}
+
+pub fn archive_summary(summary: &ArchiveSummary) {
+    let size_width = summary
+        .files
+        .iter()
+        .map(|file| format_size(file.size).len())
+        .max()
+        .unwrap_or_default();
+
+    for file in &summary.files {
+        println!("{:>size_width$}  {}", format_size(file.size), file.path.display());
+    }
+
+    println!(
+        "\n📦 {} files, {} archive",
+        summary.files.len(),
+        watercolor!("{}", format_size(summary.size), @BrightBlue)
+    );
+
+    // the limit is only reported by the API once an archive exceeds it
+    println!("The size limit is not checked locally, it is enforced when deploying");
+}
+
+#[allow(clippy::cast_precision_loss)] // only used for display
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+
+    for next_unit in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next_unit;
+    }
+
+    format!("{size:.1} {unit}")
+}
+
+pub fn deploy_uploaded() {
+    watercolor::output!("📦 Uploaded your project, waiting for the deployment to complete\n", @BrightBlue);
+}
+
+pub fn no_deployments() {
+    println!("No deployments found");
+}
+
+pub fn deployment(deployment: &Deployment) {
+    let status = deployment_status_label(deployment.status);
+    let duration = deployment
+        .duration
+        .map(|duration| format!(" in {}s", duration / 1000))
+        .unwrap_or_default();
+
+    println!(
+        "{} {status} {} on {}{duration}",
+        watercolor!("{}", deployment.id, @BrightBlue),
+        deployment.branch,
+        deployment.created_at.format(STATUS_TIME_FORMAT)
+    );
+
+    if let Some((sha, message)) = &deployment.commit {
+        let sha = sha.get(..7).unwrap_or(sha);
+        let message = message.lines().next().unwrap_or_default();
+        println!("  {}", watercolor!("{sha} {message}", @BrightBlack));
+    }
+}
+
+pub fn deployment_status(status: DeploymentStatus) {
+    println!("Status: {}\n", deployment_status_label(status));
+}
+
+fn deployment_status_label(status: DeploymentStatus) -> ColoredString {
+    match status {
+        DeploymentStatus::Queued | DeploymentStatus::InProgress => watercolor!("{status}", @Blue),
+        DeploymentStatus::Succeeded => watercolor!("{status}", @Green),
+        DeploymentStatus::Failed => watercolor!("{status}", @Red),
+    }
+}
+
+pub fn schema_diff(diff: &str) {
+    for line in diff.lines() {
+        if line.starts_with('+') {
+            watercolor::output!("{line}", @Green);
+        } else if line.starts_with('-') {
+            watercolor::output!("{line}", @Red);
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+pub fn no_schema_diff(base: DeploymentDiffBase) {
+    match base {
+        DeploymentDiffBase::PreviousBranchDeployment => {
+            println!("There is no previous deployment of the same branch to compare to");
+        }
+        DeploymentDiffBase::LatestProductionDeployment => {
+            println!("There is no production deployment to compare to");
+        }
+    }
+}
+
+pub fn metrics(metrics: &BranchMetrics) {
+    println!(
+        "Metrics of the branch {} from {} to {}\n",
+        watercolor!("{}", metrics.branch, @BrightBlue),
+        metrics.start_date.format(STATUS_TIME_FORMAT),
+        metrics.end_date.format(STATUS_TIME_FORMAT)
+    );
+    metric_chart("Requests", &metrics.requests);
+    metric_chart("Latency", &metrics.latency);
+}
+
+pub fn usage(usage: &DatabaseUsage) {
+    println!(
+        "Database usage from {} to {}, {}\n",
+        usage.start_date.format(STATUS_TIME_FORMAT),
+        usage.end_date.format(STATUS_TIME_FORMAT),
+        usage.granularity
+    );
+    metric_chart("Requests", &usage.requests);
+    metric_chart("Reads", &usage.reads);
+    metric_chart("Writes", &usage.writes);
+    metric_chart("Size", &usage.size);
+}
+
+/// renders a metric as a table of its time buckets, with bars scaled to the largest value
+fn metric_chart(title: &str, metric: &Metric) {
+    watercolor::output!("{title}", @BrightBlue);
+
+    if metric.values.is_empty() {
+        println!("No data in this time window\n");
+        return;
+    }
+
+    let labels: Vec<String> = metric
+        .values
+        .iter()
+        .map(|value| format_metric_value(value.value, metric.unit))
+        .collect();
+    let label_width = labels.iter().map(String::len).max().unwrap_or_default();
+    let max = metric
+        .values
+        .iter()
+        .map(|value| value.value)
+        .max()
+        .unwrap_or_default()
+        .max(1);
+
+    for (value, label) in metric.values.iter().zip(&labels) {
+        let bar_length =
+            usize::try_from(i64::from(value.value) * METRIC_BAR_WIDTH / i64::from(max)).unwrap_or_default();
+        println!(
+            "{}  {label:>label_width$}  {}",
+            watercolor!("{}", value.bucket.format(STATUS_TIME_FORMAT), @BrightBlack),
+            "█".repeat(bar_length)
+        );
+    }
+
+    println!();
+}
+
+fn format_metric_value(value: i32, unit: MetricUnit) -> String {
+    match unit {
+        MetricUnit::Count => value.to_string(),
+        MetricUnit::Milliseconds => format!("{value} ms"),
+        MetricUnit::Bytes => format_size(u64::try_from(value).unwrap_or_default()),
+    }
+}
+
+pub fn no_api_keys() {
+    println!("No API keys found");
+}
+
+pub fn api_key(api_key: &ApiKey, reveal: bool) {
+    let key = if reveal {
+        api_key.key.clone()
+    } else {
+        let suffix = api_key
+            .key
+            .get(api_key.key.len().saturating_sub(API_KEY_VISIBLE_CHARACTERS)..)
+            .unwrap_or_default();
+        format!("…{suffix}")
+    };
+
+    println!(
+        "{} {} {} {}",
+        watercolor!("{}", api_key.id, @BrightBlue),
+        api_key.name,
+        watercolor!("({}, created {})", api_key.environment, api_key.created_at.format(STATUS_TIME_FORMAT), @BrightBlack),
+        key
+    );
+}
+
+pub fn api_key_created(api_key: &ApiKey) {
+    watercolor::output!("✨ Successfully created the API key {}!\n", api_key.name, @BrightBlue);
+    println!("{}", api_key.key);
+}
+
+pub fn api_key_renamed(name: &str) {
+    watercolor::output!("✨ Successfully renamed the API key to {name}!", @BrightBlue);
+}
+
+pub fn api_key_deleted() {
+    watercolor::output!("✨ Successfully deleted the API key!", @BrightBlue);
+}
+
+pub fn no_organizations() {
+    println!("You are not a member of any organization");
+}
+
+pub fn organization(organization: &Organization) {
+    let members = match organization.member_count {
+        1 => "1 member".to_owned(),
+        count => format!("{count} members"),
+    };
+    let details = if let Some(role) = organization.role {
+        format!("({role}, {members})")
+    } else {
+        format!("({members})")
+    };
+
+    println!(
+        "{} {} {}",
+        watercolor!("{}", organization.slug, @BrightBlue),
+        organization.name,
+        watercolor!("{details}", @BrightBlack)
+    );
+}
+
+pub fn organization_created(slug: &str) {
+    watercolor::output!("✨ Successfully created the organization {slug}!", @BrightBlue);
+}
+
+pub fn member(member: &Member) {
+    println!(
+        "{} {} {}",
+        watercolor!("{}", member.name, @BrightBlue),
+        watercolor!("<{}>", member.email, @BrightBlack),
+        watercolor!("({}, joined {})", member.role, member.created_at.format(STATUS_TIME_FORMAT), @BrightBlack)
+    );
+}
+
+pub fn no_invites() {
+    println!("No invites found");
+}
+
+pub fn invite(invite: &Invite) {
+    println!(
+        "{} {}",
+        watercolor!("{}", invite.email, @BrightBlue),
+        watercolor!(
+            "({}, {}, sent {})",
+            invite.role,
+            invite.status,
+            invite.created_at.format(STATUS_TIME_FORMAT),
+            @BrightBlack
+        )
+    );
+}
+
+pub fn invite_sent(organization: &str, email: &str) {
+    watercolor::output!("✨ Successfully invited {email} to {organization}!", @BrightBlue);
+}
+
+pub fn invite_cancelled(email: &str) {
+    watercolor::output!("✨ Successfully cancelled the invite sent to {email}!", @BrightBlue);
+}
+
+pub fn invite_accepted(organization: &str) {
+    watercolor::output!("✨ You are now a member of {organization}!", @BrightBlue);
+}
+
+pub fn invite_declined() {
+    watercolor::output!("✨ Successfully declined the invite!", @BrightBlue);
+}
+
+pub fn member_role_updated(email: &str, role: MemberRole) {
+    watercolor::output!("✨ {email} is now a {role} of the organization!", @BrightBlue);
+}
+
+pub fn member_removed(organization: &str, email: &str) {
+    watercolor::output!("✨ Successfully removed {email} from {organization}!", @BrightBlue);
+}
+
+pub fn no_environment_variables() {
+    println!("No environment variables found");
+}
+
+pub fn environment_variable(variable: &EnvironmentVariable) {
+    let environments = variable
+        .environments
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "{}={} {}",
+        watercolor!("{}", variable.name, @BrightBlue),
+        variable.value,
+        watercolor!("({environments})", @BrightBlack)
+    );
+}
+
+pub fn environment_variable_set(name: &str) {
+    watercolor::output!("✨ Successfully set {name}!", @BrightBlue);
+}
+
+pub fn environment_variable_unset(name: &str) {
+    watercolor::output!("✨ Successfully removed {name}!", @BrightBlue);
+}
+
+pub fn environment_variable_changes(changes: &[EnvironmentVariableChange]) {
+    for change in changes {
+        match change {
+            EnvironmentVariableChange::Added { name, value } => watercolor::output!("+ {name}={value}", @Green),
+            EnvironmentVariableChange::Changed { name, from, to } => {
+                watercolor::output!("- {name}={from}", @Red);
+                watercolor::output!("+ {name}={to}", @Green);
+            }
+        }
+    }
+    println!();
+}
+
+pub fn environment_variables_up_to_date() {
+    println!("The environment variables are already up to date");
+}
+
+pub fn environment_variables_pulled(path: &Path) {
+    watercolor::output!("✨ Successfully wrote the environment variables to {}!", path.display(), @BrightBlue);
+}
+
+pub fn environment_variables_pushed() {
+    watercolor::output!("✨ Successfully uploaded the environment variables!", @BrightBlue);
+}
+
+pub fn deployment_log_entry(entry: &DeploymentLogEntry) {
+    let time = entry.created_at.format("%H:%M:%S");
+    match entry.level {
+        DeploymentLogLevel::Error => watercolor::output!("[{time}] {}", entry.message, @Red),
+        DeploymentLogLevel::Info => watercolor::output!("[{time}] {}", entry.message, @BrightBlack),
+    }
 }
 
 // TODO change this to a spinner that is removed on success
@@ -183,3 +648,131 @@ pub fn create_success(name: &str, urls: &[String]) {
         watercolor::output!("- https://{url}", @BrightBlue);
     }
 }
+
+pub fn no_resolver_fixtures(resolver_name: Option<&str>) {
+    let fixtures_path = [
+        ".",
+        GRAFBASE_DIRECTORY_NAME,
+        RESOLVERS_DIRECTORY_NAME,
+        RESOLVER_FIXTURES_DIRECTORY_NAME,
+    ]
+    .join(std::path::MAIN_SEPARATOR_STR);
+
+    match resolver_name {
+        Some(resolver_name) => println!("No fixtures found for resolver '{resolver_name}' in {fixtures_path}"),
+        None => println!("No resolver fixtures found in {fixtures_path}"),
+    }
+}
+
+pub fn attach(info: &DevServerInfo) {
+    let pid = info.pid;
+    watercolor::output!("🔗 Attached to the development server already running in this project (pid {pid})", @BrightBlue);
+}
+
+pub fn reload_requested() {
+    watercolor::output!("🔄 Reloading the development server", @BrightBlue);
+}
+
+pub fn server_status(status: &ServerStatus) {
+    let state = match status.state {
+        ServerState::Building => watercolor!("building", @Blue),
+        ServerState::Ready => watercolor!("ready", @Green),
+        ServerState::Failed => watercolor!("failed", @Red),
+    };
+
+    println!(
+        "📡 Development server on port {} (pid {}): {state}\n",
+        watercolor!("{}", status.port, @BrightBlue),
+        status.pid
+    );
+    println!("- Started:  {}", status.started_at.format(STATUS_TIME_FORMAT));
+
+    if let Some(reloaded_at) = status.reloaded_at {
+        let changed_paths = if status.changed_paths.is_empty() {
+            "on request".to_owned()
+        } else {
+            status
+                .changed_paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        println!(
+            "- Reloaded: {} ({changed_paths})",
+            reloaded_at.format(STATUS_TIME_FORMAT)
+        );
+    }
+
+    if !status.resolvers.is_empty() {
+        println!("- Resolvers:");
+    }
+
+    for resolver in &status.resolvers {
+        println!(
+            "  - {} built in {}ms at {}",
+            resolver.name,
+            resolver.duration_ms,
+            resolver.built_at.format(STATUS_TIME_FORMAT)
+        );
+    }
+
+    if let Some(compilation_error) = &status.compilation_error {
+        println!();
+        watercolor::output_error!("Error: {compilation_error}", @BrightRed);
+    }
+}
+
+pub fn no_resolver_logs(resolver_name: Option<&str>) {
+    match resolver_name {
+        Some(resolver_name) => println!("No logs recorded for resolver '{resolver_name}' yet"),
+        None => println!("No resolver logs recorded yet, logs are recorded while running 'grafbase dev'"),
+    }
+}
+
+pub fn resolver_test(report: &ResolverTestReport) {
+    let ResolverFixture {
+        resolver_name,
+        case_name,
+        snapshot_path,
+        ..
+    } = &report.fixture;
+
+    match &report.outcome {
+        ResolverTestOutcome::Passed => println!("{}   - {resolver_name} › {case_name}", watercolor!("pass", @Green)),
+        ResolverTestOutcome::SnapshotCreated => println!(
+            "{}    - {resolver_name} › {case_name}, wrote {}",
+            watercolor!("new", @Blue),
+            snapshot_path.display()
+        ),
+        ResolverTestOutcome::SnapshotUpdated => println!(
+            "{} - {resolver_name} › {case_name}, updated {}",
+            watercolor!("update", @Yellow),
+            snapshot_path.display()
+        ),
+        ResolverTestOutcome::Failed { expected, actual } => {
+            println!("{}   - {resolver_name} › {case_name}", watercolor!("fail", @Red));
+            let expected = serde_json::to_string_pretty(expected).expect("must serialise");
+            let actual = serde_json::to_string_pretty(actual).expect("must serialise");
+            watercolor::output!("  expected:\n{expected}", @Green);
+            watercolor::output!("  received:\n{actual}", @Red);
+        }
+        ResolverTestOutcome::InvocationFailed(error) => {
+            println!("{}   - {resolver_name} › {case_name}", watercolor!("fail", @Red));
+            watercolor::output!("  could not invoke the resolver: {error}", @Red);
+        }
+    }
+
+    for ResolverMessage { message, level } in &report.log_entries {
+        resolver_message(resolver_name, message, *level);
+    }
+}
+
+pub fn resolver_tests_summary(total: usize, failed: usize) {
+    let passed = total - failed;
+    if failed == 0 {
+        watercolor::output!("\n✨ {passed} of {total} resolver test(s) passed", @BrightBlue);
+    } else {
+        watercolor::output!("\n{failed} of {total} resolver test(s) failed", @BrightRed);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{errors::CliError, output::report};
+use backend::api::profiles;
+
+pub fn list() -> Result<(), CliError> {
+    let profiles = profiles::list().map_err(CliError::BackendApiError)?;
+
+    for profile in &profiles {
+        report::profile(profile);
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub fn use_profile(name: &str) -> Result<(), CliError> {
+    profiles::use_profile(name).map_err(CliError::BackendApiError)?;
+
+    report::profile_used(name);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::errors::CliError;
-use inquire::InquireError;
+use inquire::{Confirm, InquireError};
+use std::io::{self, IsTerminal};
 use std::process;
 
 pub fn handle_inquire_error(error: InquireError) -> CliError {
@@ -11,3 +12,56 @@ pub fn handle_inquire_error(error: InquireError) -> CliError {
         InquireError::InvalidConfiguration(_) | InquireError::Custom(_) => unreachable!(),
     }
 }
+
+/// fails with the arguments to pass instead of answering prompts if the CLI is not run interactively,
+/// e.g. in CI, rather than waiting for or failing on input that never comes
+pub fn require_terminal(arguments: &'static str) -> Result<(), CliError> {
+    require_interactive(io::stdin().is_terminal() && io::stdout().is_terminal(), arguments)
+}
+
+fn require_interactive(interactive: bool, arguments: &'static str) -> Result<(), CliError> {
+    if interactive {
+        Ok(())
+    } else {
+        Err(CliError::NotInteractive(arguments))
+    }
+}
+
+/// asks for confirmation before a change, defaulting to no. requires `--yes` when not run interactively
+pub fn confirm(message: &str) -> Result<bool, CliError> {
+    require_terminal("--yes")?;
+
+    Confirm::new(message)
+        .with_default(false)
+        .prompt()
+        .map_err(handle_inquire_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle_inquire_error, require_interactive, CliError, InquireError};
+
+    #[test]
+    fn prompts_fail_without_a_terminal() {
+        let error = require_interactive(false, "--yes").unwrap_err();
+
+        assert!(matches!(error, CliError::NotInteractive("--yes")), "{error:?}");
+        assert_eq!(
+            error.to_string(),
+            "could not prompt for input as the CLI is not running in an interactive terminal"
+        );
+        assert_eq!(
+            error.to_hint().as_deref(),
+            Some("try passing --yes to run the command without prompts")
+        );
+
+        assert!(require_interactive(true, "--yes").is_ok());
+    }
+
+    #[test]
+    fn prompts_failing_on_a_missing_tty() {
+        let error = handle_inquire_error(InquireError::NotTTY);
+
+        assert!(matches!(error, CliError::PromptNotTTY), "{error:?}");
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{errors::CliError, output::report};
+use backend::server_api::reload_server;
+use std::net::SocketAddr;
+
+#[tokio::main]
+pub async fn reload(address: SocketAddr, https: bool) -> Result<(), CliError> {
+    reload_server(address, https).await.map_err(CliError::BackendError)?;
+    report::reload_requested();
+    Ok(())
+}
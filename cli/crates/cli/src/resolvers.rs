@@ -0,0 +1,90 @@
+use crate::output::report;
+use crate::CliError;
+use backend::server_api::{find_resolver_fixtures, resolver_logs, test_resolvers};
+use backend::types::ServerMessage;
+use common::utils::get_thread_panic_message;
+use std::time::Duration;
+
+/// cli wrapper for [`backend::server_api::test_resolvers`]
+///
+/// # Errors
+///
+/// returns [`CliError::BackendError`] if the fixtures could not be read or the server could not be started
+///
+/// returns [`CliError::ServerError`] if the project could not be compiled or a fixture could not be run
+///
+/// returns [`CliError::ServerPanic`] if the development server panics
+///
+/// returns [`CliError::ResolverTestsFailed`] if any of the results do not match their snapshots
+pub fn test(resolver_name: Option<&str>, update: bool, timeout_seconds: u64, tracing: bool) -> Result<(), CliError> {
+    trace!("attempting to test resolvers");
+
+    let fixtures = find_resolver_fixtures(resolver_name).map_err(CliError::BackendError)?;
+
+    if fixtures.is_empty() {
+        report::no_resolver_fixtures(resolver_name);
+        return Ok(());
+    }
+
+    let (server_handle, receiver) = test_resolvers(fixtures, update, Duration::from_secs(timeout_seconds), tracing)
+        .map_err(CliError::BackendError)?;
+
+    while let Ok(message) = receiver.recv() {
+        match message {
+            ServerMessage::StartResolverBuild(resolver_name) => report::start_resolver_build(&resolver_name),
+            ServerMessage::CompleteResolverBuild { name, duration } => report::complete_resolver_build(&name, duration),
+            // compilation errors are returned from the server thread
+            ServerMessage::Ready(_)
+            | ServerMessage::Reload(_)
+            | ServerMessage::ResolverMessage { .. }
+            | ServerMessage::CompilationError(_) => {}
+            ServerMessage::Warnings(warnings) => report::warnings(&warnings),
+        }
+    }
+
+    let reports = server_handle
+        .join()
+        .map_err(|parameter| match get_thread_panic_message(&parameter) {
+            Some(message) => CliError::ServerPanic(message),
+            None => CliError::ServerPanic("unknown error".to_owned()),
+        })?
+        .map_err(CliError::ServerError)?;
+
+    for resolver_test_report in &reports {
+        report::resolver_test(resolver_test_report);
+    }
+
+    let failed = reports.iter().filter(|report| report.failed()).count();
+
+    report::resolver_tests_summary(reports.len(), failed);
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(CliError::ResolverTestsFailed(failed))
+    }
+}
+
+/// cli wrapper for [`backend::server_api::resolver_logs`]
+///
+/// # Errors
+///
+/// returns [`CliError::BackendError`] if the resolver logs could not be read
+pub fn logs(resolver_name: Option<&str>) -> Result<(), CliError> {
+    trace!("attempting to print resolver logs");
+
+    let logs = resolver_logs(resolver_name).map_err(CliError::BackendError)?;
+
+    if logs.is_empty() {
+        report::no_resolver_logs(resolver_name);
+        return Ok(());
+    }
+
+    for (resolver_name, log_entries) in logs {
+        for entry in log_entries {
+            report::resolver_message(&resolver_name, &entry.message, entry.level);
+        }
+    }
+
+    Ok(())
+}
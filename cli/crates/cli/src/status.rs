@@ -0,0 +1,10 @@
+use crate::{errors::CliError, output::report};
+use backend::server_api::server_status;
+use std::net::SocketAddr;
+
+#[tokio::main]
+pub async fn status(address: SocketAddr, https: bool) -> Result<(), CliError> {
+    let status = server_status(address, https).await.map_err(CliError::BackendError)?;
+    report::server_status(&status);
+    Ok(())
+}
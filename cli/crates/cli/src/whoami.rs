@@ -0,0 +1,13 @@
+use crate::{errors::CliError, output::report};
+use backend::api::{profiles, whoami};
+
+#[tokio::main]
+pub async fn whoami() -> Result<(), CliError> {
+    let profile = profiles::current().map_err(CliError::BackendApiError)?;
+
+    let viewer = whoami::whoami().await.map_err(CliError::BackendApiError)?;
+
+    report::whoami(&profile, &viewer);
+
+    Ok(())
+}
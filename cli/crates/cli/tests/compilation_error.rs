@@ -20,13 +20,17 @@ fn compilation_error() {
     client.poll_endpoint(30, 300);
 
     let response = client.gql::<Value>(COMPILATION_ERROR_QUERY).send();
-    let errors: Option<Vec<String>> = dot_get_opt!(response, "errors");
+    let errors: Vec<Value> = dot_get!(response, "errors");
 
-    assert_eq!(errors.map(|errors| !errors.is_empty()), Some(true));
+    assert!(!errors.is_empty());
+    assert!(errors[0]["message"].is_string());
+    assert_eq!(errors[0]["extensions"]["code"], "SCHEMA_PARSE_ERROR");
 
     let error_page = client.get_playground_html();
 
     assert!(error_page.contains("Encountered a compilation error"));
+    // the page reloads itself once the project is rebuilt
+    assert!(error_page.contains("/_grafbase/status"));
 
     client.snapshot();
 
@@ -36,7 +40,7 @@ fn compilation_error() {
 
     let response = client.gql::<Value>(DEFAULT_QUERY).send();
 
-    let errors: Option<Vec<String>> = dot_get_opt!(response, "errors");
+    let errors: Option<Vec<Value>> = dot_get_opt!(response, "errors");
 
     assert!(errors.is_none());
 
@@ -57,9 +61,10 @@ fn compilation_error() {
 
     let response = client.gql::<Value>(COMPILATION_ERROR_RESOLVER_MUTATION).send();
 
-    let errors: Option<Vec<String>> = dot_get_opt!(response, "errors");
+    let errors: Vec<Value> = dot_get!(response, "errors");
 
-    assert_eq!(errors.map(|errors| !errors.is_empty()), Some(true));
+    assert!(!errors.is_empty());
+    assert_eq!(errors[0]["extensions"]["code"], "RESOLVER_BUILD_ERROR");
 
     let error_page = client.get_playground_html();
 
@@ -82,7 +87,7 @@ fn compilation_error() {
 
     let response = client.gql::<Value>(COMPILATION_ERROR_RESOLVER_MUTATION).send();
 
-    let errors: Option<Vec<String>> = dot_get_opt!(response, "errors");
+    let errors: Option<Vec<Value>> = dot_get_opt!(response, "errors");
 
     assert!(errors.is_none());
 
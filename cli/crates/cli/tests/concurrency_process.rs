@@ -18,9 +18,12 @@ async fn concurrency_process() {
     let async_client1 = env1.create_async_client().with_api_key();
     async_client1.poll_endpoint(30, 300).await;
 
-    env2.grafbase_dev();
-    let async_client2 = env2.create_async_client().with_api_key();
-    async_client2.poll_endpoint(30, 300).await;
+    // a second development server in the same project would share its database
+    let output = env2.grafbase_dev_output();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already running in this project"));
+
+    let async_client2 = env1.create_async_client().with_api_key();
 
     for _ in 0..15 {
         let (response1, response2): (Value, Value) = tokio::join!(
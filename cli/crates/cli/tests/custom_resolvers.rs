@@ -394,3 +394,101 @@ fn test_query_mutation_resolver(
         }
     }
 }
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_resolver_environment_reload() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_file(".env", "MY_OWN_VARIABLE=test_value");
+    env.write_schema(
+        r#"
+            extend type Query {
+                variable: String @resolver(name: "return-env-variable")
+            }
+        "#,
+    );
+    env.write_resolver(
+        "return-env-variable.js",
+        r#"
+            // read while the module is evaluated, which only happens in a new worker
+            const variableAtLoad = process.env["MY_OWN_VARIABLE"];
+
+            export default function Resolver(parent, args, context, info) {
+                return variableAtLoad || null;
+            }
+        "#,
+    );
+    env.grafbase_dev_watch();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(60, 300);
+
+    let query = "{ variable }";
+
+    let response = client.gql::<Value>(query).send();
+    assert_eq!(
+        dot_get_opt!(response, "data.variable", String).as_deref(),
+        Some("test_value")
+    );
+
+    let bundle_path = env
+        .directory
+        .join(".grafbase/resolvers/return-env-variable/wrangler/entrypoint.js");
+    let bundle_modified = std::fs::metadata(&bundle_path).unwrap().modified().unwrap();
+
+    env.write_file(".env", "MY_OWN_VARIABLE=updated_value");
+
+    let start = std::time::Instant::now();
+    loop {
+        let response = client.gql::<Value>(query).send();
+        if dot_get_opt!(response, "data.variable", String).as_deref() == Some("updated_value") {
+            break;
+        }
+        assert!(
+            start.elapsed() < std::time::Duration::from_secs(60),
+            "the updated variable was not picked up"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(300));
+    }
+
+    // a change to the environment variables only restarts the worker, the resolver is not rebuilt
+    assert_eq!(
+        std::fs::metadata(&bundle_path).unwrap().modified().unwrap(),
+        bundle_modified
+    );
+}
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_resolver_thrown_error() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(
+        r#"
+            extend type Query {
+                failing: String @resolver(name: "failing")
+            }
+        "#,
+    );
+    env.write_resolver(
+        "failing.js",
+        r#"
+            export default function Resolver(parent, args, context, info) {
+                throw new Error("the resolver could not reach its upstream service");
+            }
+        "#,
+    );
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(60, 300);
+
+    let response = client.gql::<Value>("{ failing }").send();
+    let errors = dot_get_opt!(response, "errors", Vec::<serde_json::Value>).unwrap_or_default();
+    assert!(
+        errors
+            .iter()
+            .any(|error| error["message"].as_str().map_or(false, |message| message
+                .contains("the resolver could not reach its upstream service"))),
+        "Error response: {errors:?}"
+    );
+}
@@ -1,6 +1,7 @@
 mod utils;
 
 use serde_json::{json, Value};
+use std::io::{BufRead, BufReader};
 use utils::consts::{DEFAULT_CREATE, DEFAULT_QUERY, DEFAULT_SCHEMA, DEFAULT_UPDATE};
 use utils::environment::Environment;
 
@@ -82,3 +83,157 @@ fn dev() {
     let updated_todo_list: Value = dot_get!(response, "data.todoListUpdate.todoList");
     assert_eq!(dot_get!(updated_todo_list, "title", String), "Updated Title");
 }
+
+#[test]
+fn dev_all_interfaces() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    env.grafbase_dev_with_arguments(&["--host", "0.0.0.0"]);
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let response = client.gql::<Value>(DEFAULT_CREATE).send();
+    let todo_list_id: String = dot_get!(response, "data.todoListCreate.todoList.id");
+    assert!(!todo_list_id.is_empty());
+}
+
+#[test]
+fn dev_https() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    env.grafbase_dev_with_arguments(&["--https"]);
+    let client = env.create_client().with_https().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let response = client.gql::<Value>(DEFAULT_CREATE).send();
+    let todo_list_id: String = dot_get!(response, "data.todoListCreate.todoList.id");
+    assert!(!todo_list_id.is_empty());
+}
+
+#[test]
+fn dev_json_output() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    let output = env.grafbase_dev_json_output();
+
+    // every line is an event, the first build ends with a ready event
+    let ready = BufReader::new(output)
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(&line.unwrap()).expect("must be an event"))
+        .find(|event| event["event"] == "ready")
+        .expect("must be ready");
+
+    assert_eq!(ready, json!({ "event": "ready", "port": env.port }));
+}
+
+#[test]
+fn dev_admin_api() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let admin_url = format!("http://127.0.0.1:{}/_grafbase", env.port);
+    let status = |admin_url: &str| -> Value {
+        reqwest::blocking::get(format!("{admin_url}/status"))
+            .unwrap()
+            .json()
+            .unwrap()
+    };
+    let wait_for_status = |admin_url: &str, predicate: &dyn Fn(&Value) -> bool| {
+        let start = std::time::SystemTime::now();
+        loop {
+            let status = status(admin_url);
+            if predicate(&status) {
+                break status;
+            }
+            assert!(start.elapsed().unwrap().as_secs() < 30, "timeout");
+            std::thread::sleep(std::time::Duration::from_millis(300));
+        }
+    };
+
+    let initial_status = wait_for_status(&admin_url, &|status| status["state"] == "ready");
+    assert_eq!(initial_status["port"], env.port);
+    assert!(initial_status["reloaded_at"].is_null());
+
+    let registry: Value = reqwest::blocking::get(format!("{admin_url}/registry"))
+        .unwrap()
+        .json()
+        .unwrap();
+    assert!(registry.is_object());
+
+    // the routes changing the state of the server can't be used by web pages of other origins
+    let http_client = reqwest::blocking::Client::new();
+    let forbidden = http_client.post(format!("{admin_url}/reload")).send().unwrap();
+    assert_eq!(forbidden.status(), reqwest::StatusCode::FORBIDDEN);
+    let forbidden = http_client
+        .post(format!("{admin_url}/reset"))
+        .header("x-grafbase-admin", "1")
+        .header("origin", "http://example.com")
+        .send()
+        .unwrap();
+    assert_eq!(forbidden.status(), reqwest::StatusCode::FORBIDDEN);
+
+    assert!(env.grafbase_reload().status.success());
+
+    // the status is only ready again once the reloaded build is serving requests
+    wait_for_status(&admin_url, &|status| {
+        status["state"] == "ready" && !status["reloaded_at"].is_null()
+    });
+
+    let response = client.gql::<Value>(DEFAULT_QUERY).send();
+    let todo_list_collection: Value = dot_get!(response, "data.todoListCollection.edges");
+    assert!(todo_list_collection.is_array());
+}
+
+#[test]
+fn dev_attach() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let mut lines = BufReader::new(env.grafbase_dev_attach()).lines();
+
+    assert!(lines
+        .by_ref()
+        .any(|line| line.unwrap().contains("Attached to the development server")));
+
+    // messages of the running server are followed once attached
+    assert!(env.grafbase_reload().status.success());
+
+    assert!(lines.any(|line| line.unwrap().contains("Reloading")));
+}
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn dev_graceful_shutdown() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(DEFAULT_SCHEMA);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    let response = client.gql::<Value>(DEFAULT_CREATE).send();
+    let todo_list_id: String = dot_get!(response, "data.todoListCreate.todoList.id");
+
+    env.interrupt_processes();
+
+    // the project is released once the server stopped
+    assert!(!env.directory.join(".grafbase/dev.json").exists());
+
+    env.grafbase_dev();
+    client.poll_endpoint(30, 300);
+
+    let response = client.gql::<Value>(DEFAULT_QUERY).send();
+    let edges: Value = dot_get!(response, "data.todoListCollection.edges");
+    assert_eq!(dot_get!(edges, "0.node.id", String), todo_list_id);
+}
@@ -44,3 +44,73 @@ fn dev_watch() {
     assert!(user_birthday.ends_with('Z'));
     assert_eq!(user_verified, "VERIFIED");
 }
+
+#[test]
+fn dev_watch_serves_during_reload() {
+    let mut env = Environment::init();
+
+    env.grafbase_init();
+
+    env.write_schema(DEFAULT_SCHEMA);
+
+    env.grafbase_dev_watch();
+
+    let client = env.create_client().with_api_key();
+
+    client.poll_endpoint(30, 300);
+
+    env.append_to_schema(UPDATED_SCHEMA);
+
+    let start = std::time::SystemTime::now();
+
+    // the previous build keeps answering until the updated schema is swapped in
+    loop {
+        let response = client.gql::<Value>(DEFAULT_QUERY).send();
+
+        let todo_list_collection: Value = dot_get!(response, "data.todoListCollection.edges");
+
+        assert!(todo_list_collection.is_array());
+
+        let response = client.gql::<Value>(UPDATED_QUERY).send();
+
+        if response
+            .dot_get::<Value>("data.userCollection.edges")
+            .ok()
+            .flatten()
+            .is_some()
+        {
+            break;
+        }
+
+        assert!(start.elapsed().unwrap().as_secs() < 30, "timeout");
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn dev_watch_poll() {
+    let mut env = Environment::init();
+
+    env.grafbase_init();
+
+    env.write_schema(DEFAULT_SCHEMA);
+
+    env.grafbase_dev_watch_with_arguments(&["--watch-poll", "--watch-debounce", "200"]);
+
+    let mut client = env.create_client().with_api_key();
+
+    client.poll_endpoint(30, 300);
+
+    client.snapshot();
+
+    env.append_to_schema(UPDATED_SCHEMA);
+
+    client.poll_endpoint_for_changes(30, 300);
+
+    let response = client.gql::<Value>(UPDATED_QUERY).send();
+
+    let user_collection: Value = dot_get!(response, "data.userCollection.edges");
+
+    assert!(user_collection.is_array());
+}
@@ -1,5 +1,6 @@
 mod utils;
 
+use serde_json::Value;
 use std::collections::HashMap;
 use utils::consts::ENVIRONMENT_SCHEMA;
 use utils::environment::Environment;
@@ -26,3 +27,61 @@ fn environment_file() {
 
 // TODO: add a test for precedence once we have a way to print variables
 // (the .env variables are higher priority than process enviroment variables)
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn environment_file_precedence() {
+    let mut env = Environment::init();
+
+    env.grafbase_init();
+
+    env.write_schema(
+        r#"
+            extend type Query {
+                variable(name: String!): String @resolver(name: "return-env-variable")
+            }
+        "#,
+    );
+
+    env.write_resolver(
+        "return-env-variable.js",
+        r#"
+            export default function Resolver(parent, args, context, info) {
+                return process.env[args.name] || null;
+            }
+        "#,
+    );
+
+    env.write_file(
+        ".env",
+        "FROM_DOT_ENV=dot_env\nOVERRIDDEN_LOCAL=dot_env\nOVERRIDDEN_MODE=dot_env",
+    );
+    env.write_file(".env.local", "OVERRIDDEN_LOCAL=local\nOVERRIDDEN_MODE=local");
+    env.write_file(".env.preview", "OVERRIDDEN_MODE=preview\nOVERRIDDEN_FILE=preview");
+    env.write_file("../extra.env", "OVERRIDDEN_FILE=extra");
+
+    env.grafbase_dev_with_arguments(&["--mode", "preview", "--env-file", "extra.env"]);
+
+    let client = env.create_client().with_api_key();
+
+    client.poll_endpoint(60, 300);
+
+    for (name, expected) in [
+        ("FROM_DOT_ENV", "dot_env"),
+        ("OVERRIDDEN_LOCAL", "local"),
+        ("OVERRIDDEN_MODE", "preview"),
+        ("OVERRIDDEN_FILE", "extra"),
+        ("GRAFBASE_ENV", "preview"),
+    ] {
+        let response = client
+            .gql::<Value>("query GetVariable($name: String!) { variable(name: $name) }")
+            .variables(serde_json::json!({ "name": name }))
+            .send();
+
+        assert_eq!(
+            dot_get_opt!(response, "data.variable", String).as_deref(),
+            Some(expected),
+            "{name}"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+mod utils;
+
+use utils::environment::Environment;
+
+const SCHEMA: &str = r#"
+    type Post @model {
+        title: String!
+        text: String! @resolver(name: "return-text")
+    }
+"#;
+
+const FIXTURE: &str = r#"{ "parent": { "title": "Hello" }, "args": {}, "context": {}, "info": {} }"#;
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn resolvers_test() {
+    let env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(SCHEMA);
+    env.write_resolver(
+        "return-text.js",
+        r#"
+            export default function Resolver(parent, args, context, info) {
+                return "Lorem ipsum dolor sit amet";
+            }
+        "#,
+    );
+    env.write_resolver("__fixtures__/return-text/basic.json", FIXTURE);
+
+    let snapshot_path = env
+        .directory
+        .join("grafbase/resolvers/__fixtures__/return-text/basic.snap");
+
+    let output = env.grafbase_resolvers_test_output(false);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(std::fs::read_to_string(&snapshot_path)
+        .unwrap()
+        .contains("Lorem ipsum dolor sit amet"));
+
+    let output = env.grafbase_resolvers_test_output(false);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    env.write_resolver(
+        "return-text.js",
+        r#"
+            export default function Resolver(parent, args, context, info) {
+                return "consectetur adipiscing elit";
+            }
+        "#,
+    );
+
+    let output = env.grafbase_resolvers_test_output(false);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 resolver test(s) failed"));
+
+    let output = env.grafbase_resolvers_test_output(true);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(std::fs::read_to_string(&snapshot_path)
+        .unwrap()
+        .contains("consectetur adipiscing elit"));
+}
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn resolvers_test_with_dev_server_running() {
+    let mut env = Environment::init();
+    env.grafbase_init();
+    env.write_schema(SCHEMA);
+    env.write_resolver(
+        "return-text.js",
+        r#"
+            export default function Resolver(parent, args, context, info) {
+                return "Lorem ipsum dolor sit amet";
+            }
+        "#,
+    );
+    env.write_resolver("__fixtures__/return-text/basic.json", FIXTURE);
+    env.grafbase_dev();
+    let client = env.create_client().with_api_key();
+    client.poll_endpoint(30, 300);
+
+    // the tests would share the database of the running server
+    let output = env.grafbase_resolvers_test_output(false);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("a development server is already running in this project"));
+}
@@ -32,6 +32,19 @@ impl Client {
         }
     }
 
+    /// switches to HTTPS, accepting the certificate issued by the local certificate authority of `grafbase dev --https`
+    pub fn with_https(mut self) -> Self {
+        self.endpoint = self.endpoint.replacen("http://", "https://", 1);
+        self.playground_endpoint = self.playground_endpoint.replacen("http://", "https://", 1);
+        self.client = reqwest::blocking::Client::builder()
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(5))
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        self
+    }
+
     pub fn with_api_key(self) -> Self {
         self.with_header("x-api-key", "any")
     }
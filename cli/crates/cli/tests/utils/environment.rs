@@ -5,12 +5,12 @@ use super::kill_with_children::kill_with_children;
 use super::{cargo_bin::cargo_bin, client::Client};
 use cfg_if::cfg_if;
 use common::consts::{GRAFBASE_DIRECTORY_NAME, GRAFBASE_SCHEMA_FILE_NAME};
-use duct::{cmd, Handle};
-use std::io;
+use duct::{cmd, Handle, ReaderHandle};
 use std::path::Path;
 use std::process::Output;
 use std::sync::Arc;
 use std::{env, fs, io::Write, path::PathBuf};
+use sysinfo::{Pid, ProcessExt, Signal, System, SystemExt};
 use tempfile::{tempdir, TempDir};
 
 pub struct Environment {
@@ -212,6 +212,20 @@ impl Environment {
         .unwrap();
     }
 
+    pub fn grafbase_resolvers_test_output(&self, update: bool) -> Output {
+        if update {
+            cmd!(cargo_bin("grafbase"), "resolvers", "test", "--update")
+        } else {
+            cmd!(cargo_bin("grafbase"), "resolvers", "test")
+        }
+        .dir(&self.directory)
+        .stdout_capture()
+        .stderr_capture()
+        .unchecked()
+        .run()
+        .unwrap()
+    }
+
     pub fn remove_grafbase_dir(&self, name: Option<&str>) {
         let directory = name.map_or_else(|| self.directory.join("grafbase"), |name| self.directory.join(name));
         fs::remove_dir_all(directory).unwrap();
@@ -241,6 +255,22 @@ impl Environment {
         self.commands.push(command);
     }
 
+    pub fn grafbase_dev_with_arguments(&mut self, arguments: &[&str]) {
+        let port = self.port.to_string();
+        let command = cmd(
+            cargo_bin("grafbase"),
+            ["--trace", "2", "dev", "--disable-watch", "--port", &port]
+                .iter()
+                .chain(arguments),
+        )
+        .dir(&self.directory);
+        #[cfg(feature = "dynamodb")]
+        let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
+        let command = command.start().unwrap();
+
+        self.commands.push(command);
+    }
+
     pub fn grafbase_dev_with_home_flag(&mut self) {
         let command = cmd!(
             cargo_bin("grafbase"),
@@ -264,18 +294,50 @@ impl Environment {
         self.commands.push(command);
     }
 
-    pub fn grafbase_dev_output(&mut self) -> io::Result<Output> {
+    pub fn grafbase_dev_output(&mut self) -> Output {
+        let command = cmd!(
+            cargo_bin("grafbase"),
+            "dev",
+            "--disable-watch",
+            "--port",
+            self.port.to_string()
+        )
+        .dir(&self.directory);
+        #[cfg(feature = "dynamodb")]
+        let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
+        command.stdout_capture().stderr_capture().unchecked().run().unwrap()
+    }
+
+    /// starts `grafbase dev --attach`, which stops following the running server once the returned reader is dropped
+    pub fn grafbase_dev_attach(&mut self) -> ReaderHandle {
+        cmd!(
+            cargo_bin("grafbase"),
+            "dev",
+            "--disable-watch",
+            "--attach",
+            "--port",
+            self.port.to_string()
+        )
+        .dir(&self.directory)
+        .reader()
+        .unwrap()
+    }
+
+    /// starts `grafbase dev --output json`, the server is stopped once the returned reader is dropped
+    pub fn grafbase_dev_json_output(&mut self) -> ReaderHandle {
         let command = cmd!(
             cargo_bin("grafbase"),
             "dev",
             "--disable-watch",
+            "--output",
+            "json",
             "--port",
             self.port.to_string()
         )
         .dir(&self.directory);
         #[cfg(feature = "dynamodb")]
         let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
-        command.start()?.into_output()
+        command.reader().unwrap()
     }
 
     pub fn set_variables<K, V>(&mut self, variables: impl IntoIterator<Item = (K, V)>)
@@ -296,6 +358,14 @@ impl Environment {
         .unwrap();
     }
 
+    pub fn grafbase_reload(&self) -> Output {
+        cmd!(cargo_bin("grafbase"), "reload", "--port", self.port.to_string())
+            .dir(&self.directory)
+            .stdout_capture()
+            .run()
+            .unwrap()
+    }
+
     pub fn grafbase_reset(&mut self) {
         cmd!(cargo_bin("grafbase"), "reset").dir(&self.directory).run().unwrap();
     }
@@ -309,6 +379,16 @@ impl Environment {
         self.commands.push(command);
     }
 
+    pub fn grafbase_dev_watch_with_arguments(&mut self, arguments: &[&str]) {
+        let port = self.port.to_string();
+        let command = cmd(cargo_bin("grafbase"), ["dev", "--port", &port].iter().chain(arguments)).dir(&self.directory);
+        #[cfg(feature = "dynamodb")]
+        let command = command.env("DYNAMODB_TABLE_NAME", &self.dynamodb_env.table_name);
+        let command = command.start().unwrap();
+
+        self.commands.push(command);
+    }
+
     pub fn append_to_schema(&self, contents: &'static str) {
         let mut file = fs::OpenOptions::new().append(true).open(&self.schema_path).unwrap();
 
@@ -327,6 +407,30 @@ impl Environment {
         self.commands = vec![];
     }
 
+    /// interrupts the started commands (as with Ctrl-C) and waits for them to exit
+    pub fn interrupt_processes(&mut self) {
+        let mut system = System::new();
+        system.refresh_processes();
+
+        for command in &self.commands {
+            let pid = Pid::from(*command.pids().first().unwrap() as usize);
+            if let Some(process) = system.process(pid) {
+                process.kill_with(Signal::Interrupt);
+            }
+        }
+
+        let start = std::time::SystemTime::now();
+
+        for command in &self.commands {
+            while command.try_wait().unwrap().is_none() {
+                assert!(start.elapsed().unwrap().as_secs() < 30, "timeout");
+                std::thread::sleep(std::time::Duration::from_millis(100));
+            }
+        }
+
+        self.commands = vec![];
+    }
+
     pub fn has_database_directory(&mut self) -> bool {
         fs::metadata(self.directory.join(".grafbase/database")).is_ok()
     }
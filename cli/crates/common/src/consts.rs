@@ -4,6 +4,11 @@ use std::ops::Range;
 pub const DEFAULT_PORT: u16 = 4000;
 /// the max port to use when searching for an available port
 pub const MAX_PORT: u16 = u16::MAX;
+/// the path under which `grafbase dev` serves its admin API, only reachable from the local machine
+pub const ADMIN_API_PATH: &str = "/_grafbase";
+/// required by the routes of the admin API changing the state of the server,
+/// which web pages of other origins can't send without a CORS preflight the admin API never allows
+pub const ADMIN_REQUEST_HEADER: &str = "x-grafbase-admin";
 /// localhost IP
 pub const LOCALHOST: &str = "127.0.0.1";
 /// the name of the directory indicating a grafbase project
@@ -20,8 +25,22 @@ pub const DOT_GRAFBASE_DIRECTORY: &str = ".grafbase";
 pub const REGISTRY_FILE: &str = "registry.json";
 /// the /resolvers directory containing resolver implementations
 pub const RESOLVERS_DIRECTORY_NAME: &str = "resolvers";
+/// the directory within /resolvers containing fixture payloads and snapshots for `grafbase resolvers test`
+pub const RESOLVER_FIXTURES_DIRECTORY_NAME: &str = "__fixtures__";
+/// the default value of `GRAFBASE_ENV` when running locally
+pub const DEFAULT_GRAFBASE_ENV: &str = "dev";
+/// the default amount of seconds a resolver invocation may take before timing out
+pub const DEFAULT_RESOLVER_TIMEOUT_SECONDS: u64 = 30;
+/// the default amount of milliseconds to wait for further changes before reloading `grafbase dev`
+pub const DEFAULT_WATCH_DEBOUNCE_MILLISECONDS: u64 = 1000;
 /// the wrangler installation directory within ~/.grafbase
 pub const WRANGLER_DIRECTORY_NAME: &str = "wrangler";
+/// the directory within ~/.grafbase containing the local certificate authority used by `grafbase dev --https`
+pub const CERTIFICATES_DIRECTORY_NAME: &str = "certificates";
+/// the certificate of the local certificate authority, to be trusted by browsers and clients
+pub const LOCAL_CA_CERTIFICATE_FILE_NAME: &str = "ca.pem";
+/// the private key of the local certificate authority
+pub const LOCAL_CA_KEY_FILE_NAME: &str = "ca-key.pem";
 /// the tracing filter to be used when tracing is on
 pub const TRACE_LOG_FILTER: &str = "grafbase=trace,grafbase_local_common=trace,grafbase_local_server=trace,grafbase_local_backend=trace,tower_http=debug";
 /// the tracing filter to be used when tracing is off
@@ -3,7 +3,8 @@
 use crate::{
     consts::{
         DATABASE_DIRECTORY, DOT_GRAFBASE_DIRECTORY, GRAFBASE_DIRECTORY_NAME, GRAFBASE_HOME, GRAFBASE_SCHEMA_FILE_NAME,
-        GRAFBASE_TS_CONFIG_FILE_NAME, REGISTRY_FILE, RESOLVERS_DIRECTORY_NAME, WRANGLER_DIRECTORY_NAME,
+        GRAFBASE_TS_CONFIG_FILE_NAME, REGISTRY_FILE, RESOLVERS_DIRECTORY_NAME, RESOLVER_FIXTURES_DIRECTORY_NAME,
+        WRANGLER_DIRECTORY_NAME,
     },
     errors::CommonError,
 };
@@ -59,7 +60,7 @@ impl GrafbaseSchemaPath {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Warning {
     message: Cow<'static, str>,
     hint: Option<Cow<'static, str>>,
@@ -109,6 +110,8 @@ pub struct Project {
     pub registry_path: PathBuf,
     /// the path of the `grafbase/resolvers` directory.
     pub resolvers_source_path: PathBuf,
+    /// the path of the `grafbase/resolvers/__fixtures__` directory, containing fixture payloads for resolver tests.
+    pub resolver_fixtures_path: PathBuf,
     /// the path within `$PROJECT/.grafbase/` containing build artifacts for custom resolvers.
     pub resolvers_build_artifact_path: PathBuf,
     /// the path within '$PROJECT/.grafbase' containing the database
@@ -169,6 +172,7 @@ impl Project {
         let dot_grafbase_directory_path = path.join(DOT_GRAFBASE_DIRECTORY);
         let registry_path = dot_grafbase_directory_path.join(REGISTRY_FILE);
         let resolvers_source_path = grafbase_directory_path.join(RESOLVERS_DIRECTORY_NAME);
+        let resolver_fixtures_path = resolvers_source_path.join(RESOLVER_FIXTURES_DIRECTORY_NAME);
         let resolvers_build_artifact_path = dot_grafbase_directory_path.join(RESOLVERS_DIRECTORY_NAME);
         let database_directory_path = dot_grafbase_directory_path.join(DATABASE_DIRECTORY);
 
@@ -179,6 +183,7 @@ impl Project {
             grafbase_directory_path,
             registry_path,
             resolvers_source_path,
+            resolver_fixtures_path,
             resolvers_build_artifact_path,
             database_directory_path,
         })
@@ -25,4 +25,7 @@ pub enum CommonError {
     /// returned if ~/.grafbase could not be created
     #[error("could not create '~/.grafbase'\ncaused by: {0}")]
     CreateUserDotGrafbaseFolder(std::io::Error),
+    /// returned if a pattern passed via `--redact` is not a valid regular expression
+    #[error("could not parse the redaction pattern\ncaused by: {0}")]
+    RedactionPattern(regex::Error),
 }
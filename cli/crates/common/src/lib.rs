@@ -9,5 +9,6 @@ pub mod consts;
 pub mod debug_macros;
 pub mod environment;
 pub mod errors;
+pub mod redaction;
 pub mod types;
 pub mod utils;
@@ -0,0 +1,124 @@
+use crate::errors::CommonError;
+use regex::Regex;
+use std::borrow::Cow;
+use std::io::{self, Write};
+use std::sync::{PoisonError, RwLock};
+
+/// the replacement for redacted values
+pub const REDACTED: &str = "[redacted]";
+
+/// values shorter than this are not treated as secrets to avoid masking common words and flags (e.g. `true`)
+const MIN_SECRET_LENGTH: usize = 8;
+
+struct Redactor {
+    /// sorted by length in descending order so that secrets containing other secrets are fully masked
+    secrets: Vec<String>,
+    patterns: Vec<Regex>,
+}
+
+static REDACTOR: RwLock<Redactor> = RwLock::new(Redactor {
+    secrets: Vec::new(),
+    patterns: Vec::new(),
+});
+
+/// adds patterns for values to be redacted in addition to the known secrets
+///
+/// # Errors
+///
+/// returns [`CommonError::RedactionPattern`] if one of the patterns is not a valid regular expression
+pub fn add_patterns<S: AsRef<str>>(patterns: &[S]) -> Result<(), CommonError> {
+    let patterns = patterns
+        .iter()
+        .map(|pattern| Regex::new(pattern.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CommonError::RedactionPattern)?;
+
+    REDACTOR
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .patterns
+        .extend(patterns);
+
+    Ok(())
+}
+
+/// replaces the known secrets, e.g. with the values of the variables loaded from `.env` files
+pub fn set_secrets(secrets: impl IntoIterator<Item = String>) {
+    let mut secrets: Vec<_> = secrets
+        .into_iter()
+        .filter(|secret| secret.len() >= MIN_SECRET_LENGTH)
+        .collect();
+
+    secrets.sort_unstable_by(|left, right| right.len().cmp(&left.len()).then_with(|| left.cmp(right)));
+    secrets.dedup();
+
+    REDACTOR.write().unwrap_or_else(PoisonError::into_inner).secrets = secrets;
+}
+
+/// masks the known secrets and any matches of the redaction patterns within `text`
+#[must_use]
+pub fn redact(text: &str) -> Cow<'_, str> {
+    let redactor = REDACTOR.read().unwrap_or_else(PoisonError::into_inner);
+
+    let mut text = Cow::Borrowed(text);
+
+    for secret in &redactor.secrets {
+        if text.contains(secret.as_str()) {
+            text = Cow::Owned(text.replace(secret.as_str(), REDACTED));
+        }
+    }
+
+    for pattern in &redactor.patterns {
+        if let Cow::Owned(replaced) = pattern.replace_all(&text, REDACTED) {
+            text = Cow::Owned(replaced);
+        }
+    }
+
+    text
+}
+
+/// a writer redacting anything written to it before passing it on, e.g. for tracing output
+pub struct RedactedWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> RedactedWriter<W> {
+    #[must_use]
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+}
+
+impl<W: Write> Write for RedactedWriter<W> {
+    // tracing writes each event with a single call, so secrets are not split across calls
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        let text = String::from_utf8_lossy(buffer);
+        self.inner.write_all(redact(&text).as_bytes())?;
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_patterns, redact, set_secrets, REDACTED};
+
+    #[test]
+    fn redaction() {
+        set_secrets([
+            "sk_live_1234".to_owned(),
+            "sk_live_1234_extended".to_owned(),
+            "true".to_owned(),
+        ]);
+        add_patterns(&[r"ghp_[A-Za-z0-9]+"]).unwrap();
+
+        assert_eq!(
+            redact("key=sk_live_1234_extended other=sk_live_1234 flag=true token=ghp_abc123"),
+            format!("key={REDACTED} other={REDACTED} flag=true token={REDACTED}")
+        );
+        assert_eq!(redact("nothing to see here"), "nothing to see here");
+    }
+}
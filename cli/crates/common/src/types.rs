@@ -1,4 +1,4 @@
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 
 #[derive(Clone, Copy)]
 pub enum LocalAddressType {
@@ -8,7 +8,7 @@ pub enum LocalAddressType {
     Unspecified,
 }
 
-#[derive(Clone, Copy, Debug, serde::Deserialize)]
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ResolverMessageLevel {
     Debug,
@@ -26,3 +26,9 @@ impl LocalAddressType {
         }
     }
 }
+
+impl From<LocalAddressType> for IpAddr {
+    fn from(local_address_type: LocalAddressType) -> Self {
+        IpAddr::V4(local_address_type.to_ip_v4())
+    }
+}
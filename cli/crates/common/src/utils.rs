@@ -1,29 +1,51 @@
-use crate::{consts::MAX_PORT, types::LocalAddressType};
+use crate::consts::MAX_PORT;
+use std::net::IpAddr;
 use std::{any::Any, net::TcpListener};
 
-/// determines if a port or port range are available
+/// determines if a port or port range are available on an address (e.g. [`crate::types::LocalAddressType`])
 #[must_use]
-pub fn find_available_port(search: bool, start_port: u16, local_address_type: LocalAddressType) -> Option<u16> {
+pub fn find_available_port(search: bool, start_port: u16, local_address: impl Into<IpAddr>) -> Option<u16> {
     if search {
-        find_available_port_in_range(start_port..MAX_PORT, local_address_type)
+        find_available_port_in_range(start_port..MAX_PORT, local_address)
     } else {
-        let local_address = local_address_type.to_ip_v4();
-        TcpListener::bind((local_address, start_port))
+        TcpListener::bind((local_address.into(), start_port))
             .is_ok()
             .then_some(start_port)
     }
 }
 
-/// finds an available port within a range
+/// finds an available port within a range on an address (e.g. [`crate::types::LocalAddressType`])
 #[must_use]
-pub fn find_available_port_in_range<R>(mut range: R, local_address_type: LocalAddressType) -> Option<u16>
+pub fn find_available_port_in_range<R>(mut range: R, local_address: impl Into<IpAddr>) -> Option<u16>
 where
     R: ExactSizeIterator<Item = u16>,
 {
-    let local_address = local_address_type.to_ip_v4();
+    let local_address = local_address.into();
     range.find(|port| TcpListener::bind((local_address, *port)).is_ok())
 }
 
+/// the addresses of the network interfaces other devices can reach the server at when listening on all interfaces
+#[must_use]
+pub fn network_addresses(host: IpAddr) -> Vec<IpAddr> {
+    if !host.is_unspecified() {
+        return Vec::new();
+    }
+
+    if_addrs::get_if_addrs()
+        .map(|interfaces| {
+            interfaces
+                .iter()
+                .filter(|interface| !interface.is_loopback())
+                .map(if_addrs::Interface::ip)
+                // listening on `::` accepts IPv4 connections as well
+                .filter(|address| host.is_ipv6() || address.is_ipv4())
+                // link-local IPv6 addresses require a zone index to be reachable
+                .filter(|address| !matches!(address, IpAddr::V6(address) if address.segments()[0] & 0xffc0 == 0xfe80))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// converts an unknown panic parameter from [`std::thread::JoinHandle`] `join` to an [`Option<String>`]
 #[must_use]
 pub fn get_thread_panic_message(parameter: &Box<dyn Any + Send>) -> Option<String> {
@@ -0,0 +1,288 @@
+use crate::bridge::ResolverMessage;
+use crate::consts::ADMIN_MESSAGES_BOUND;
+use crate::event::Event;
+use crate::resolver_logs;
+use crate::types::{ResolverBuild, ServerMessage, ServerState, ServerStatus, ShutdownHandle};
+use axum::body::StreamBody;
+use axum::extract::{ConnectInfo, Query, State};
+use axum::http::uri::Authority;
+use axum::http::{header, Request, StatusCode, Uri};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::Utc;
+use common::consts::ADMIN_REQUEST_HEADER;
+use common::environment::Project;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// the state shared by the handlers of the admin API
+#[allow(clippy::module_name_repetitions)]
+pub struct AdminState {
+    /// the address the server listens on
+    host: IpAddr,
+    status: Mutex<ServerStatus>,
+    event_bus: broadcast::Sender<Event>,
+    /// the server messages serialized as JSON lines, followed by CLI invocations attached to the server
+    messages: broadcast::Sender<String>,
+    /// ends the streamed messages, which would otherwise hold up a graceful shutdown
+    shutdown: ShutdownHandle,
+}
+
+impl AdminState {
+    pub fn new(address: SocketAddr, event_bus: broadcast::Sender<Event>, shutdown: ShutdownHandle) -> Self {
+        Self {
+            host: address.ip(),
+            status: Mutex::new(ServerStatus {
+                pid: std::process::id(),
+                port: address.port(),
+                state: ServerState::Building,
+                started_at: Utc::now(),
+                reloaded_at: None,
+                changed_paths: Vec::new(),
+                compilation_error: None,
+                resolvers: Vec::new(),
+            }),
+            event_bus,
+            messages: broadcast::channel(ADMIN_MESSAGES_BOUND).0,
+            shutdown,
+        }
+    }
+
+    /// keeps track of the status of the server from the messages it sends
+    pub fn record(&self, message: &ServerMessage) {
+        if self.messages.receiver_count() > 0 {
+            let _: Result<_, _> = self
+                .messages
+                .send(serde_json::to_string(message).expect("must serialise") + "\n");
+        }
+
+        let mut status = self.status.lock().expect("must not be poisoned");
+
+        match message {
+            ServerMessage::Ready(_) => {
+                status.state = ServerState::Ready;
+                status.compilation_error = None;
+            }
+            ServerMessage::Reload(paths) => {
+                status.state = ServerState::Building;
+                status.reloaded_at = Some(Utc::now());
+                status.changed_paths = paths.clone();
+            }
+            ServerMessage::CompleteResolverBuild { name, duration } => {
+                let build = ResolverBuild {
+                    name: name.clone(),
+                    duration_ms: duration.as_millis(),
+                    built_at: Utc::now(),
+                };
+
+                if let Some(resolver) = status.resolvers.iter_mut().find(|resolver| resolver.name == *name) {
+                    *resolver = build;
+                } else {
+                    status.resolvers.push(build);
+                    status.resolvers.sort_by(|left, right| left.name.cmp(&right.name));
+                }
+            }
+            ServerMessage::CompilationError(error) => {
+                status.state = ServerState::Failed;
+                status.compilation_error = Some(error.clone());
+            }
+            ServerMessage::StartResolverBuild(_)
+            | ServerMessage::ResolverMessage { .. }
+            | ServerMessage::Warnings(_) => {}
+        }
+    }
+
+    fn status(&self) -> ServerStatus {
+        self.status.lock().expect("must not be poisoned").clone()
+    }
+}
+
+/// the routes of the admin API, nested under [`common::consts::ADMIN_API_PATH`] by the proxy
+pub fn router<S>(admin_state: Arc<AdminState>) -> Router<S> {
+    let changing_routes = Router::new()
+        .route("/reload", post(reload))
+        .route("/reset", post(reset))
+        .route_layer(middleware::from_fn(admin_header));
+
+    Router::new()
+        .route("/status", get(status))
+        .route("/resolvers", get(resolvers))
+        .route("/registry", get(registry))
+        .route("/logs", get(logs))
+        .route("/messages", get(messages))
+        .merge(changing_routes)
+        .route_layer(middleware::from_fn(same_origin))
+        .route_layer(middleware::from_fn_with_state(admin_state.clone(), local_only))
+        .with_state(admin_state)
+}
+
+/// the admin API can reset the database, so it isn't exposed to other devices when listening on a network interface.
+/// requests from the local machine come from a loopback address, or from the address of the server itself
+/// when it listens on a single network interface
+async fn local_only<B>(
+    State(admin_state): State<Arc<AdminState>>,
+    ConnectInfo(remote_address): ConnectInfo<SocketAddr>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Result<Response, StatusCode> {
+    let remote_ip = match remote_address.ip() {
+        IpAddr::V6(address) => address.to_ipv4_mapped().map_or(IpAddr::V6(address), IpAddr::V4),
+        address @ IpAddr::V4(_) => address,
+    };
+
+    if remote_ip.is_loopback() || (!admin_state.host.is_unspecified() && remote_ip == admin_state.host) {
+        Ok(next.run(request).await)
+    } else {
+        Err(StatusCode::FORBIDDEN)
+    }
+}
+
+/// rejects requests sent by web pages of other origins, including pages of other sites resolving to the local machine.
+/// the pages served by the development server itself are reached through an IP address or `localhost`
+async fn same_origin<B>(request: Request<B>, next: Next<B>) -> Result<Response, StatusCode> {
+    let Some(origin) = request.headers().get(header::ORIGIN) else {
+        return Ok(next.run(request).await);
+    };
+
+    let host = request
+        .uri()
+        .authority()
+        .map(Authority::as_str)
+        .or_else(|| request.headers().get(header::HOST).and_then(|host| host.to_str().ok()));
+
+    let origin_authority = origin
+        .to_str()
+        .ok()
+        .and_then(|origin| origin.parse::<Uri>().ok())
+        .and_then(|origin| origin.authority().cloned());
+
+    match origin_authority {
+        Some(origin_authority) if Some(origin_authority.as_str()) == host && local_host(origin_authority.host()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::FORBIDDEN),
+    }
+}
+
+fn local_host(host: &str) -> bool {
+    host == "localhost"
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok()
+}
+
+/// the routes changing the state of the server (e.g. deleting the database) require [`ADMIN_REQUEST_HEADER`]
+async fn admin_header<B>(request: Request<B>, next: Next<B>) -> Result<Response, StatusCode> {
+    if request.headers().contains_key(ADMIN_REQUEST_HEADER) {
+        Ok(next.run(request).await)
+    } else {
+        Err(StatusCode::FORBIDDEN)
+    }
+}
+
+#[allow(clippy::unused_async)]
+async fn status(State(admin_state): State<Arc<AdminState>>) -> Json<ServerStatus> {
+    Json(admin_state.status())
+}
+
+#[allow(clippy::unused_async)]
+async fn resolvers(State(admin_state): State<Arc<AdminState>>) -> Json<Vec<ResolverBuild>> {
+    Json(admin_state.status().resolvers)
+}
+
+async fn registry() -> Result<impl IntoResponse, StatusCode> {
+    match tokio::fs::read(&Project::get().registry_path).await {
+        Ok(registry) => Ok(([(header::CONTENT_TYPE, "application/json")], registry)),
+        // the project has not been built yet
+        Err(error) if error.kind() == ErrorKind::NotFound => Err(StatusCode::NOT_FOUND),
+        Err(error) => {
+            error!("could not read the registry: {error}");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct LogsQuery {
+    resolver: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResolverLogs {
+    resolver_name: String,
+    entries: Vec<ResolverMessage>,
+}
+
+async fn logs(Query(query): Query<LogsQuery>) -> Result<Json<Vec<ResolverLogs>>, StatusCode> {
+    let entries = tokio::task::spawn_blocking(move || resolver_logs::read_entries(query.resolver.as_deref()))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|error| {
+            error!("could not read the resolver logs: {error}");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(
+        entries
+            .into_iter()
+            .map(|(resolver_name, entries)| ResolverLogs { resolver_name, entries })
+            .collect(),
+    ))
+}
+
+/// streams the messages of the server as JSON lines until it stops
+#[allow(clippy::unused_async)]
+async fn messages(State(admin_state): State<Arc<AdminState>>) -> impl IntoResponse {
+    let receiver = admin_state.messages.subscribe();
+    let shutdown = admin_state.shutdown.clone();
+
+    let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(message) => return Some((Ok::<_, Infallible>(message), receiver)),
+                // slow clients miss messages rather than holding up the server
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+    .take_until(async move { shutdown.requested().await });
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        StreamBody::new(stream),
+    )
+}
+
+#[allow(clippy::unused_async)]
+async fn reload(State(admin_state): State<Arc<AdminState>>) -> StatusCode {
+    // an empty list of changes rebuilds everything, as on startup
+    match admin_state.event_bus.send(Event::Reload(Vec::new())) {
+        Ok(_) => StatusCode::ACCEPTED,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
+
+async fn reset(State(admin_state): State<Arc<AdminState>>) -> StatusCode {
+    // the current generation keeps its open database until it's replaced by the reloaded one, which creates a new database
+    match tokio::fs::remove_dir_all(&Project::get().database_directory_path).await {
+        Ok(()) => {}
+        Err(error) if error.kind() == ErrorKind::NotFound => {}
+        Err(error) => {
+            error!("could not delete the database: {error}");
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+    }
+
+    reload(State(admin_state)).await
+}
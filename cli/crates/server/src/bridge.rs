@@ -9,4 +9,5 @@ mod types;
 
 pub mod errors;
 
+pub use resolvers::{call_resolver, ResolverMessage};
 pub use server::start;
@@ -1,4 +1,5 @@
 use super::api_counterfeit::search;
+use super::resolvers::ResolverMessage;
 use super::sqlite::extended_error_codes;
 use super::types::Constraint;
 use super::types::Operation;
@@ -7,8 +8,11 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::response::Response;
 use axum::Json;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use sqlx::Error as SqlxError;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -23,6 +27,30 @@ pub enum ApiError {
     ServerError,
     #[error("resolver {0} is invalid")]
     ResolverInvalid(String),
+    /// returned to the worker if a resolver does not respond in time
+    #[error("resolver {0} timed out after {1:?}")]
+    ResolverTimeout(String, Duration),
+    /// returned to the worker if a resolver throws, propagating the thrown error
+    #[error("resolver {resolver_name} threw an error: {error}")]
+    ResolverThrew {
+        resolver_name: String,
+        error: ResolverThrownError,
+        log_entries: Vec<ResolverMessage>,
+    },
+}
+
+/// an error thrown by a resolver, as reported by the resolver wrapper worker
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ResolverThrownError {
+    pub message: String,
+    pub stack: Option<String>,
+}
+
+impl fmt::Display for ResolverThrownError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // stack traces in JS already start with the error message
+        formatter.write_str(self.stack.as_deref().unwrap_or(&self.message))
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -52,6 +80,23 @@ impl IntoResponse for ApiError {
             ApiError::SqlError(_) | ApiError::ServerError | ApiError::ResolverInvalid(_) => {
                 StatusCode::INTERNAL_SERVER_ERROR.into_response()
             }
+
+            ApiError::ResolverTimeout(..) => (
+                StatusCode::GATEWAY_TIMEOUT,
+                Json(json!({ "message": self.to_string() })),
+            )
+                .into_response(),
+
+            ApiError::ResolverThrew {
+                resolver_name, error, ..
+            } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({
+                    "message": format!("resolver {resolver_name} threw an error: {}", error.message),
+                    "stack": error.stack,
+                })),
+            )
+                .into_response(),
         }
     }
 }
@@ -11,11 +11,12 @@ use crate::{
 use common::environment::Project;
 use reqwest::Client;
 use sqlx::{query, query_as, Connection, SqliteConnection};
+use std::future::Future;
 use tokio::sync::broadcast::Sender;
 use tokio::time::sleep;
 use uuid::Uuid;
 
-async fn event_listener(worker_port: u16) -> Result<(), ServerError> {
+async fn event_listener(worker_port: u16, retired: impl Future<Output = ()>) -> Result<(), ServerError> {
     let project = Project::get();
     // existence is already guaranteed by the bridge server
     let database_file = project.database_directory_path.join(DATABASE_FILE);
@@ -38,66 +39,87 @@ async fn event_listener(worker_port: u16) -> Result<(), ServerError> {
 
     query(&clean_modifications_table).execute(&mut connection).await?;
 
+    tokio::pin!(retired);
+
     loop {
-        sleep(MODIFICATION_POLL_INTERVAL).await;
+        tokio::select! {
+            () = sleep(MODIFICATION_POLL_INTERVAL) => {}
+            () = &mut retired => {
+                // a replaced generation keeps its worker until the bridge is done, so the last modifications
+                // can still be delivered (the worker of an abandoned build may already be gone)
+                trace!("delivering the remaining modifications");
+                deliver_modifications(&mut connection, &client, worker_port).await;
+                connection.close().await?;
+                return Ok(());
+            }
+        }
+
+        deliver_modifications(&mut connection, &client, worker_port).await;
+    }
+}
 
-        let delete_and_return_modifications = format!("DELETE FROM {MODIFICATIONS_TABLE_NAME} RETURNING *");
+/// sends the pending modifications to the stream router of the worker
+async fn deliver_modifications(connection: &mut SqliteConnection, client: &Client, worker_port: u16) {
+    let delete_and_return_modifications = format!("DELETE FROM {MODIFICATIONS_TABLE_NAME} RETURNING *");
 
-        let modifications = query_as::<_, Modification>(&delete_and_return_modifications);
+    let modifications = query_as::<_, Modification>(&delete_and_return_modifications);
 
-        let results = match modifications.fetch_all(&mut connection).await {
-            Ok(results) => results,
-            // retry on the next interval if the DB is busy (due to a trigger writing an update)
-            Err(err) => {
-                trace!("Failed to retrieve latest modifications with error: {:?}", err);
-                // TODO: narrow this
-                continue;
+    let results = match modifications.fetch_all(connection).await {
+        Ok(results) => results,
+        // retry on the next interval if the DB is busy (due to a trigger writing an update)
+        Err(err) => {
+            trace!("Failed to retrieve latest modifications with error: {:?}", err);
+            // TODO: narrow this
+            return;
+        }
+    };
+    if !results.is_empty() {
+        let dynamo_events = results
+            .iter()
+            .map(|result| EventRecord {
+                aws_region: DEFAULT_AWS_REGION.to_owned(),
+                change: StreamRecord {
+                    approximate_creation_date_time: result.approximate_creation_date_time,
+                    keys: result.to_keys(),
+                    new_image: result.document_new.clone().unwrap_or_default(),
+                    old_image: result.document_old.clone().unwrap_or_default(),
+                    // unused by the stream router
+                    size_bytes: 0,
+                },
+                event_id: Uuid::new_v4().to_string(),
+                event_name: result.to_event_name().to_owned(),
+                event_source_arn: Some(RECORDS_TABLE_NAME.to_owned()),
+            })
+            .collect::<Vec<_>>();
+
+        let response = match client
+            .post(format!(
+                "http://127.0.0.1:{worker_port}/stream-router/main/dynamodb/{DEFAULT_AWS_REGION}"
+            ))
+            .header("x-api-key", CLI_API_KEY)
+            .json(&dynamo_events)
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(error) => {
+                error!("could not contact the stream router: {error}");
+                return;
             }
         };
-        if !results.is_empty() {
-            let dynamo_events = results
-                .iter()
-                .map(|result| EventRecord {
-                    aws_region: DEFAULT_AWS_REGION.to_owned(),
-                    change: StreamRecord {
-                        approximate_creation_date_time: result.approximate_creation_date_time,
-                        keys: result.to_keys(),
-                        new_image: result.document_new.clone().unwrap_or_default(),
-                        old_image: result.document_old.clone().unwrap_or_default(),
-                        // unused by the stream router
-                        size_bytes: 0,
-                    },
-                    event_id: Uuid::new_v4().to_string(),
-                    event_name: result.to_event_name().to_owned(),
-                    event_source_arn: Some(RECORDS_TABLE_NAME.to_owned()),
-                })
-                .collect::<Vec<_>>();
-
-            let response = client
-                .post(format!(
-                    "http://127.0.0.1:{worker_port}/stream-router/main/dynamodb/{DEFAULT_AWS_REGION}"
-                ))
-                .header("x-api-key", CLI_API_KEY)
-                .json(&dynamo_events)
-                .send()
-                .await
-                // TODO: consider if this should panic or show a specific error to the user
-                .expect("could not contact the stream router");
-            trace!(
-                "Sent update to stream-router, responded with status: {}",
-                response.status()
-            );
-        }
+        trace!(
+            "Sent update to stream-router, responded with status: {}",
+            response.status()
+        );
     }
 }
 
 pub async fn start(worker_port: u16, event_bus: Sender<Event>) -> Result<(), ServerError> {
     trace!("starting db event listener");
 
-    tokio::select! {
-        _ = wait_for_event(event_bus.subscribe(), |event| matches!(event, Event::Reload(_))) => {}
-        event_listener_result = event_listener(worker_port) => {  event_listener_result? }
-    }
-
-    Ok(())
+    event_listener(
+        worker_port,
+        wait_for_event(event_bus.subscribe(), |event| *event == Event::Retire),
+    )
+    .await
 }
@@ -1,71 +1,296 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
+use crate::resolver_logs::ResolverLogBuffer;
 use crate::types::ServerMessage;
 
+use common::redaction::redact;
 use common::types::ResolverMessageLevel;
 
-use super::errors::ApiError;
+use super::errors::{ApiError, ResolverThrownError};
 
-#[derive(serde::Serialize)]
-struct ResolverContext<'a> {
-    env: &'a HashMap<String, String>,
-}
-
-#[derive(serde::Serialize)]
-struct ResolverArgs<'a> {
-    context: ResolverContext<'a>,
-}
-
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
-struct ResolverMessage {
-    message: String,
-    level: ResolverMessageLevel,
+pub struct ResolverMessage {
+    pub message: String,
+    pub level: ResolverMessageLevel,
 }
 
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ResolverResponse {
+    #[serde(default)]
     log_entries: Vec<ResolverMessage>,
+    #[serde(default)]
+    error: Option<ResolverThrownError>,
     #[serde(flatten)]
     rest: serde_json::Value,
 }
 
-pub async fn invoke_resolver(
-    bridge_sender: &tokio::sync::mpsc::Sender<ServerMessage>,
+/// adds the environment variables to the context of the invocation payload unless they are already present
+/// (e.g. within a fixture), to be exposed to the resolver as `process.env`
+fn with_environment(payload: &serde_json::Value, environment_variables: &HashMap<String, String>) -> serde_json::Value {
+    let mut payload = payload.clone();
+
+    if let Some(context) = payload
+        .as_object_mut()
+        .map(|payload| payload.entry("context").or_insert_with(|| serde_json::json!({})))
+        .and_then(serde_json::Value::as_object_mut)
+    {
+        context
+            .entry("env")
+            .or_insert_with(|| serde_json::to_value(environment_variables).expect("must serialise"));
+    }
+
+    payload
+}
+
+/// invokes a resolver via its worker, returning the result along with the log entries of the invocation
+///
+/// # Errors
+///
+/// returns [`ApiError::ResolverTimeout`] if the resolver does not respond within `timeout`
+///
+/// returns [`ApiError::ResolverThrew`] if the resolver threw an error
+///
+/// returns [`ApiError::ResolverInvalid`] if the worker could not be reached or returned an unexpected response
+pub async fn call_resolver(
+    client: &reqwest::Client,
     port: u16,
     resolver_name: &str,
     payload: &serde_json::Value,
-) -> Result<serde_json::Value, ApiError> {
-    use futures_util::TryFutureExt;
+    environment_variables: &HashMap<String, String>,
+    timeout: Duration,
+) -> Result<(serde_json::Value, Vec<ResolverMessage>), ApiError> {
     trace!("resolver invocation of '{resolver_name}'");
-    let json_string = reqwest::Client::new()
+
+    let response = client
         .post(format!("http://127.0.0.1:{port}/resolver/{resolver_name}/invoke"))
-        .json(&payload)
+        .json(&with_environment(payload, environment_variables))
+        .timeout(timeout)
         .send()
-        .inspect_err(|err| error!("resolver worker error: {err:?}"))
         .await
-        .map_err(|_| ApiError::ServerError)?
+        .map_err(|error| request_error(resolver_name, timeout, &error))?;
+
+    let status = response.status();
+
+    let json_string = response
         .text()
-        .inspect_err(|err| error!("resolver worker error: {err:?}"))
         .await
-        .map_err(|_| ApiError::ServerError)?;
+        .map_err(|error| request_error(resolver_name, timeout, &error))?;
+
+    let ResolverResponse {
+        log_entries,
+        error,
+        rest,
+    } = match serde_json::from_str(&json_string) {
+        Ok(response) => response,
+        // errors thrown outside of the wrapper (e.g. while loading the resolver) are returned as plain text
+        Err(_) if !status.is_success() => {
+            return Err(ApiError::ResolverThrew {
+                resolver_name: resolver_name.to_owned(),
+                error: ResolverThrownError {
+                    message: redact(&json_string).into_owned(),
+                    stack: None,
+                },
+                log_entries: Vec::new(),
+            })
+        }
+        Err(error) => {
+            error!("deserialisation from '{json_string}' failed: {error:?}");
+            return Err(ApiError::ResolverInvalid(resolver_name.to_owned()));
+        }
+    };
+
+    let log_entries = log_entries
+        .into_iter()
+        .map(|ResolverMessage { message, level }| ResolverMessage {
+            message: redact(&message).into_owned(),
+            level,
+        })
+        .collect();
+
+    if let Some(ResolverThrownError { message, stack }) = error {
+        return Err(ApiError::ResolverThrew {
+            resolver_name: resolver_name.to_owned(),
+            error: ResolverThrownError {
+                message: redact(&message).into_owned(),
+                stack: stack.map(|stack| redact(&stack).into_owned()),
+            },
+            log_entries,
+        });
+    }
+
+    if !status.is_success() {
+        error!("resolver worker returned {status}: '{json_string}'");
+        return Err(ApiError::ResolverInvalid(resolver_name.to_owned()));
+    }
+
+    Ok((rest, log_entries))
+}
+
+fn request_error(resolver_name: &str, timeout: Duration, error: &reqwest::Error) -> ApiError {
+    if error.is_timeout() {
+        ApiError::ResolverTimeout(resolver_name.to_owned(), timeout)
+    } else {
+        error!("resolver worker error: {error:?}");
+        ApiError::ResolverInvalid(resolver_name.to_owned())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn invoke_resolver(
+    bridge_sender: &tokio::sync::mpsc::Sender<ServerMessage>,
+    log_buffer: &ResolverLogBuffer,
+    client: &reqwest::Client,
+    port: u16,
+    resolver_name: &str,
+    payload: &serde_json::Value,
+    environment_variables: &HashMap<String, String>,
+    timeout: Duration,
+) -> Result<serde_json::Value, ApiError> {
+    let result = call_resolver(client, port, resolver_name, payload, environment_variables, timeout).await;
+
+    let mut log_entries = match &result {
+        Ok((_, log_entries)) | Err(ApiError::ResolverThrew { log_entries, .. }) => log_entries.clone(),
+        Err(_) => Vec::new(),
+    };
 
-    let ResolverResponse { log_entries, rest } = serde_json::from_str(&json_string).map_err(|err| {
-        error!("deserialisation from '{json_string}' failed: {err:?}");
-        ApiError::ServerError
-    })?;
+    if let Err(error) = &result {
+        log_entries.push(ResolverMessage {
+            message: error.to_string(),
+            level: ResolverMessageLevel::Error,
+        });
+    }
+
+    log_buffer.push(resolver_name, &log_entries).await;
 
     for ResolverMessage { level, message } in log_entries {
-        bridge_sender
+        // the receiver is only dropped when the server is stopping
+        let _: Result<_, _> = bridge_sender
             .send(ServerMessage::ResolverMessage {
                 resolver_name: resolver_name.to_owned(),
                 level,
                 message,
             })
-            .await
-            .unwrap();
+            .await;
+    }
+
+    result.map(|(rest, _)| rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::call_resolver;
+    use crate::bridge::errors::ApiError;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const TIMEOUT: Duration = Duration::from_millis(200);
+
+    /// answers a single invocation with the given status and body after `delay`, returning the port of the worker
+    async fn resolver_worker(status: &'static str, body: &'static str, delay: Duration) -> u16 {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0; 4096];
+            let _: Result<_, _> = stream.read(&mut request).await;
+
+            tokio::time::sleep(delay).await;
+
+            let response = format!(
+                "HTTP/1.1 {status}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _: Result<_, _> = stream.write_all(response.as_bytes()).await;
+        });
+
+        port
     }
 
-    Ok(rest)
+    async fn invoke(port: u16, resolver_name: &str) -> Result<serde_json::Value, ApiError> {
+        call_resolver(
+            &reqwest::Client::new(),
+            port,
+            resolver_name,
+            &serde_json::json!({ "args": {} }),
+            &HashMap::new(),
+            TIMEOUT,
+        )
+        .await
+        .map(|(rest, _)| rest)
+    }
+
+    async fn response_body(error: ApiError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn resolver_timeout() {
+        let port = resolver_worker("200 OK", "{}", Duration::from_secs(5)).await;
+
+        let error = invoke(port, "slow").await.unwrap_err();
+        assert!(
+            matches!(&error, ApiError::ResolverTimeout(resolver_name, timeout) if resolver_name == "slow" && *timeout == TIMEOUT),
+            "{error:?}"
+        );
+
+        let (status, body) = response_body(error).await;
+        assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(body["message"], "resolver slow timed out after 200ms");
+    }
+
+    #[tokio::test]
+    async fn resolver_threw() {
+        let port = resolver_worker(
+            "500 Internal Server Error",
+            r#"{
+                "logEntries": [{ "message": "about to fail", "level": "info" }],
+                "error": { "message": "something went wrong", "stack": "Error: something went wrong\n    at Resolver" }
+            }"#,
+            Duration::ZERO,
+        )
+        .await;
+
+        let error = invoke(port, "failing").await.unwrap_err();
+        assert!(
+            matches!(
+                &error,
+                ApiError::ResolverThrew { error, log_entries, .. }
+                    if error.message == "something went wrong"
+                        && error.stack.as_deref() == Some("Error: something went wrong\n    at Resolver")
+                        && log_entries.len() == 1
+            ),
+            "{error:?}"
+        );
+
+        let (status, body) = response_body(error).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["message"], "resolver failing threw an error: something went wrong");
+        assert_eq!(body["stack"], "Error: something went wrong\n    at Resolver");
+    }
+
+    #[tokio::test]
+    async fn resolver_threw_while_loading() {
+        let port = resolver_worker(
+            "500 Internal Server Error",
+            "ReferenceError: missing is not defined",
+            Duration::ZERO,
+        )
+        .await;
+
+        let error = invoke(port, "broken").await.unwrap_err();
+        assert!(
+            matches!(&error, ApiError::ResolverThrew { error, .. } if error.message == "ReferenceError: missing is not defined"),
+            "{error:?}"
+        );
+    }
 }
@@ -9,6 +9,7 @@ use crate::bridge::listener;
 use crate::bridge::types::{Constraint, ConstraintKind, OperationKind};
 use crate::errors::ServerError;
 use crate::event::{wait_for_event, Event};
+use crate::resolver_logs::ResolverLogBuffer;
 use crate::types::ServerMessage;
 use axum::extract::State;
 use axum::Json;
@@ -19,10 +20,12 @@ use sqlx::query::{Query, QueryAs};
 use sqlx::{migrate::MigrateDatabase, query, query_as, sqlite::SqlitePoolOptions, Sqlite, SqlitePool};
 use tokio::fs;
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
 use std::net::{Ipv4Addr, SocketAddr};
 use std::sync::Arc;
+use std::time::Duration;
 
 use tower_http::trace::TraceLayer;
 
@@ -30,6 +33,10 @@ struct HandlerState {
     worker_port: u16,
     pool: SqlitePool,
     bridge_sender: tokio::sync::mpsc::Sender<ServerMessage>,
+    resolver_client: reqwest::Client,
+    resolver_log_buffer: ResolverLogBuffer,
+    resolver_timeout: Duration,
+    environment_variables: HashMap<String, String>,
 }
 
 async fn query_endpoint(
@@ -128,12 +135,15 @@ async fn invoke_resolver_endpoint(
     trace!("resolver invocation\n\n{:#?}\n", payload);
     super::resolvers::invoke_resolver(
         &handler_state.bridge_sender,
+        &handler_state.resolver_log_buffer,
+        &handler_state.resolver_client,
         handler_state.worker_port,
         &payload.resolver_name,
         &payload.payload,
+        &handler_state.environment_variables,
+        handler_state.resolver_timeout,
     )
     .await
-    .map_err(|_| ApiError::ResolverInvalid(payload.resolver_name.clone()))
     .map(Json)
 }
 
@@ -142,6 +152,8 @@ pub async fn start(
     worker_port: u16,
     bridge_sender: tokio::sync::mpsc::Sender<ServerMessage>,
     event_bus: tokio::sync::broadcast::Sender<Event>,
+    environment_variables: HashMap<String, String>,
+    resolver_timeout: Duration,
 ) -> Result<(), ServerError> {
     trace!("starting bridge at port {port}");
 
@@ -175,6 +187,10 @@ pub async fn start(
         worker_port,
         pool,
         bridge_sender,
+        resolver_client: reqwest::Client::new(),
+        resolver_log_buffer: ResolverLogBuffer::default(),
+        resolver_timeout,
+        environment_variables,
     });
 
     let router = Router::new()
@@ -189,16 +205,16 @@ pub async fn start(
 
     let server = axum::Server::bind(&socket_address)
         .serve(router.into_make_service())
-        .with_graceful_shutdown(wait_for_event(event_bus.subscribe(), |event| {
-            matches!(event, Event::Reload(_))
-        }));
+        .with_graceful_shutdown(wait_for_event(event_bus.subscribe(), |event| *event == Event::Retire));
 
     event_bus.send(Event::BridgeReady).expect("cannot fail");
 
-    tokio::select! {
-        server_result = server => { server_result? }
-        listener_result = listener::start(worker_port, event_bus) => { listener_result? }
-    };
+    // both stop once retired, the server after finishing the requests in flight
+    // and the listener after delivering the remaining modifications
+    tokio::try_join!(
+        async { server.await.map_err(ServerError::from) },
+        listener::start(worker_port, event_bus)
+    )?;
 
     handler_state.pool.close().await;
 
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 pub const ASSET_VERSION_FILE: &str = "version.txt";
 pub const SCHEMA_PARSER_DIR: &str = "parser";
 pub const GENERATED_SCHEMAS_DIR: &str = "generated/schemas";
@@ -7,4 +9,40 @@ pub const GIT_IGNORE_FILE: &str = ".gitignore";
 pub const GIT_IGNORE_CONTENTS: &str = "*\n";
 pub const MIN_NODE_VERSION: &str = "v18.0.0";
 pub const DOT_ENV_FILE: &str = ".env";
+pub const LOCAL_DOT_ENV_SUFFIX: &str = "local";
+pub const DEVELOPMENT_DOT_ENV_SUFFIX: &str = "development";
 pub const TS_NODE_SCRIPT_PATH: &str = "node_modules/ts-node/dist/bin.js";
+pub const RESOLVER_FIXTURE_EXTENSION: &str = "json";
+pub const RESOLVER_SNAPSHOT_EXTENSION: &str = "snap";
+pub const WORKER_POLL_INTERVAL: Duration = Duration::from_millis(100);
+pub const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+pub const RESOLVER_LOGS_DIR: &str = "resolver-logs";
+// held by the development server running in a project, next to the information of the server
+pub const DEV_SERVER_LOCK_FILE: &str = "dev.lock";
+pub const DEV_SERVER_INFO_FILE: &str = "dev.json";
+pub const ADMIN_MESSAGES_BOUND: usize = 64;
+// the time given to each stage of a graceful shutdown (draining requests, stopping the servers)
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+pub const RESOLVER_LOG_EXTENSION: &str = "jsonl";
+pub const RESOLVER_LOG_BUFFER_SIZE: usize = 200;
+pub const RESOLVER_ENTRYPOINT_FILE: &str = "entrypoint.js";
+pub const RESOLVER_WRAPPER_FILE: &str = "wrapper.js";
+// sets `process.env` from the environment variables sent along with each invocation by the bridge.
+// the resolver is only imported once the first invocation came in (the dynamic import is bundled as
+// a lazily evaluated module), so that `process.env` is also set while the modules of the resolver are evaluated
+pub const RESOLVER_ENTRYPOINT_CONTENTS: &str = r"let wrapper;
+
+export default {
+  async fetch(request, env, context) {
+    const payload = await request
+      .clone()
+      .json()
+      .catch(() => ({}));
+    globalThis.process = { env: payload?.context?.env ?? {} };
+    if (!wrapper) {
+      wrapper = (await import('./wrapper.js')).default;
+    }
+    return wrapper.fetch(request, env, context);
+  },
+};
+";
@@ -1,15 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::mpsc::Sender;
+use std::sync::OnceLock;
 
 use common::environment::{Environment, Project};
 use futures_util::pin_mut;
 use itertools::Itertools;
-use tokio::io::AsyncWriteExt;
+use regex::Regex;
 use tokio::process::Command;
 
+use crate::consts::{RESOLVER_ENTRYPOINT_CONTENTS, RESOLVER_ENTRYPOINT_FILE, RESOLVER_WRAPPER_FILE};
 use crate::errors::ServerError;
+use crate::redacted_output;
 use crate::servers::DetectedResolver;
 use crate::types::ServerMessage;
 
@@ -22,15 +25,21 @@ async fn run_command<P: AsRef<Path>>(
 ) -> Result<(), ServerError> {
     trace!("running '{command_type} {}'", arguments.iter().format(" "));
 
-    let command = Command::new(command_type.to_string())
+    let mut command = Command::new(command_type.to_string())
         .envs(environment.iter().copied())
         .args(arguments)
-        .stdout(if tracing { Stdio::inherit() } else { Stdio::piped() })
-        .stderr(if tracing { Stdio::inherit() } else { Stdio::piped() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .current_dir(current_directory.as_ref())
+        // stopped along with an abandoned build
+        .kill_on_drop(true)
         .spawn()
         .map_err(|err| ServerError::ResolverPackageManagerCommandError(command_type, err))?;
 
+    if tracing {
+        redacted_output::forward(&mut command);
+    }
+
     let output = command
         .wait_with_output()
         .await
@@ -103,7 +112,6 @@ async fn guess_package_manager_from_package_root(path: impl AsRef<Path>) -> Opti
 async fn build_resolver(
     environment: &Environment,
     project: &Project,
-    environment_variables: &std::collections::HashMap<String, String>,
     resolver_name: &str,
     resolver_wrapper_worker_contents: &str,
     resolver_build_artifact_directory_path: &Path,
@@ -172,7 +180,7 @@ async fn build_resolver(
     tokio::fs::create_dir_all(&resolver_build_artifact_directory_path)
         .await
         .map_err(|_err| ServerError::CreateDir(resolver_build_artifact_directory_path.to_owned()))?;
-    let resolver_build_entrypoint_path = resolver_build_artifact_directory_path.join("entrypoint.js");
+    let resolver_build_entrypoint_path = resolver_build_artifact_directory_path.join(RESOLVER_ENTRYPOINT_FILE);
 
     let resolver_build_package_json_path = resolver_build_artifact_directory_path.join("package.json");
 
@@ -218,13 +226,22 @@ async fn build_resolver(
         .await
         .map_err(|err| ServerError::CreateResolverArtifactFile(resolver_input_file_path, err))?;
 
-    let entrypoint_contents = resolver_wrapper_worker_contents.replace(
+    let wrapper_contents = resolver_wrapper_worker_contents.replace(
         "${RESOLVER_MAIN_FILE_PATH}",
         resolver_js_file_path.to_str().expect("must be valid utf-8"),
     );
-    tokio::fs::write(&resolver_build_entrypoint_path, entrypoint_contents)
-        .await
-        .map_err(|err| ServerError::CreateResolverArtifactFile(resolver_build_entrypoint_path.clone(), err))?;
+
+    // the environment variables are injected per invocation rather than baked into the bundle,
+    // allowing changes to `.env` to be picked up without rebuilding the resolver
+    for (file_name, contents) in [
+        (RESOLVER_WRAPPER_FILE, wrapper_contents.as_str()),
+        (RESOLVER_ENTRYPOINT_FILE, RESOLVER_ENTRYPOINT_CONTENTS),
+    ] {
+        let path = resolver_build_artifact_directory_path.join(file_name);
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|err| ServerError::CreateResolverArtifactFile(path, err))?;
+    }
 
     let wrangler_output_directory_path = resolver_build_artifact_directory_path.join("wrangler");
     let outdir_argument = format!(
@@ -295,35 +312,6 @@ async fn build_resolver(
         })?;
     }
 
-    let process_env_prelude = format!(
-        "globalThis.process = {{ env: {} }};",
-        serde_json::to_string(&environment_variables).expect("must be valid JSON")
-    );
-
-    let (temp_file, temp_file_path) = tokio::task::spawn_blocking(tempfile::NamedTempFile::new)
-        .await?
-        .map_err(ServerError::CreateTemporaryFile)?
-        .into_parts();
-    {
-        let mut temp_file: tokio::fs::File = temp_file.into();
-        temp_file
-            .write_all(process_env_prelude.as_bytes())
-            .await
-            .map_err(|err| ServerError::CreateNotWriteToTemporaryFile(temp_file_path.to_path_buf(), err))?;
-        temp_file
-            .write_all(
-                &tokio::fs::read(wrangler_output_directory_path.join("entrypoint.js"))
-                    .await
-                    .expect("must succeed"),
-            )
-            .await
-            .map_err(|err| ServerError::CreateNotWriteToTemporaryFile(temp_file_path.to_path_buf(), err))?;
-    }
-    let entrypoint_js_path = wrangler_output_directory_path.join("entrypoint.js");
-    tokio::fs::copy(temp_file_path, &entrypoint_js_path)
-        .await
-        .map_err(|err| ServerError::CreateResolverArtifactFile(entrypoint_js_path, err))?;
-
     let slugified_resolver_name = slug::slugify(resolver_name);
     tokio::fs::write(
         wrangler_toml_file_path,
@@ -395,7 +383,6 @@ pub async fn install_wrangler(environment: &Environment, tracing: bool) -> Resul
 pub async fn build_resolvers(
     sender: &Sender<ServerMessage>,
     environment: &Environment,
-    environment_variables: &std::collections::HashMap<String, String>,
     resolvers: impl IntoIterator<Item = crate::servers::DetectedResolver>,
     tracing: bool,
 ) -> Result<HashMap<String, PathBuf>, ServerError> {
@@ -405,19 +392,22 @@ pub async fn build_resolvers(
 
     let project = Project::get();
 
-    let mut resolvers_iterator = resolvers.into_iter().peekable();
-    if resolvers_iterator.peek().is_none() {
+    let resolvers: Vec<_> = resolvers.into_iter().collect();
+    if resolvers.is_empty() {
         return Ok(HashMap::new());
     }
 
-    // Install wrangler once and for all.
-    install_wrangler(environment, tracing).await?;
+    // wrangler is only needed to build resolvers, fresh resolvers (e.g. when only `.env` changed) are left as is
+    if resolvers.iter().any(|resolver| !resolver.fresh) {
+        // Install wrangler once and for all.
+        install_wrangler(environment, tracing).await?;
+    }
 
     let resolver_wrapper_worker_contents = extract_resolver_wrapper_worker_contents().await?;
 
     let resolvers_build_artifact_directory_path = project.resolvers_build_artifact_path.as_path();
 
-    futures_util::stream::iter(resolvers_iterator)
+    futures_util::stream::iter(resolvers)
         .map(Ok)
         .map_ok(|DetectedResolver { resolver_name, fresh }| {
             let resolver_wrapper_worker_contents = resolver_wrapper_worker_contents.as_str();
@@ -439,7 +429,6 @@ pub async fn build_resolvers(
                     build_resolver(
                         environment,
                         project,
-                        environment_variables,
                         resolver_name.as_str(),
                         resolver_wrapper_worker_contents,
                         &resolver_build_artifact_directory_path,
@@ -458,3 +447,52 @@ pub async fn build_resolvers(
         .try_collect()
         .await
 }
+
+/// the directories outside of the grafbase directory containing files bundled into the built resolvers
+/// (e.g. shared code of a monorepo), derived from the module path comments esbuild adds to the bundles
+pub async fn external_import_directories(resolver_paths: &HashMap<String, PathBuf>) -> BTreeSet<PathBuf> {
+    static MODULE_PATH_COMMENT: OnceLock<Regex> = OnceLock::new();
+
+    let module_path_comment = MODULE_PATH_COMMENT
+        .get_or_init(|| Regex::new(r"(?m)^// (\S+\.(?:[cm]?[jt]sx?|json))$").expect("must be valid"));
+
+    let environment = Environment::get();
+    let project = Project::get();
+
+    let Ok(grafbase_directory_path) = project.grafbase_directory_path.canonicalize() else {
+        return BTreeSet::new();
+    };
+
+    let mut directories = BTreeSet::new();
+
+    for resolver_build_artifact_directory_path in resolver_paths.values() {
+        let bundle_path = resolver_build_artifact_directory_path
+            .join("wrangler")
+            .join(RESOLVER_ENTRYPOINT_FILE);
+
+        let Ok(bundle) = tokio::fs::read_to_string(&bundle_path).await else {
+            continue;
+        };
+
+        // the module paths are relative to the working directory of esbuild
+        let bases = [
+            environment.wrangler_installation_path.as_path(),
+            resolver_build_artifact_directory_path.as_path(),
+        ];
+
+        directories.extend(
+            module_path_comment
+                .captures_iter(&bundle)
+                .filter_map(|captures| {
+                    let module_path = Path::new(captures.get(1).expect("must exist").as_str());
+                    bases.iter().find_map(|base| base.join(module_path).canonicalize().ok())
+                })
+                .filter(|path| !path.starts_with(&grafbase_directory_path))
+                .filter(|path| !path.starts_with(&project.dot_grafbase_directory_path))
+                .filter(|path| !path.iter().any(|part| part == "node_modules"))
+                .filter_map(|path| path.parent().map(Path::to_owned)),
+        );
+    }
+
+    directories
+}
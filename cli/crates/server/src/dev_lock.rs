@@ -0,0 +1,94 @@
+use crate::consts::{DEV_SERVER_INFO_FILE, DEV_SERVER_LOCK_FILE};
+use crate::errors::ServerError;
+use crate::servers::create_project_dot_grafbase_directory;
+use crate::types::DevServerInfo;
+use common::environment::Project;
+use fslock::LockFile;
+use std::fs;
+use std::path::PathBuf;
+
+/// keeps other development servers from running in the same project (and sharing its database) while held.
+///
+/// the lock itself is released by the operating system when the process exits,
+/// the information of the server recorded next to it is only trusted while the lock is held
+pub struct DevServerLock {
+    _lock_file: LockFile,
+    info_path: PathBuf,
+}
+
+impl DevServerLock {
+    /// locks the current project for a development server
+    ///
+    /// # Errors
+    ///
+    /// returns [`ServerError::ProjectLocked`] if another development server holds the lock
+    ///
+    /// returns [`ServerError::ProjectLock`] if the lock file cannot be opened
+    pub fn acquire() -> Result<Self, ServerError> {
+        create_project_dot_grafbase_directory()?;
+
+        let mut lock_file = open_lock_file()?;
+
+        if !lock_file.try_lock().map_err(ServerError::ProjectLock)? {
+            return Err(ServerError::ProjectLocked);
+        }
+
+        Ok(Self {
+            _lock_file: lock_file,
+            info_path: info_path(),
+        })
+    }
+
+    /// records the information of the development server for other invocations of the CLI
+    ///
+    /// # Errors
+    ///
+    /// returns [`ServerError::WriteDevServerInfo`] if the information cannot be written
+    pub fn record(&self, info: &DevServerInfo) -> Result<(), ServerError> {
+        fs::write(&self.info_path, serde_json::to_vec(info).expect("must serialise"))
+            .map_err(|error| ServerError::WriteDevServerInfo(self.info_path.clone(), error))
+    }
+}
+
+impl Drop for DevServerLock {
+    fn drop(&mut self) {
+        let _: Result<_, _> = fs::remove_file(&self.info_path);
+    }
+}
+
+/// the development server running in the current project, if any.
+/// returns `Ok(None)` for a server which is still starting and hasn't recorded its information yet
+///
+/// # Errors
+///
+/// returns [`ServerError::ProjectLock`] if the lock file cannot be checked
+pub fn running_server() -> Result<Option<DevServerInfo>, ServerError> {
+    if !Project::get()
+        .dot_grafbase_directory_path
+        .join(DEV_SERVER_LOCK_FILE)
+        .exists()
+    {
+        return Ok(None);
+    }
+
+    let mut lock_file = open_lock_file()?;
+
+    // the information left behind by a server which did not exit cleanly is stale
+    if lock_file.try_lock().map_err(ServerError::ProjectLock)? {
+        lock_file.unlock().map_err(ServerError::ProjectLock)?;
+        return Ok(None);
+    }
+
+    Ok(fs::read(info_path())
+        .ok()
+        .and_then(|info| serde_json::from_slice(&info).ok()))
+}
+
+fn open_lock_file() -> Result<LockFile, ServerError> {
+    LockFile::open(&Project::get().dot_grafbase_directory_path.join(DEV_SERVER_LOCK_FILE))
+        .map_err(ServerError::ProjectLock)
+}
+
+fn info_path() -> PathBuf {
+    Project::get().dot_grafbase_directory_path.join(DEV_SERVER_INFO_FILE)
+}
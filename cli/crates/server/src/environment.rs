@@ -1,20 +1,102 @@
-use common::environment::Project;
+use common::consts::DEFAULT_GRAFBASE_ENV;
+use common::environment::{Project, Warning};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-use crate::consts::DOT_ENV_FILE;
+use crate::consts::{DEVELOPMENT_DOT_ENV_SUFFIX, DOT_ENV_FILE, LOCAL_DOT_ENV_SUFFIX};
+use crate::types::EnvironmentOptions;
+
+impl EnvironmentOptions {
+    /// the `.env` files within the grafbase directory, from the lowest to the highest precedence
+    fn dot_env_file_names(&self) -> [String; 4] {
+        // follows the common `.env.development` naming for the default mode
+        let mode = if self.mode == DEFAULT_GRAFBASE_ENV {
+            DEVELOPMENT_DOT_ENV_SUFFIX
+        } else {
+            self.mode.as_str()
+        };
+
+        [
+            DOT_ENV_FILE.to_owned(),
+            format!("{DOT_ENV_FILE}.{LOCAL_DOT_ENV_SUFFIX}"),
+            format!("{DOT_ENV_FILE}.{mode}"),
+            format!("{DOT_ENV_FILE}.{mode}.{LOCAL_DOT_ENV_SUFFIX}"),
+        ]
+    }
+
+    /// the files environment variables are loaded from, from the lowest to the highest precedence:
+    /// the selected `.env` files within the grafbase directory followed by the additional files
+    pub(crate) fn env_file_paths(&self) -> Vec<PathBuf> {
+        let project = Project::get();
+
+        self.dot_env_file_names()
+            .into_iter()
+            .map(|file_name| project.grafbase_directory_path.join(file_name))
+            .chain(self.env_files.iter().cloned())
+            .collect()
+    }
+
+    /// whether a changed path (relative to the project root if within the project)
+    /// is one of the files environment variables are loaded from
+    pub(crate) fn is_env_file(&self, path: &Path) -> bool {
+        let project = Project::get();
+
+        self.env_file_paths()
+            .iter()
+            .any(|env_file| env_file.strip_prefix(&project.path).unwrap_or(env_file) == path)
+    }
+}
+
+/// returns the environment variables of the current process, overridden by the ones in the selected `.env` files
+/// and `GRAFBASE_ENV`.
+///
+/// the `.env` files are loaded in the following order, later files taking precedence:
+/// `.env`, `.env.local`, `.env.<mode>`, `.env.<mode>.local` (`.env.development` for the default mode),
+/// followed by any additional files in [`EnvironmentOptions::env_files`]
+pub fn variables(options: &EnvironmentOptions) -> impl Iterator<Item = (String, String)> + '_ {
+    std::env::vars()
+        .chain(file_variables(options))
+        .chain(std::iter::once(("GRAFBASE_ENV".to_string(), options.mode.clone())))
+}
+
+/// masks the values of the variables loaded from the selected `.env` files in traces and resolver output
+pub fn redact_file_variables(options: &EnvironmentOptions) {
+    common::redaction::set_secrets(file_variables(options).map(|(_, value)| value));
+}
 
 #[allow(deprecated)] // https://github.com/dotenv-rs/dotenv/pull/54
-pub fn variables() -> impl Iterator<Item = (String, String)> {
-    let project = Project::get();
-    let dot_env_file_path = project.grafbase_directory_path.join(DOT_ENV_FILE);
+fn file_variables(options: &EnvironmentOptions) -> impl Iterator<Item = (String, String)> + '_ {
     // We don't use dotenv::dotenv() as we don't want to pollute the process' environment.
     // Doing otherwise would make us unable to properly refresh it whenever any of the .env files
-    // changes which is something we may want to do in the future.
-    std::env::vars()
-        .chain(
-            dotenv::from_path_iter(dot_env_file_path)
-                .into_iter()
-                .flatten()
-                .filter_map(Result::ok),
-        )
-        .chain(std::iter::once(("GRAFBASE_ENV".to_string(), "dev".to_string())))
+    // changes.
+    options.env_file_paths().into_iter().flat_map(|path| {
+        dotenv::from_path_iter(path)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+    })
+}
+
+/// returns a warning for each variable referenced in the schema via `{{ env.VARIABLE }}` that isn't set
+pub fn missing_variable_warnings(schema: &str, variables: &HashMap<String, String>) -> Vec<Warning> {
+    static VARIABLE_REFERENCE: OnceLock<Regex> = OnceLock::new();
+
+    let variable_reference = VARIABLE_REFERENCE
+        .get_or_init(|| Regex::new(r"\{\{\s*env\.([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").expect("must be valid"));
+
+    variable_reference
+        .captures_iter(schema)
+        .map(|captures| captures[1].to_owned())
+        .filter(|name| !variables.contains_key(name))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|name| {
+            Warning::new(format!(
+                "The environment variable '{name}' is referenced in the schema but is not set"
+            ))
+            .with_hint(format!("Try adding '{name}' to 'grafbase/.env' or to an '--env-file'"))
+        })
+        .collect()
 }
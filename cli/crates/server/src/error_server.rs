@@ -1,3 +1,5 @@
+mod diagnostic;
 mod server;
 
+pub use diagnostic::{Diagnostic, ErrorCode};
 pub use server::start;
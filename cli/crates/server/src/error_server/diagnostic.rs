@@ -0,0 +1,211 @@
+use crate::errors::ServerError;
+use common::environment::{Project, SchemaLocation};
+use common::redaction::redact;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// the amount of lines shown before and after the line of an error in a code frame
+const CODE_FRAME_CONTEXT_LINES: usize = 2;
+
+/// the kind of compilation error, returned as `extensions.code` of GraphQL errors
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum ErrorCode {
+    /// the schema could not be parsed
+    SchemaParseError,
+    /// a resolver could not be built
+    ResolverBuildError,
+    /// the project could not be compiled for another reason, e.g. a missing dependency
+    CompilationError,
+}
+
+/// a position in a source file, both starting at 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// a compilation error along with the location it originates from, if known
+#[derive(Debug)]
+pub struct Diagnostic {
+    code: ErrorCode,
+    message: String,
+    /// the file the locations point to, relative to the project directory
+    file: Option<PathBuf>,
+    locations: Vec<Location>,
+    /// the contents of `file`, for code frames
+    source: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(code: ErrorCode, message: &str) -> Self {
+        Self {
+            code,
+            message: redact(message).into_owned(),
+            file: None,
+            locations: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// extracts the location of schema parser errors, other errors are reported as is
+    pub async fn from_schema_parser_error(error: &ServerError) -> Self {
+        let ServerError::ParseSchema(output) = error else {
+            return Self::new(ErrorCode::CompilationError, &error.to_string());
+        };
+
+        let diagnostic = Self::new(ErrorCode::SchemaParseError, &error.to_string());
+
+        // the locations of a TypeScript config point to the generated schema rather than to the config itself
+        let SchemaLocation::Graphql(schema_path) = Project::get().schema_path.location() else {
+            return diagnostic;
+        };
+
+        let locations = parse_locations(output);
+
+        if locations.is_empty() {
+            return diagnostic;
+        }
+
+        Self {
+            file: Some(relative_to_project(schema_path)),
+            locations,
+            source: tokio::fs::read_to_string(schema_path)
+                .await
+                .ok()
+                .map(|source| redact(&source).into_owned()),
+            ..diagnostic
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// the error as a GraphQL error object, with the location of the error in the schema if known
+    pub fn graphql_error(&self) -> Value {
+        let mut error = json!({
+            "message": self.message,
+            "extensions": {
+                "code": self.code,
+            },
+        });
+
+        if !self.locations.is_empty() {
+            error["locations"] = json!(self.locations);
+        }
+
+        if let Some(file) = &self.file {
+            error["extensions"]["file"] = json!(file);
+        }
+
+        error
+    }
+
+    /// the lines of the source surrounding the first location, the line of the error marked with a caret at its column
+    pub fn code_frame(&self) -> Option<String> {
+        let source = self.source.as_ref()?;
+        let Location { line, column } = *self.locations.first()?;
+
+        let lines: Vec<_> = source.lines().collect();
+
+        if line == 0 || line > lines.len() {
+            return None;
+        }
+
+        let first_line = line.saturating_sub(CODE_FRAME_CONTEXT_LINES).max(1);
+        let last_line = (line + CODE_FRAME_CONTEXT_LINES).min(lines.len());
+        let number_width = last_line.to_string().len();
+
+        let mut code_frame = String::new();
+
+        for (number, contents) in (first_line..=last_line).zip(&lines[first_line - 1..last_line]) {
+            let marker = if number == line { '>' } else { ' ' };
+            code_frame.push_str(&format!("{marker} {number:>number_width$} | {contents}\n"));
+
+            if number == line {
+                let padding = " ".repeat(column.saturating_sub(1));
+                code_frame.push_str(&format!("  {:number_width$} | {padding}^\n", ""));
+            }
+        }
+
+        Some(code_frame)
+    }
+}
+
+/// the locations mentioned in the output of the schema parser, e.g. `[3:5]`, ` --> 3:5` or `line 3, column 5`
+fn parse_locations(output: &str) -> Vec<Location> {
+    static LOCATION: OnceLock<Regex> = OnceLock::new();
+
+    let location = LOCATION.get_or_init(|| {
+        Regex::new(r"(?i)(?:-->\s*|\[|line\s+|\.graphql:)(\d+)(?::|,\s*column\s+)(\d+)").expect("must be valid")
+    });
+
+    let mut locations = Vec::new();
+
+    for captures in location.captures_iter(output) {
+        let (Ok(line), Ok(column)) = (captures[1].parse(), captures[2].parse()) else {
+            continue;
+        };
+
+        let parsed = Location { line, column };
+
+        if !locations.contains(&parsed) {
+            locations.push(parsed);
+        }
+    }
+
+    locations
+}
+
+fn relative_to_project(path: &Path) -> PathBuf {
+    path.strip_prefix(&Project::get().path)
+        .map_or_else(|_| path.to_owned(), Path::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_locations_of_parser_output() {
+        assert_eq!(
+            parse_locations(" --> 1:10\n  |\n1 | type Xyz e\n  |          ^---"),
+            vec![Location { line: 1, column: 10 }]
+        );
+        assert_eq!(
+            parse_locations("[3:5] unknown directive\n[3:5] unknown directive\n[7:1] duplicate type"),
+            vec![Location { line: 3, column: 5 }, Location { line: 7, column: 1 }]
+        );
+        assert_eq!(
+            parse_locations("unexpected token at line 2, column 4"),
+            vec![Location { line: 2, column: 4 }]
+        );
+        assert!(parse_locations("could not parse the schema").is_empty());
+    }
+
+    #[test]
+    fn code_frame_marks_the_first_location() {
+        let diagnostic = Diagnostic {
+            code: ErrorCode::SchemaParseError,
+            message: String::new(),
+            file: Some(PathBuf::from("grafbase/schema.graphql")),
+            locations: vec![Location { line: 2, column: 10 }],
+            source: Some("type Todo @model {\n  title: Strin\n}\n".to_owned()),
+        };
+
+        assert_eq!(
+            diagnostic.code_frame().unwrap(),
+            "  1 | type Todo @model {\n> 2 |   title: Strin\n    |          ^\n  3 | }\n"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::diagnostic::Diagnostic;
 use crate::{
     errors::ServerError,
     event::{wait_for_event, Event},
@@ -8,32 +9,65 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
+use common::consts::ADMIN_API_PATH;
 use serde_json::{json, Value};
 use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
 use tower_http::trace::TraceLayer;
 
 #[allow(clippy::unused_async)]
-async fn playground(State(error): State<String>) -> Html<String> {
-    let document = include_str!("error-page.html").replace("{{error}}", &error);
+async fn playground(State(diagnostic): State<Arc<Diagnostic>>) -> Html<String> {
+    let code_frame = match (diagnostic.file(), diagnostic.code_frame()) {
+        (Some(file), Some(code_frame)) => format!(
+            r#"<div class="code-frame-file">{}</div><pre class="code-frame">{}</pre>"#,
+            escape_html(&file.display().to_string()),
+            escape_html(&code_frame)
+        ),
+        _ => String::new(),
+    };
+
+    // the inserted contents are escaped, so they cannot contain placeholders
+    let document = include_str!("error-page.html")
+        .replace("{{status_url}}", &format!("{ADMIN_API_PATH}/status"))
+        .replace("{{code_frame}}", &code_frame)
+        .replace("{{error}}", &escape_html(diagnostic.message()));
 
     Html(document)
 }
 
 #[allow(clippy::unused_async)]
-async fn endpoint(State(error): State<String>) -> Json<Value> {
+async fn endpoint(State(diagnostic): State<Arc<Diagnostic>>) -> Json<Value> {
     let document = json!(
         {
             "data": null,
-            "errors": [error]
+            "errors": [diagnostic.graphql_error()]
         }
     );
 
     Json(document)
 }
 
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '{' => escaped.push_str("&#123;"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
 pub async fn start(
     port: u16,
-    error: String,
+    diagnostic: Diagnostic,
     event_bus: tokio::sync::broadcast::Sender<Event>,
 ) -> Result<(), ServerError> {
     trace!("starting error server at port {port}");
@@ -42,16 +76,16 @@ pub async fn start(
         .route("/", get(playground))
         .route("/graphql", post(endpoint))
         .route("/graphql", get(endpoint))
-        .with_state(error)
+        .with_state(Arc::new(diagnostic))
         .layer(TraceLayer::new_for_http());
 
     let socket_address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
 
     let server = axum::Server::bind(&socket_address)
         .serve(router.into_make_service())
-        .with_graceful_shutdown(wait_for_event(event_bus.subscribe(), |event| {
-            matches!(event, Event::Reload(_))
-        }));
+        .with_graceful_shutdown(wait_for_event(event_bus.subscribe(), |event| *event == Event::Retire));
+
+    let _: Result<_, _> = event_bus.send(Event::WorkerReady);
 
     server.await?;
 
@@ -87,6 +87,22 @@ pub enum ServerError {
     #[error("could not read the file {0}: {1}")]
     ReadFile(PathBuf, IoError),
 
+    /// returned if a resolver fixture or snapshot is invalid JSON
+    #[error("the resolver fixture {0} is malformed JSON:\n{1}")]
+    ResolverFixtureJson(PathBuf, serde_json::Error),
+
+    /// returned if a resolver snapshot could not be written
+    #[error("could not write the resolver snapshot {0}: {1}")]
+    WriteResolverSnapshot(PathBuf, IoError),
+
+    /// returned if the log entries of a resolver could not be written
+    #[error("could not write the resolver logs {0}: {1}")]
+    WriteResolverLogs(PathBuf, IoError),
+
+    /// returned if resolver tests could not run due to the project failing to compile
+    #[error("{0}")]
+    Compilation(String),
+
     /// returned if the schema parser command exits unsuccessfully
     #[error("could not extract the resolver wrapper worker contents")]
     ExtractResolverWrapperWorkerContents(String),
@@ -134,8 +150,8 @@ pub enum ServerError {
     #[error("could not read the project database directory\ncaused by: {0}")]
     ReadDatabaseDir(IoError),
 
-    /// returned if an available port cannot be found for the bridge server
-    #[error("could not find an available port for the bridge server")]
+    /// returned if an available port cannot be found for the bridge server or the worker
+    #[error("could not find an available port for the bridge server or the worker")]
     AvailablePort,
 
     /// returned if a spawned task panics
@@ -158,6 +174,42 @@ pub enum ServerError {
     #[error("A file watcher encountered an error\ncaused by: {0}")]
     FileWatcher(#[from] NotifyError),
 
+    /// returned if the package.json file in the grafbase directory is invalid
+    #[error("the file {0} is malformed JSON:\n{1}")]
+    PackageJson(PathBuf, serde_json::Error),
+
+    /// returned if one of the include or exclude globs of the file watcher is invalid
+    #[error("invalid watch glob\ncaused by: {0}")]
+    WatchGlob(globset::Error),
+
+    /// returned if the certificate of the local certificate authority or the server certificate could not be generated
+    #[error("could not generate a certificate\ncaused by: {0}")]
+    Certificate(#[from] rcgen::RcgenError),
+
+    /// returned if the local certificate authority cannot be written to `~/.grafbase`
+    #[error("could not write the local certificate authority to {0}\ncaused by: {1}")]
+    WriteCertificateAuthority(PathBuf, IoError),
+
+    /// returned if the certificate or private key used for HTTPS are invalid
+    #[error("could not use the certificate for HTTPS\ncaused by: {0}")]
+    TlsConfig(IoError),
+
+    /// returned if the HTTPS server cannot be started or stops due to an error
+    #[error("the HTTPS server encountered an error\ncaused by: {0}")]
+    HttpsServer(IoError),
+
+    /// returned if the project lock of the development server cannot be created or checked
+    #[error("could not lock the project for the development server\ncaused by: {0}")]
+    ProjectLock(fslock::Error),
+
+    /// returned if another development server holds the project lock
+    #[error("a development server is already running in this project")]
+    ProjectLocked,
+
+    /// returned if the information of the development server cannot be written next to the project lock
+    #[error("could not write the development server information to {0}\ncaused by: {1}")]
+    WriteDevServerInfo(PathBuf, IoError),
+
     #[error("Could not create a lock for the wrangler installation: {0}")]
     Lock(#[from] fslock::Error),
 }
@@ -1,14 +1,26 @@
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 use tokio::sync::broadcast::Receiver;
 
 /// server lifecycle related events
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Event {
-    /// emitted when a schema change is detected
+    /// emitted when changes are detected
     /// and the server should be reloaded
-    Reload(PathBuf),
+    Reload(Vec<PathBuf>),
     /// emitted when the bridge is ready to receive requests
     BridgeReady,
+    /// emitted once the resolvers are built with the directories outside of the grafbase directory
+    /// containing files bundled into them, which are watched in addition to the grafbase directory
+    ImportedDirectories(BTreeSet<PathBuf>),
+    /// emitted when the worker (or the error server standing in for it) is ready to receive requests
+    WorkerReady,
+    /// emitted when a newer generation of the servers has taken over
+    /// and the servers of the current generation should stop
+    Retire,
+    /// emitted once the proxy stopped accepting connections during a graceful shutdown,
+    /// the servers should stop after finishing their requests in flight
+    Shutdown,
 }
 
 /// returns a future that resolves when given event is sent
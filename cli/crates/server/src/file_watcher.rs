@@ -1,69 +1,307 @@
-use crate::consts::DOT_ENV_FILE;
+use crate::consts::{GIT_IGNORE_FILE, WATCH_POLL_INTERVAL};
 use crate::errors::ServerError;
-use common::consts::GRAFBASE_SCHEMA_FILE_NAME;
-use notify::RecursiveMode;
-use notify_debouncer_mini::new_debouncer;
+use crate::event::Event;
+use crate::types::WatchOptions;
+use common::consts::{GRAFBASE_SCHEMA_FILE_NAME, RESOLVER_FIXTURES_DIRECTORY_NAME};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::gitignore::Gitignore;
+use notify::{Config, PollWatcher, RecursiveMode, Watcher};
+use notify_debouncer_mini::{new_debouncer, new_debouncer_opt, DebounceEventResult, Debouncer};
+use serde::Deserialize;
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
+use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
 use tokio::runtime::Handle;
+use tokio::sync::broadcast;
+use tokio::sync::mpsc::Receiver;
 
-const FILE_WATCHER_INTERVAL: Duration = Duration::from_secs(1);
-
-/// watches a path for file system events, running a callback on each event
-pub async fn start_watcher<P, T>(path: P, on_change: T) -> Result<(), ServerError>
+/// watches a path for file system events, running a callback with the changed paths of each batch of events.
+///
+/// also watches the directories declared in the package.json file of the path,
+/// the directories sent with [`Event::ImportedDirectories`] and the files environment variables are loaded from
+pub async fn start_watcher<P, T>(
+    path: P,
+    options: &WatchOptions,
+    env_files: &[PathBuf],
+    server_events: broadcast::Receiver<Event>,
+    on_change: T,
+) -> Result<(), ServerError>
 where
     P: AsRef<Path> + Send + 'static,
-    T: Fn(&PathBuf) + Send + 'static,
+    T: Fn(Vec<PathBuf>) + Send + 'static,
 {
-    let (notify_sender, mut notify_receiver) = tokio::sync::mpsc::channel(1);
+    let filter = PathFilter::new(path.as_ref(), declared_watch_roots(path.as_ref())?, env_files, options)?;
+
+    let (notify_sender, notify_receiver) = tokio::sync::mpsc::channel(1);
 
     let handle = Handle::current();
 
-    let mut debouncer = new_debouncer(FILE_WATCHER_INTERVAL, None, move |res| {
+    let event_handler = move |res: DebounceEventResult| {
         handle.block_on(async { notify_sender.send(res).await.expect("must be open") });
-    })?;
+    };
+
+    // file system notifications don't fire on some network and container file systems
+    if options.poll {
+        let debouncer = new_debouncer_opt::<_, PollWatcher>(
+            options.debounce,
+            None,
+            event_handler,
+            Config::default().with_poll_interval(WATCH_POLL_INTERVAL),
+        )?;
+
+        watch(debouncer, notify_receiver, server_events, filter, on_change).await
+    } else {
+        let debouncer = new_debouncer(options.debounce, None, event_handler)?;
+
+        watch(debouncer, notify_receiver, server_events, filter, on_change).await
+    }
+}
 
-    debouncer.watcher().watch(path.as_ref(), RecursiveMode::Recursive)?;
+async fn watch<W, T>(
+    mut debouncer: Debouncer<W>,
+    mut notify_receiver: Receiver<DebounceEventResult>,
+    mut server_events: broadcast::Receiver<Event>,
+    mut filter: PathFilter,
+    on_change: T,
+) -> Result<(), ServerError>
+where
+    W: Watcher,
+    T: Fn(Vec<PathBuf>),
+{
+    for root in filter.roots() {
+        debouncer.watcher().watch(root, RecursiveMode::Recursive)?;
+    }
+
+    // the directories of the `.env` files are watched rather than the files, which are often replaced when saved
+    for directory in filter.env_file_directories() {
+        trace!("watching the env file directory {}", directory.display());
+        if let Err(error) = debouncer.watcher().watch(directory, RecursiveMode::NonRecursive) {
+            trace!("could not watch {}: {error}", directory.display());
+        }
+    }
 
     loop {
-        match notify_receiver.recv().await {
-            Some(Ok(events)) => {
-                // for the purposes of display, we need the last non ignored event
-                if let Some(event) = events
-                    .iter()
-                    .rev()
-                    .find(|event| non_ignored_path(&event.path, path.as_ref()))
-                {
-                    on_change(&event.path);
+        tokio::select! {
+            result = notify_receiver.recv() => match result {
+                Some(Ok(events)) => {
+                    let paths: Vec<_> = events
+                        .into_iter()
+                        .map(|event| event.path)
+                        .filter(|path| filter.non_ignored_path(path))
+                        .collect();
+
+                    if !paths.is_empty() {
+                        on_change(paths);
+                    }
                 }
-            }
 
-            Some(Err(errors)) => {
-                if let Some(error) = errors
-                    .into_iter()
-                    .find(|error| error.paths.contains(&path.as_ref().to_owned()))
-                {
-                    // an error with the root path, non recoverable
-                    return Err(ServerError::FileWatcher(error));
+                Some(Err(errors)) => {
+                    if let Some(error) = errors
+                        .into_iter()
+                        .find(|error| filter.roots().any(|root| error.paths.iter().any(|path| path == root)))
+                    {
+                        // an error with a root path, non recoverable
+                        return Err(ServerError::FileWatcher(error));
+                    }
+                    // errors for specific files, ignored
+                }
+                // unreachable, should always be stopped externally by `select!`
+                None => {}
+            },
+            event = server_events.recv() => {
+                if let Ok(Event::ImportedDirectories(directories)) = event {
+                    let directories: BTreeSet<_> = directories
+                        .into_iter()
+                        .filter(|directory| !filter.within_roots(directory))
+                        .collect();
+
+                    let env_file_directories: BTreeSet<_> = filter.env_file_directories().collect();
+
+                    for directory in filter.imported_directories.difference(&directories) {
+                        if !env_file_directories.contains(directory.as_path()) {
+                            let _: Result<_, _> = debouncer.watcher().unwatch(directory);
+                        }
+                    }
+
+                    for directory in directories.difference(&filter.imported_directories) {
+                        trace!("watching the imported directory {}", directory.display());
+                        // imported directories are not required for the server to run, so errors are only traced
+                        if let Err(error) = debouncer.watcher().watch(directory, RecursiveMode::NonRecursive) {
+                            trace!("could not watch {}: {error}", directory.display());
+                        }
+                    }
+
+                    filter.imported_directories = directories;
                 }
-                // errors for specific files, ignored
             }
-            // unreachable, should always be stopped externally by `select!`
-            None => {}
         }
     }
 }
 
-const ROOT_FILE_WHITELIST: [&str; 2] = [GRAFBASE_SCHEMA_FILE_NAME, DOT_ENV_FILE];
+#[derive(Default, Deserialize)]
+struct PackageJson {
+    #[serde(default)]
+    grafbase: PackageJsonGrafbaseSection,
+}
+
+#[derive(Default, Deserialize)]
+struct PackageJsonGrafbaseSection {
+    #[serde(default)]
+    watch: Vec<PathBuf>,
+}
+
+/// the directories to watch in addition to the grafbase directory, declared in its package.json file as
+/// `{ "grafbase": { "watch": ["../packages/shared"] } }` relative to the grafbase directory
+fn declared_watch_roots(root: &Path) -> Result<Vec<PathBuf>, ServerError> {
+    let package_json_path = root.join("package.json");
+
+    let package_json_contents = match std::fs::read(&package_json_path) {
+        Ok(package_json_contents) => package_json_contents,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(ServerError::ReadFile(package_json_path, error)),
+    };
+
+    let package_json: PackageJson = serde_json::from_slice(&package_json_contents)
+        .map_err(|error| ServerError::PackageJson(package_json_path.clone(), error))?;
+
+    package_json
+        .grafbase
+        .watch
+        .into_iter()
+        .map(|watch_root| {
+            let path = root.join(watch_root);
+            path.canonicalize().map_err(|error| ServerError::ReadFile(path, error))
+        })
+        .collect()
+}
+
+const ROOT_FILE_WHITELIST: [&str; 1] = [GRAFBASE_SCHEMA_FILE_NAME];
 const EXTENSION_WHITELIST: [&str; 11] = [
     "js", "ts", "jsx", "tsx", "mjs", "mts", ".wasm", "cjs", "json", "yaml", "yml",
 ];
-const DIRECTORY_BLACKLIST: [&str; 1] = ["node_modules"];
+// resolver fixtures and snapshots are only used by `grafbase resolvers test`
+const DIRECTORY_BLACKLIST: [&str; 2] = ["node_modules", RESOLVER_FIXTURES_DIRECTORY_NAME];
 
-fn non_ignored_path(path: &Path, root: &Path) -> bool {
-    likely_not_a_dir(path)
-        && (whitelisted_root_file(path, root) || (!in_blacklisted_directory(path, root) && whitelisted_extension(path)))
+/// decides which changed paths trigger a reload, in order of precedence:
+/// - the files environment variables are loaded from are watched
+/// - paths matching an exclude glob are ignored
+/// - paths matching an include glob and whitelisted root files are watched
+/// - paths with a whitelisted extension are watched unless they're in a blacklisted directory or ignored by git
+///
+/// globs are matched against paths relative to the grafbase directory,
+/// or to the declared root or imported directory containing them
+struct PathFilter {
+    root: PathBuf,
+    declared_roots: Vec<PathBuf>,
+    imported_directories: BTreeSet<PathBuf>,
+    env_files: BTreeSet<PathBuf>,
+    include: GlobSet,
+    exclude: GlobSet,
+    gitignores: Vec<Gitignore>,
+}
+
+impl PathFilter {
+    fn new(
+        root: &Path,
+        declared_roots: Vec<PathBuf>,
+        env_files: &[PathBuf],
+        options: &WatchOptions,
+    ) -> Result<Self, ServerError> {
+        // the `.gitignore` files of the project directory and of the grafbase directory
+        let gitignores = root
+            .ancestors()
+            .take(2)
+            .map(|directory| directory.join(GIT_IGNORE_FILE))
+            .filter(|path| path.is_file())
+            .map(|path| {
+                let (gitignore, error) = Gitignore::new(&path);
+                if let Some(error) = error {
+                    trace!("could not fully parse {}: {error}", path.display());
+                }
+                gitignore
+            })
+            .collect();
+
+        Ok(Self {
+            root: root.to_owned(),
+            declared_roots,
+            imported_directories: BTreeSet::new(),
+            env_files: env_files.iter().cloned().collect(),
+            include: glob_set(&options.include)?,
+            exclude: glob_set(&options.exclude)?,
+            gitignores,
+        })
+    }
+
+    /// the directories watched recursively
+    fn roots(&self) -> impl Iterator<Item = &PathBuf> {
+        std::iter::once(&self.root).chain(&self.declared_roots)
+    }
+
+    fn within_roots(&self, path: &Path) -> bool {
+        self.roots().any(|root| path.starts_with(root))
+    }
+
+    /// the directories containing `.env` files outside of the roots, watched non recursively
+    fn env_file_directories(&self) -> impl Iterator<Item = &Path> {
+        self.env_files
+            .iter()
+            .filter_map(|env_file| env_file.parent())
+            .filter(|directory| !self.within_roots(directory))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+
+    fn non_ignored_path(&self, path: &Path) -> bool {
+        // checked first as removed files can't be told apart from directories
+        if self.env_files.contains(path) {
+            return true;
+        }
+
+        if !likely_not_a_dir(path) {
+            return false;
+        }
+
+        let Some(relative_path) = self.relative_path(path) else {
+            return false;
+        };
+
+        if self.exclude.is_match(relative_path) {
+            return false;
+        }
+
+        if self.include.is_match(relative_path) || whitelisted_root_file(path, &self.root) {
+            return true;
+        }
+
+        !in_blacklisted_directory(relative_path) && whitelisted_extension(path) && !self.gitignored(path)
+    }
+
+    fn relative_path<'a>(&self, path: &'a Path) -> Option<&'a Path> {
+        self.roots().find_map(|root| path.strip_prefix(root).ok()).or_else(|| {
+            // imported directories are watched non recursively
+            path.parent()
+                .filter(|parent| self.imported_directories.contains(*parent))
+                .and_then(|parent| path.strip_prefix(parent).ok())
+        })
+    }
+
+    fn gitignored(&self, path: &Path) -> bool {
+        self.gitignores.iter().any(|gitignore| {
+            path.starts_with(gitignore.path()) && gitignore.matched_path_or_any_parents(path, false).is_ignore()
+        })
+    }
+}
+
+fn glob_set(globs: &[String]) -> Result<GlobSet, ServerError> {
+    let mut builder = GlobSetBuilder::new();
+
+    for glob in globs {
+        builder.add(Glob::new(glob).map_err(ServerError::WatchGlob)?);
+    }
+
+    builder.build().map_err(ServerError::WatchGlob)
 }
 
 fn likely_not_a_dir(path: &Path) -> bool {
@@ -84,17 +322,14 @@ fn whitelisted_root_file(path: &Path, root: &Path) -> bool {
             .is_some()
 }
 
-fn in_blacklisted_directory(path: &Path, root: &Path) -> bool {
-    // we only blacklist directories under the grafbase directory
-    path.strip_prefix(root)
-        .expect("must contain root directory")
-        .iter()
-        .any(|path_part| {
-            path_part
-                .to_str()
-                .filter(|path_part| DIRECTORY_BLACKLIST.contains(path_part))
-                .is_some()
-        })
+fn in_blacklisted_directory(relative_path: &Path) -> bool {
+    // we only blacklist directories under the watched directories
+    relative_path.iter().any(|path_part| {
+        path_part
+            .to_str()
+            .filter(|path_part| DIRECTORY_BLACKLIST.contains(path_part))
+            .is_some()
+    })
 }
 
 fn whitelisted_extension(path: &Path) -> bool {
@@ -103,3 +338,37 @@ fn whitelisted_extension(path: &Path) -> bool {
         .filter(|extension| EXTENSION_WHITELIST.contains(extension))
         .is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PathFilter;
+    use crate::types::WatchOptions;
+
+    #[test]
+    fn only_selected_env_files_are_watched() {
+        let root = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+
+        for file_name in [".env", ".env.local", ".env.example", ".env.production"] {
+            std::fs::write(root.path().join(file_name), "VARIABLE=value").unwrap();
+        }
+        let env_file = outside.path().join("shared.env");
+        std::fs::write(&env_file, "VARIABLE=value").unwrap();
+
+        let env_files = [
+            root.path().join(".env"),
+            root.path().join(".env.local"),
+            env_file.clone(),
+        ];
+        let filter = PathFilter::new(root.path(), Vec::new(), &env_files, &WatchOptions::default()).unwrap();
+
+        assert!(filter.non_ignored_path(&root.path().join(".env")));
+        assert!(filter.non_ignored_path(&root.path().join(".env.local")));
+        assert!(filter.non_ignored_path(&env_file));
+        assert!(!filter.non_ignored_path(&root.path().join(".env.example")));
+        assert!(!filter.non_ignored_path(&root.path().join(".env.production")));
+        assert!(!filter.non_ignored_path(&outside.path().join("other.env")));
+
+        assert_eq!(filter.env_file_directories().collect::<Vec<_>>(), [outside.path()]);
+    }
+}
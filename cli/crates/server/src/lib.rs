@@ -21,6 +21,7 @@ let server_handle = server::start(PORT).unwrap();
 #[macro_use]
 extern crate log;
 
+mod admin;
 mod bridge;
 mod consts;
 mod custom_resolvers;
@@ -28,9 +29,16 @@ mod environment;
 mod error_server;
 mod event;
 mod file_watcher;
+mod proxy;
+mod redacted_output;
 mod servers;
+mod tls;
 
+pub mod dev_lock;
 pub mod errors;
+pub mod resolver_logs;
+pub mod resolver_tests;
 pub mod types;
 
-pub use servers::start;
+pub use redacted_output::reserve_stdout;
+pub use servers::{start, test_resolvers};
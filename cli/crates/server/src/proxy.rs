@@ -0,0 +1,117 @@
+use crate::admin::{self, AdminState};
+use crate::consts::SHUTDOWN_TIMEOUT;
+use crate::errors::ServerError;
+use crate::tls::TlsCertificate;
+use crate::types::ShutdownHandle;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::uri::PathAndQuery;
+use axum::http::{Request, Response, StatusCode, Uri};
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use common::consts::ADMIN_API_PATH;
+use hyper::client::HttpConnector;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tower_http::trace::TraceLayer;
+
+struct ProxyState {
+    client: hyper::Client<HttpConnector>,
+    worker_port: watch::Receiver<Option<u16>>,
+}
+
+async fn forward(
+    State(proxy_state): State<Arc<ProxyState>>,
+    mut request: Request<Body>,
+) -> Result<Response<Body>, StatusCode> {
+    let mut worker_port = proxy_state.worker_port.clone();
+
+    // requests received before the first generation of the servers is ready wait for it
+    let worker_port = loop {
+        let current_worker_port = *worker_port.borrow();
+
+        if let Some(current_worker_port) = current_worker_port {
+            break current_worker_port;
+        }
+
+        worker_port
+            .changed()
+            .await
+            .map_err(|_| StatusCode::SERVICE_UNAVAILABLE)?;
+    };
+
+    let path_and_query = request.uri().path_and_query().map_or("/", PathAndQuery::as_str);
+
+    let uri: Uri = format!("http://127.0.0.1:{worker_port}{path_and_query}")
+        .parse()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    *request.uri_mut() = uri;
+
+    proxy_state.client.request(request).await.map_err(|error| {
+        error!("could not forward a request to the worker: {error}");
+        StatusCode::BAD_GATEWAY
+    })
+}
+
+/// forwards requests received on `address` to the worker of the current generation of the servers,
+/// which is swapped by sending its port through `worker_port`, and serves the admin API under [`ADMIN_API_PATH`].
+/// terminates TLS when given a certificate, the worker itself is always reached over plain HTTP.
+/// stops accepting connections once a shutdown is requested and resolves when the requests in flight are done
+/// (or after [`SHUTDOWN_TIMEOUT`])
+pub async fn start(
+    address: SocketAddr,
+    tls: Option<TlsCertificate>,
+    admin_state: Arc<AdminState>,
+    worker_port: watch::Receiver<Option<u16>>,
+    shutdown: ShutdownHandle,
+) -> Result<(), ServerError> {
+    trace!("starting proxy at {address}");
+
+    let proxy_state = Arc::new(ProxyState {
+        client: hyper::Client::new(),
+        worker_port,
+    });
+
+    let router = Router::new()
+        .nest(ADMIN_API_PATH, admin::router(admin_state))
+        .fallback(forward)
+        .with_state(proxy_state)
+        .layer(TraceLayer::new_for_http());
+
+    if let Some(TlsCertificate { certificate, key }) = tls {
+        let config = RustlsConfig::from_pem(certificate, key)
+            .await
+            .map_err(ServerError::TlsConfig)?;
+
+        let handle = axum_server::Handle::new();
+
+        let shutdown_handle = handle.clone();
+        tokio::spawn(async move {
+            shutdown.requested().await;
+            shutdown_handle.graceful_shutdown(Some(SHUTDOWN_TIMEOUT));
+        });
+
+        axum_server::bind_rustls(address, config)
+            .handle(handle)
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+            .map_err(ServerError::HttpsServer)?;
+    } else {
+        let server_shutdown = shutdown.clone();
+        let server = axum::Server::bind(&address)
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
+            .with_graceful_shutdown(async move { server_shutdown.requested().await });
+
+        tokio::select! {
+            result = server => result?,
+            () = async {
+                shutdown.requested().await;
+                tokio::time::sleep(SHUTDOWN_TIMEOUT).await;
+            } => trace!("stopped waiting for requests in flight"),
+        }
+    }
+
+    Ok(())
+}
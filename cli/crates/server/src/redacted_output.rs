@@ -0,0 +1,37 @@
+use common::redaction::redact;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Child;
+
+/// set when the standard output of the current process is reserved for events (e.g. in json mode)
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// forwards the standard output of child processes to the standard error of the current process from now on
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// forwards the output of a child process to the output of the current process line by line, redacting secrets.
+/// used in place of inheriting the output when tracing
+pub fn forward(child: &mut Child) {
+    if let Some(stdout) = child.stdout.take() {
+        if STDOUT_RESERVED.load(Ordering::Relaxed) {
+            tokio::spawn(forward_lines(stdout, std::io::stderr()));
+        } else {
+            tokio::spawn(forward_lines(stdout, std::io::stdout()));
+        }
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        tokio::spawn(forward_lines(stderr, std::io::stderr()));
+    }
+}
+
+async fn forward_lines(reader: impl AsyncRead + Unpin, mut writer: impl Write) {
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        let _: Result<_, _> = writeln!(writer, "{}", redact(&line));
+    }
+}
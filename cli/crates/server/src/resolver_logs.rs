@@ -0,0 +1,202 @@
+use crate::bridge::ResolverMessage;
+use crate::consts::{RESOLVER_LOGS_DIR, RESOLVER_LOG_BUFFER_SIZE, RESOLVER_LOG_EXTENSION};
+use crate::errors::ServerError;
+use common::environment::Project;
+use itertools::Itertools;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+
+/// keeps the most recent log entries of each resolver, mirroring them to `.grafbase/resolver-logs`
+/// so that `grafbase resolvers logs` can print them from outside of the running server
+pub(crate) struct ResolverLogBuffer {
+    directory: PathBuf,
+    entries: Mutex<HashMap<String, VecDeque<ResolverMessage>>>,
+}
+
+impl Default for ResolverLogBuffer {
+    fn default() -> Self {
+        Self {
+            directory: resolver_logs_path(),
+            entries: Mutex::default(),
+        }
+    }
+}
+
+impl ResolverLogBuffer {
+    pub async fn push(&self, resolver_name: &str, log_entries: &[ResolverMessage]) {
+        if log_entries.is_empty() {
+            return;
+        }
+
+        let log_path = self
+            .directory
+            .join(resolver_name)
+            .with_extension(RESOLVER_LOG_EXTENSION);
+
+        // held until the file is written to keep concurrent invocations from interleaving
+        let mut entries = self.entries.lock().await;
+
+        let buffer = match entries.entry(resolver_name.to_owned()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            // picks up where the previous server left off after a reload
+            Entry::Vacant(entry) => entry.insert(read_log_file(&log_path).await.unwrap_or_default().into()),
+        };
+
+        buffer.extend(log_entries.iter().cloned());
+
+        if buffer.len() > RESOLVER_LOG_BUFFER_SIZE {
+            buffer.drain(..buffer.len() - RESOLVER_LOG_BUFFER_SIZE);
+        }
+
+        let contents: String = buffer
+            .iter()
+            .map(|entry| serde_json::to_string(entry).expect("must serialise") + "\n")
+            .collect();
+
+        if let Err(error) = write_log_file(&log_path, contents).await {
+            error!("could not write the logs of resolver '{resolver_name}': {error}");
+        }
+    }
+}
+
+fn resolver_logs_path() -> PathBuf {
+    Project::get().dot_grafbase_directory_path.join(RESOLVER_LOGS_DIR)
+}
+
+async fn read_log_file(path: &Path) -> Option<Vec<ResolverMessage>> {
+    let contents = tokio::fs::read_to_string(path).await.ok()?;
+
+    Some(
+        contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect(),
+    )
+}
+
+async fn write_log_file(path: &Path, contents: String) -> Result<(), ServerError> {
+    let directory = path.parent().expect("must have a parent");
+
+    tokio::fs::create_dir_all(directory)
+        .await
+        .map_err(|_| ServerError::CreateDir(directory.to_owned()))?;
+
+    tokio::fs::write(path, contents)
+        .await
+        .map_err(|error| ServerError::WriteResolverLogs(path.to_owned(), error))
+}
+
+/// reads the most recent log entries recorded by the development server, optionally only the ones of a specific resolver
+///
+/// # Errors
+///
+/// returns [`ServerError::ReadFile`] if the resolver logs directory or one of the log files cannot be read
+pub fn read_entries(resolver_name: Option<&str>) -> Result<Vec<(String, Vec<ResolverMessage>)>, ServerError> {
+    let root = resolver_logs_path();
+
+    let mut log_paths = Vec::new();
+
+    if root.is_dir() {
+        collect_log_paths(&root, &mut log_paths)?;
+    }
+
+    log_paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path
+                .strip_prefix(&root)
+                .expect("must contain the root directory")
+                .with_extension("")
+                .iter()
+                .map(OsStr::to_string_lossy)
+                .join("/");
+
+            resolver_name
+                .map_or(true, |resolver_name| name == resolver_name)
+                .then_some((name, path))
+        })
+        .sorted_by(|(left, _), (right, _)| left.cmp(right))
+        .map(|(name, path)| {
+            let contents =
+                std::fs::read_to_string(&path).map_err(|error| ServerError::ReadFile(path.clone(), error))?;
+
+            let entries = contents
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect();
+
+            Ok((name, entries))
+        })
+        .collect()
+}
+
+fn collect_log_paths(directory: &Path, log_paths: &mut Vec<PathBuf>) -> Result<(), ServerError> {
+    let entries = std::fs::read_dir(directory).map_err(|error| ServerError::ReadFile(directory.to_owned(), error))?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|error| ServerError::ReadFile(directory.to_owned(), error))?
+            .path();
+
+        if path.is_dir() {
+            collect_log_paths(&path, log_paths)?;
+        } else if path.extension().and_then(OsStr::to_str) == Some(RESOLVER_LOG_EXTENSION) {
+            log_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_log_file, ResolverLogBuffer, ResolverMessage, RESOLVER_LOG_BUFFER_SIZE};
+    use common::types::ResolverMessageLevel;
+    use tokio::sync::Mutex;
+
+    fn buffer(directory: &std::path::Path) -> ResolverLogBuffer {
+        ResolverLogBuffer {
+            directory: directory.to_owned(),
+            entries: Mutex::default(),
+        }
+    }
+
+    fn messages(range: std::ops::Range<usize>) -> Vec<ResolverMessage> {
+        range
+            .map(|index| ResolverMessage {
+                message: index.to_string(),
+                level: ResolverMessageLevel::Info,
+            })
+            .collect()
+    }
+
+    fn logged_messages(entries: &[ResolverMessage]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.message.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn resolver_log_buffer_rollover() {
+        let directory = tempfile::tempdir().unwrap();
+        let log_path = directory.path().join("nested/resolver.jsonl");
+
+        let log_buffer = buffer(directory.path());
+        log_buffer.push("nested/resolver", &messages(0..150)).await;
+        log_buffer.push("nested/resolver", &messages(150..250)).await;
+
+        let entries = read_log_file(&log_path).await.unwrap();
+        assert_eq!(entries.len(), RESOLVER_LOG_BUFFER_SIZE);
+        assert_eq!(logged_messages(&entries), logged_messages(&messages(50..250)));
+
+        // a new server (e.g. after a reload) appends to the entries of the previous one
+        buffer(directory.path())
+            .push("nested/resolver", &messages(250..251))
+            .await;
+
+        let entries = read_log_file(&log_path).await.unwrap();
+        assert_eq!(entries.len(), RESOLVER_LOG_BUFFER_SIZE);
+        assert_eq!(logged_messages(&entries), logged_messages(&messages(51..251)));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::bridge::call_resolver;
+use crate::bridge::errors::ApiError;
+use crate::consts::{RESOLVER_FIXTURE_EXTENSION, RESOLVER_SNAPSHOT_EXTENSION, WORKER_POLL_INTERVAL};
+use crate::errors::ServerError;
+use crate::types::EnvironmentOptions;
+use common::environment::Project;
+use itertools::Itertools;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::ErrorKind;
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::sleep;
+
+pub use crate::bridge::ResolverMessage;
+
+/// a payload used to invoke a resolver, read from `grafbase/resolvers/__fixtures__/<resolver name>/<case>.json`
+/// and compared against the snapshot in `<case>.snap` next to it
+#[derive(Clone, Debug)]
+pub struct ResolverFixture {
+    pub resolver_name: String,
+    pub case_name: String,
+    pub payload_path: PathBuf,
+    pub snapshot_path: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum ResolverTestOutcome {
+    /// the result matched the existing snapshot
+    Passed,
+    /// the result did not match the existing snapshot
+    Failed { expected: Value, actual: Value },
+    /// there was no existing snapshot, the result was written as a new snapshot
+    SnapshotCreated,
+    /// the result did not match the existing snapshot, which was overwritten with the result
+    SnapshotUpdated,
+    /// the resolver could not be invoked
+    InvocationFailed(String),
+}
+
+#[derive(Debug)]
+pub struct ResolverTestReport {
+    pub fixture: ResolverFixture,
+    pub log_entries: Vec<ResolverMessage>,
+    pub outcome: ResolverTestOutcome,
+}
+
+impl ResolverTestReport {
+    #[must_use]
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.outcome,
+            ResolverTestOutcome::Failed { .. } | ResolverTestOutcome::InvocationFailed(_)
+        )
+    }
+}
+
+/// finds the fixtures in `grafbase/resolvers/__fixtures__`, optionally only the ones of a specific resolver
+///
+/// # Errors
+///
+/// returns [`ServerError::ReadFile`] if the fixtures directory or one of its subdirectories cannot be read
+pub fn discover_fixtures(resolver_name: Option<&str>) -> Result<Vec<ResolverFixture>, ServerError> {
+    let project = Project::get();
+
+    let mut fixtures = Vec::new();
+
+    if project.resolver_fixtures_path.is_dir() {
+        collect_fixtures(
+            &project.resolver_fixtures_path,
+            &project.resolver_fixtures_path,
+            &mut fixtures,
+        )?;
+    }
+
+    Ok(fixtures
+        .into_iter()
+        .filter(|fixture| resolver_name.map_or(true, |resolver_name| fixture.resolver_name == resolver_name))
+        .sorted_by(|left, right| (&left.resolver_name, &left.case_name).cmp(&(&right.resolver_name, &right.case_name)))
+        .collect())
+}
+
+fn collect_fixtures(root: &Path, directory: &Path, fixtures: &mut Vec<ResolverFixture>) -> Result<(), ServerError> {
+    let entries = std::fs::read_dir(directory).map_err(|error| ServerError::ReadFile(directory.to_owned(), error))?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|error| ServerError::ReadFile(directory.to_owned(), error))?
+            .path();
+
+        if path.is_dir() {
+            collect_fixtures(root, &path, fixtures)?;
+            continue;
+        }
+
+        if path.extension().and_then(OsStr::to_str) != Some(RESOLVER_FIXTURE_EXTENSION) {
+            continue;
+        }
+
+        // nested resolvers (e.g. `resolvers/users/get.js`) have their fixtures in matching nested directories,
+        // files directly within the fixtures directory don't belong to any resolver
+        let resolver_path = directory.strip_prefix(root).expect("must contain the root directory");
+
+        if resolver_path.as_os_str().is_empty() {
+            continue;
+        }
+
+        let resolver_name = resolver_path.iter().map(OsStr::to_string_lossy).join("/");
+        let case_name = path
+            .file_stem()
+            .expect("must have a file name")
+            .to_string_lossy()
+            .into_owned();
+
+        fixtures.push(ResolverFixture {
+            resolver_name,
+            case_name,
+            snapshot_path: path.with_extension(RESOLVER_SNAPSHOT_EXTENSION),
+            payload_path: path,
+        });
+    }
+
+    Ok(())
+}
+
+/// resolves once the worker accepts connections
+pub(crate) async fn wait_for_worker(worker_port: u16) {
+    while TcpStream::connect((Ipv4Addr::LOCALHOST, worker_port)).await.is_err() {
+        sleep(WORKER_POLL_INTERVAL).await;
+    }
+}
+
+/// invokes each fixture through the resolver worker and compares the results with the stored snapshots
+pub(crate) async fn run_fixtures(
+    worker_port: u16,
+    fixtures: Vec<ResolverFixture>,
+    update: bool,
+    environment_options: &EnvironmentOptions,
+    resolver_timeout: Duration,
+) -> Result<Vec<ResolverTestReport>, ServerError> {
+    let client = reqwest::Client::new();
+
+    let environment_variables: HashMap<_, _> = crate::environment::variables(environment_options).collect();
+
+    let mut reports = Vec::with_capacity(fixtures.len());
+
+    for fixture in fixtures {
+        trace!(
+            "running fixture '{}' for resolver '{}'",
+            fixture.case_name,
+            fixture.resolver_name
+        );
+
+        let payload_contents = tokio::fs::read(&fixture.payload_path)
+            .await
+            .map_err(|error| ServerError::ReadFile(fixture.payload_path.clone(), error))?;
+
+        let payload: Value = serde_json::from_slice(&payload_contents)
+            .map_err(|error| ServerError::ResolverFixtureJson(fixture.payload_path.clone(), error))?;
+
+        let result = call_resolver(
+            &client,
+            worker_port,
+            &fixture.resolver_name,
+            &payload,
+            &environment_variables,
+            resolver_timeout,
+        )
+        .await;
+
+        let (actual, log_entries) = match result {
+            Ok(result) => result,
+            Err(error) => {
+                let log_entries = match &error {
+                    ApiError::ResolverThrew { log_entries, .. } => log_entries.clone(),
+                    _ => Vec::new(),
+                };
+                reports.push(ResolverTestReport {
+                    fixture,
+                    log_entries,
+                    outcome: ResolverTestOutcome::InvocationFailed(error.to_string()),
+                });
+                continue;
+            }
+        };
+
+        let outcome = compare_with_snapshot(&fixture.snapshot_path, actual, update).await?;
+
+        reports.push(ResolverTestReport {
+            fixture,
+            log_entries,
+            outcome,
+        });
+    }
+
+    Ok(reports)
+}
+
+async fn compare_with_snapshot(
+    snapshot_path: &Path,
+    actual: Value,
+    update: bool,
+) -> Result<ResolverTestOutcome, ServerError> {
+    let expected: Option<Value> = match tokio::fs::read(snapshot_path).await {
+        Ok(contents) => Some(
+            serde_json::from_slice(&contents)
+                .map_err(|error| ServerError::ResolverFixtureJson(snapshot_path.to_owned(), error))?,
+        ),
+        Err(error) if error.kind() == ErrorKind::NotFound => None,
+        Err(error) => return Err(ServerError::ReadFile(snapshot_path.to_owned(), error)),
+    };
+
+    match expected {
+        Some(expected) if expected == actual => Ok(ResolverTestOutcome::Passed),
+        Some(_) if update => {
+            write_snapshot(snapshot_path, &actual).await?;
+            Ok(ResolverTestOutcome::SnapshotUpdated)
+        }
+        Some(expected) => Ok(ResolverTestOutcome::Failed { expected, actual }),
+        None => {
+            write_snapshot(snapshot_path, &actual).await?;
+            Ok(ResolverTestOutcome::SnapshotCreated)
+        }
+    }
+}
+
+async fn write_snapshot(snapshot_path: &Path, value: &Value) -> Result<(), ServerError> {
+    let contents = serde_json::to_string_pretty(value).expect("serde_json::Value serialises just fine for sure");
+
+    tokio::fs::write(snapshot_path, format!("{contents}\n"))
+        .await
+        .map_err(|error| ServerError::WriteResolverSnapshot(snapshot_path.to_owned(), error))
+}
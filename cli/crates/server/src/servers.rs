@@ -1,26 +1,38 @@
+use crate::admin::AdminState;
 use crate::consts::{
     ASSET_VERSION_FILE, CONFIG_PARSER_SCRIPT, GENERATED_SCHEMAS_DIR, GIT_IGNORE_CONTENTS, GIT_IGNORE_FILE,
-    MIN_NODE_VERSION, SCHEMA_PARSER_DIR, SCHEMA_PARSER_INDEX, TS_NODE_SCRIPT_PATH,
+    MIN_NODE_VERSION, SCHEMA_PARSER_DIR, SCHEMA_PARSER_INDEX, SHUTDOWN_TIMEOUT, TS_NODE_SCRIPT_PATH,
 };
-use crate::custom_resolvers::build_resolvers;
-use crate::error_server;
-use crate::event::{wait_for_event, wait_for_event_and_match, Event};
+use crate::custom_resolvers::{build_resolvers, external_import_directories};
+use crate::dev_lock::DevServerLock;
+use crate::environment::missing_variable_warnings;
+use crate::error_server::{self, Diagnostic, ErrorCode};
+use crate::event::{wait_for_event, Event};
 use crate::file_watcher::start_watcher;
-use crate::types::{ServerMessage, ASSETS_GZIP};
+use crate::resolver_tests::{run_fixtures, wait_for_worker, ResolverFixture, ResolverTestReport};
+use crate::tls;
+use crate::types::{EnvironmentOptions, HttpsOptions, ServerMessage, ShutdownHandle, WatchOptions, ASSETS_GZIP};
 use crate::{bridge, errors::ServerError};
+use crate::{proxy, redacted_output};
 use common::consts::{
     EPHEMERAL_PORT_RANGE, GRAFBASE_DIRECTORY_NAME, GRAFBASE_SCHEMA_FILE_NAME, GRAFBASE_TS_CONFIG_FILE_NAME,
 };
 use common::environment::{Environment, Project, SchemaLocation};
 use common::types::LocalAddressType;
 use common::utils::find_available_port_in_range;
-use futures_util::FutureExt;
+use futures_util::stream::FuturesUnordered;
+use futures_util::{FutureExt, StreamExt};
 
 use flate2::read::GzDecoder;
 use std::borrow::Cow;
 use std::env;
-use std::path::Path;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::Duration;
 use std::{
     fs,
     process::Stdio,
@@ -29,7 +41,8 @@ use std::{
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tokio::runtime::Builder;
-use tokio::sync::broadcast::{self, channel};
+use tokio::sync::broadcast::{self, channel, error::RecvError};
+use tokio::sync::watch;
 use version_compare::Version;
 use which::which;
 
@@ -46,21 +59,184 @@ const EVENT_BUS_BOUND: usize = 5;
 ///
 /// returns [`ServerError::WriteFile`] if a file cannot be written into `WORKER_DIR`
 ///
+/// returns [`ServerError::Certificate`] if the certificate for `https` cannot be issued
+///
+/// the server runs until stopped through the returned [`ShutdownHandle`]
+///
 /// # Panics
 ///
 /// The spawned server and miniflare thread can panic if either of the two inner spawned threads panic
 #[must_use]
-pub fn start(port: u16, watch: bool, tracing: bool) -> (JoinHandle<Result<(), ServerError>>, Receiver<ServerMessage>) {
+pub fn start(
+    lock: DevServerLock,
+    address: SocketAddr,
+    watch: Option<WatchOptions>,
+    https: Option<HttpsOptions>,
+    environment_options: EnvironmentOptions,
+    resolver_timeout: Duration,
+    tracing: bool,
+) -> (
+    JoinHandle<Result<(), ServerError>>,
+    Receiver<ServerMessage>,
+    ShutdownHandle,
+) {
     let (sender, receiver): (Sender<ServerMessage>, Receiver<ServerMessage>) = mpsc::channel();
 
     let project = Project::get();
 
+    let shutdown = ShutdownHandle::new();
+    let server_shutdown = shutdown.clone();
+
+    let handle = thread::spawn(move || {
+        // held until the server stops
+        let _lock = lock;
+
+        export_embedded_files()?;
+
+        create_project_dot_grafbase_directory()?;
+
+        let tls = https
+            .as_ref()
+            .map(|https| tls::certificate(https, address.ip()))
+            .transpose()?;
+
+        let (event_bus, _receiver) = channel::<Event>(EVENT_BUS_BOUND);
+
+        let admin_state = Arc::new(AdminState::new(address, event_bus.clone(), server_shutdown.clone()));
+
+        let (server_sender, server_receiver): (Sender<ServerMessage>, Receiver<ServerMessage>) = mpsc::channel();
+
+        // forwards the server messages while keeping track of the status reported by the admin API
+        let forwarder_admin_state = admin_state.clone();
+        thread::spawn(move || {
+            while let Ok(message) = server_receiver.recv() {
+                forwarder_admin_state.record(&message);
+                let _: Result<_, _> = sender.send(message);
+            }
+        });
+
+        // manual implementation of #[tokio::main] due to a rust analyzer issue
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let (worker_port_sender, worker_port_receiver) = watch::channel(None);
+
+                let watch_event_bus = event_bus.clone();
+                let env_file_paths = environment_options.env_file_paths();
+                let watcher = watch.as_ref().map(|watch_options| {
+                    start_watcher(
+                        project.grafbase_directory_path.clone(),
+                        watch_options,
+                        &env_file_paths,
+                        event_bus.subscribe(),
+                        move |paths| {
+                            // paths outside of the project (e.g. in imported directories) are kept absolute
+                            let relative_paths = paths
+                                .iter()
+                                .map(|path| path.strip_prefix(&project.path).unwrap_or(path).to_owned())
+                                .collect();
+                            watch_event_bus.send(Event::Reload(relative_paths)).expect("cannot fail");
+                        },
+                    )
+                });
+
+                let servers = server_loop(
+                    address.port(),
+                    worker_port_sender,
+                    server_sender,
+                    event_bus.clone(),
+                    &environment_options,
+                    resolver_timeout,
+                    tracing,
+                );
+                tokio::pin!(servers);
+
+                tokio::select! {
+                    // the proxy only stops without an error once a shutdown was requested
+                    // and the requests in flight are done
+                    result = proxy::start(address, tls, admin_state, worker_port_receiver, server_shutdown) => { result?; }
+                    result = optional(watcher) => { return result; }
+                    result = &mut servers => { return result; }
+                }
+
+                trace!("stopping the servers");
+                let _: Result<_, _> = event_bus.send(Event::Shutdown);
+
+                if tokio::time::timeout(SHUTDOWN_TIMEOUT, servers).await.is_err() {
+                    trace!("stopped waiting for the servers");
+                }
+
+                Ok(())
+            })
+    });
+
+    (handle, receiver, shutdown)
+}
+
+/// starts a development server without watching for changes and runs the given resolver fixtures
+/// once the worker is ready, stopping the server afterwards.
+/// the project stays locked for other development servers until the tests are done
+///
+/// # Errors
+///
+/// see [`start`]
+///
+/// returns [`ServerError::Compilation`] if the project could not be compiled
+///
+/// # Panics
+///
+/// The spawned server thread can panic if either of the two inner spawned threads panic
+#[must_use]
+pub fn test_resolvers(
+    lock: DevServerLock,
+    port: u16,
+    fixtures: Vec<ResolverFixture>,
+    update: bool,
+    resolver_timeout: Duration,
+    tracing: bool,
+) -> (
+    JoinHandle<Result<Vec<ResolverTestReport>, ServerError>>,
+    Receiver<ServerMessage>,
+) {
+    let (sender, receiver): (Sender<ServerMessage>, Receiver<ServerMessage>) = mpsc::channel();
+
     let handle = thread::spawn(move || {
+        // held until the tests are done
+        let _lock = lock;
+
         export_embedded_files()?;
 
         create_project_dot_grafbase_directory()?;
 
-        let bridge_port = get_bridge_port(port)?;
+        let ports = Ports::direct(port)?;
+
+        let environment_options = EnvironmentOptions::default();
+
+        let (server_sender, server_receiver): (Sender<ServerMessage>, Receiver<ServerMessage>) = mpsc::channel();
+        let (build_sender, build_receiver) = tokio::sync::oneshot::channel::<Result<(), String>>();
+
+        // forwards the server messages while keeping track of the outcome of the build
+        thread::spawn(move || {
+            let mut build_sender = Some(build_sender);
+
+            while let Ok(message) = server_receiver.recv() {
+                let build_result = match &message {
+                    ServerMessage::Ready(_) => Some(Ok(())),
+                    ServerMessage::CompilationError(error) => Some(Err(error.clone())),
+                    _ => None,
+                };
+
+                if let Some(build_result) = build_result {
+                    if let Some(build_sender) = build_sender.take() {
+                        let _: Result<_, _> = build_sender.send(build_result);
+                    }
+                }
+
+                let _: Result<_, _> = sender.send(message);
+            }
+        });
 
         // manual implementation of #[tokio::main] due to a rust analyzer issue
         Builder::new_current_thread()
@@ -70,18 +246,26 @@ pub fn start(port: u16, watch: bool, tracing: bool) -> (JoinHandle<Result<(), Se
             .block_on(async {
                 let (event_bus, _receiver) = channel::<Event>(EVENT_BUS_BOUND);
 
-                if watch {
-                    let watch_event_bus = event_bus.clone();
+                let tests = async {
+                    match build_receiver.await {
+                        Ok(Ok(())) => {}
+                        Ok(Err(error)) => return Err(ServerError::Compilation(error)),
+                        // the servers stopped before the build completed, which is handled below
+                        Err(_) => std::future::pending().await,
+                    }
+
+                    wait_for_worker(port).await;
+
+                    run_fixtures(port, fixtures, update, &environment_options, resolver_timeout).await
+                };
 
-                    tokio::select! {
-                        result = start_watcher(project.grafbase_directory_path.clone(), move |path| {
-                            let relative_path = path.strip_prefix(&project.path).expect("must succeed by definition").to_owned();
-                            watch_event_bus.send(Event::Reload(relative_path)).expect("cannot fail");
-                        }) => { result }
-                        result = server_loop(port, bridge_port, watch, sender, event_bus.clone(), tracing) => { result }
+                tokio::select! {
+                    // `watch` is set to make sure the worker is stopped along with the servers
+                    result = spawn_servers(ports, true, server_sender, event_bus, Vec::new(), &environment_options, resolver_timeout, tracing) => {
+                        result?;
+                        Err(ServerError::MiniflareError("the worker stopped before the resolver tests could run".to_owned()))
                     }
-                } else {
-                    Ok(spawn_servers(port, bridge_port, watch, sender, event_bus, None, tracing).await?)
+                    result = tests => { result }
                 }
             })
     });
@@ -89,73 +273,286 @@ pub fn start(port: u16, watch: bool, tracing: bool) -> (JoinHandle<Result<(), Se
     (handle, receiver)
 }
 
-async fn server_loop(
+/// the ports used by a single generation of the servers
+#[derive(Clone, Copy, Debug)]
+struct Ports {
+    /// the port requests are received on, reported to the user
+    public: u16,
+    /// the port of the worker, either the public port or one behind the proxy
+    worker: u16,
+    bridge: u16,
+}
+
+impl Ports {
+    /// ports for servers receiving requests directly, without reloads
+    fn direct(port: u16) -> Result<Self, ServerError> {
+        Ok(Self {
+            public: port,
+            worker: port,
+            bridge: get_bridge_port(port)?,
+        })
+    }
+
+    /// ports for a generation of servers behind the proxy
+    fn proxied(port: u16) -> Result<Self, ServerError> {
+        let bridge = get_bridge_port(port)?;
+
+        // the bridge port isn't bound yet, so the worker port is searched for after it
+        let skip = ephemeral_ports(port)
+            .position(|ephemeral_port| ephemeral_port == bridge)
+            .expect("must be within the range")
+            + 1;
+
+        let worker = find_available_port_in_range(ephemeral_ports(port).skip(skip), LocalAddressType::Localhost)
+            .ok_or(ServerError::AvailablePort)?;
+
+        Ok(Self {
+            public: port,
+            worker,
+            bridge,
+        })
+    }
+}
+
+/// a generation of the servers (either the bridge and the worker or the error server),
+/// built while the previous generation keeps serving requests and swapped in once ready
+struct Generation<'a> {
     worker_port: u16,
-    bridge_port: u16,
-    watch: bool,
+    paths_changed: Vec<PathBuf>,
+    event_bus: broadcast::Sender<Event>,
+    worker_ready: Pin<Box<dyn Future<Output = ()> + 'a>>,
+    servers: Pin<Box<dyn Future<Output = Result<(), ServerError>> + 'a>>,
+}
+
+impl<'a> Generation<'a> {
+    fn new(
+        port: u16,
+        sender: &Sender<ServerMessage>,
+        watch_event_bus: &broadcast::Sender<Event>,
+        paths_changed: Vec<PathBuf>,
+        environment_options: &'a EnvironmentOptions,
+        resolver_timeout: Duration,
+        tracing: bool,
+    ) -> Result<Self, ServerError> {
+        let ports = Ports::proxied(port)?;
+
+        let (event_bus, _receiver) = channel::<Event>(EVENT_BUS_BOUND);
+
+        let worker_ready = Box::pin(wait_for_event(event_bus.subscribe(), |event| {
+            *event == Event::WorkerReady
+        }));
+
+        // the watcher picks up the directories imported by the resolvers of each generation
+        let mut imports_receiver = event_bus.subscribe();
+        let watch_event_bus = watch_event_bus.clone();
+        tokio::spawn(async move {
+            loop {
+                match imports_receiver.recv().await {
+                    Ok(event @ Event::ImportedDirectories(_)) => {
+                        let _: Result<_, _> = watch_event_bus.send(event);
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+
+        let servers = Box::pin(spawn_servers(
+            ports,
+            true,
+            sender.clone(),
+            event_bus.clone(),
+            paths_changed.clone(),
+            environment_options,
+            resolver_timeout,
+            tracing,
+        ));
+
+        Ok(Self {
+            worker_port: ports.worker,
+            paths_changed,
+            event_bus,
+            worker_ready,
+            servers,
+        })
+    }
+
+    /// resolves once the generation can receive requests
+    async fn ready(&mut self) -> Result<(), ServerError> {
+        tokio::select! {
+            () = &mut self.worker_ready => Ok(()),
+            result = &mut self.servers => {
+                result?;
+                Err(ServerError::MiniflareError("the worker stopped before it was ready".to_owned()))
+            }
+        }
+    }
+
+    /// resolves once the servers of the generation stop
+    async fn stopped(&mut self) -> Result<(), ServerError> {
+        (&mut self.servers).await
+    }
+
+    /// abandons a generation that never served requests, dropping the generation kills its child processes
+    fn abandon(self) {
+        let _: Result<_, _> = self.event_bus.send(Event::Retire);
+    }
+
+    /// stops the servers of the generation once they are done with their requests in flight,
+    /// the worker is stopped last
+    async fn shut_down(mut self) -> Result<(), ServerError> {
+        let _: Result<_, _> = self.event_bus.send(Event::Retire);
+        self.stopped().await
+    }
+}
+
+/// awaits the future if there is one, otherwise never resolves
+async fn optional<F: Future>(future: Option<F>) -> F::Output {
+    match future {
+        Some(future) => future.await,
+        None => std::future::pending().await,
+    }
+}
+
+// the user facing address is served by a proxy forwarding to the current generation of the servers.
+// on each reload (triggered by the file watcher or the admin API) a new generation is built on other ports
+// while the current one keeps serving requests, the proxy switches over through `worker_port_sender`
+// once the new generation is ready (or once it falls back to the error server),
+// the previous generation is then shut down once it is done with its requests in flight
+async fn server_loop(
+    port: u16,
+    worker_port_sender: watch::Sender<Option<u16>>,
     sender: Sender<ServerMessage>,
     event_bus: broadcast::Sender<Event>,
+    environment_options: &EnvironmentOptions,
+    resolver_timeout: Duration,
     tracing: bool,
 ) -> Result<(), ServerError> {
-    let mut path_changed = None;
+    let mut reload_receiver = event_bus.subscribe();
+
+    let mut serving: Option<Generation<'_>> = None;
+    // replaced generations are kept alive until they are done with the requests they received before the switch
+    let mut retiring = FuturesUnordered::new();
+    let mut building = Some(Generation::new(
+        port,
+        &sender,
+        &event_bus,
+        Vec::new(),
+        environment_options,
+        resolver_timeout,
+        tracing,
+    )?);
+
     loop {
-        let receiver = event_bus.subscribe();
         tokio::select! {
-            result = spawn_servers(worker_port, bridge_port, watch, sender.clone(), event_bus.clone(), path_changed.as_deref(), tracing) => {
+            result = optional(building.as_mut().map(Generation::ready)) => {
+                result?;
+                let generation = building.take().expect("must exist if ready");
+                trace!("switching to the worker at port {}", generation.worker_port);
+                worker_port_sender.send_replace(Some(generation.worker_port));
+                if let Some(previous) = serving.replace(generation) {
+                    retiring.push(previous.shut_down());
+                }
+            }
+            Some(result) = retiring.next(), if !retiring.is_empty() => {
+                if let Err(error) = result {
+                    error!("a replaced generation failed to stop: {error}");
+                }
+            }
+            result = optional(serving.as_mut().map(Generation::stopped)) => {
                 result?;
+                // the servers stopped on their own, keep the previous change to rebuild with
+                let paths_changed = serving.take().map(|generation| generation.paths_changed).unwrap_or_default();
+                if building.is_none() {
+                    building = Some(Generation::new(
+                        port, &sender, &event_bus, paths_changed, environment_options, resolver_timeout, tracing,
+                    )?);
+                }
             }
-            path = wait_for_event_and_match(receiver, |event| match event {
-                Event::Reload(path) => Some(path),
-                Event::BridgeReady => None,
-            }) => {
+            event = reload_receiver.recv() => {
+                let mut paths_changed = match event {
+                    Ok(Event::Reload(paths)) => paths,
+                    Ok(Event::Shutdown) => {
+                        // a build in progress is abandoned, its child processes are killed when dropped
+                        if let Some(building) = building.take() {
+                            building.abandon();
+                        }
+                        if let Some(serving) = serving.take() {
+                            retiring.push(serving.shut_down());
+                        }
+                        while let Some(result) = retiring.next().await {
+                            result?;
+                        }
+                        return Ok(());
+                    }
+                    _ => continue,
+                };
                 trace!("reload");
-                path_changed = Some(path.clone());
-                let _: Result<_, _> = sender.send(ServerMessage::Reload(path));
+                let _: Result<_, _> = sender.send(ServerMessage::Reload(paths_changed.clone()));
+                // a change superseding a build in progress must still rebuild everything the superseded change required
+                if let Some(superseded) = building.take() {
+                    paths_changed.extend(superseded.paths_changed.iter().cloned());
+                    superseded.abandon();
+                }
+                building = Some(Generation::new(
+                    port, &sender, &event_bus, paths_changed, environment_options, resolver_timeout, tracing,
+                )?);
             }
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 #[tracing::instrument(level = "trace")]
 async fn spawn_servers(
-    worker_port: u16,
-    bridge_port: u16,
+    ports: Ports,
     watch: bool,
     sender: Sender<ServerMessage>,
     event_bus: broadcast::Sender<Event>,
-    path_changed: Option<&Path>,
+    paths_changed: Vec<PathBuf>,
+    environment_options: &EnvironmentOptions,
+    resolver_timeout: Duration,
     tracing: bool,
 ) -> Result<(), ServerError> {
+    let Ports {
+        public: public_port,
+        worker: worker_port,
+        bridge: bridge_port,
+    } = ports;
+
     let bridge_event_bus = event_bus.clone();
 
     let receiver = event_bus.subscribe();
 
     validate_dependencies().await?;
 
-    let environment_variables: std::collections::HashMap<_, _> = crate::environment::variables().collect();
+    let environment_variables: std::collections::HashMap<_, _> =
+        crate::environment::variables(environment_options).collect();
+
+    crate::environment::redact_file_variables(environment_options);
 
-    let mut resolvers = match run_schema_parser(&environment_variables).await {
+    let mut resolvers = match run_schema_parser(&sender, &environment_variables).await {
         Ok(resolvers) => resolvers,
         Err(error) => {
             let _: Result<_, _> = sender.send(ServerMessage::CompilationError(error.to_string()));
-            tokio::spawn(async move { error_server::start(worker_port, error.to_string(), bridge_event_bus).await })
-                .await??;
+            let diagnostic = Diagnostic::from_schema_parser_error(&error).await;
+            tokio::spawn(async move { error_server::start(worker_port, diagnostic, bridge_event_bus).await }).await??;
             return Ok(());
         }
     };
 
-    // If the rebuild has been triggered by a change in the schema file, we can honour the freshness of resolvers
+    // If the rebuild has been triggered by changes in the schema file or in the environment variables
+    // (which are passed to resolvers on each invocation), we can honour the freshness of resolvers
     // determined by inspecting the modified time of final artifacts of detected resolvers compared to the modified time
-    // of the generated schema registry file.
+    // of the generated schema registry file. Fresh resolvers are neither built nor is wrangler installed,
+    // so a change to the environment variables only restarts the workers, which pick up the new variables.
     // Otherwise, we trigger a rebuild all resolvers. That, individually, will still more often than not be very quick
     // because the build naturally reuses the intermediate artifacts from node_modules from previous builds.
     // For this logic to become more fine-grained we would need to have an understanding of the module dependency graph
     // in resolvers, and that's a non-trivial problem.
-    if path_changed
-        .map(|path| (Path::new(GRAFBASE_DIRECTORY_NAME), path))
-        .filter(|(dir, path)| path != &dir.join(GRAFBASE_SCHEMA_FILE_NAME))
-        .filter(|(dir, path)| path != &dir.join(GRAFBASE_TS_CONFIG_FILE_NAME))
-        .is_some()
+    if !paths_changed
+        .iter()
+        .all(|path| honours_resolver_freshness(environment_options, path))
     {
         for resolver in &mut resolvers {
             resolver.fresh = false;
@@ -165,7 +562,7 @@ async fn spawn_servers(
     let environment = Environment::get();
     let project = Project::get();
 
-    let resolver_paths = match build_resolvers(&sender, environment, &environment_variables, resolvers, tracing).await {
+    let resolver_paths = match build_resolvers(&sender, environment, resolvers, tracing).await {
         Ok(resolver_paths) => resolver_paths,
         Err(error) => {
             let _: Result<_, _> = sender.send(ServerMessage::CompilationError(error.to_string()));
@@ -174,21 +571,38 @@ async fn spawn_servers(
                 .ok()
                 .and_then(|stripped| String::from_utf8(stripped).ok())
                 .unwrap_or_else(|| error.to_string());
-            tokio::spawn(async move { error_server::start(worker_port, error, bridge_event_bus).await }).await??;
+            let diagnostic = Diagnostic::new(ErrorCode::ResolverBuildError, &error);
+            tokio::spawn(async move { error_server::start(worker_port, diagnostic, bridge_event_bus).await }).await??;
             return Ok(());
         }
     };
 
+    if watch {
+        let imported_directories = external_import_directories(&resolver_paths).await;
+        let _: Result<_, _> = event_bus.send(Event::ImportedDirectories(imported_directories));
+    }
+
     let (bridge_sender, mut bridge_receiver) = tokio::sync::mpsc::channel(128);
 
-    let mut bridge_handle =
-        tokio::spawn(async move { bridge::start(bridge_port, worker_port, bridge_sender, bridge_event_bus).await })
-            .fuse();
+    let mut bridge_handle = tokio::spawn(async move {
+        bridge::start(
+            bridge_port,
+            worker_port,
+            bridge_sender,
+            bridge_event_bus,
+            environment_variables,
+            resolver_timeout,
+        )
+        .await
+    })
+    .fuse();
 
     let sender_cloned = sender.clone();
     tokio::spawn(async move {
         while let Some(message) = bridge_receiver.recv().await {
-            sender_cloned.send(message).unwrap();
+            if sender_cloned.send(message).is_err() {
+                break;
+            }
         }
     });
 
@@ -271,33 +685,58 @@ async fn spawn_servers(
         // Unbounded worker limit
         .env("MINIFLARE_SUBREQUEST_LIMIT", "1000")
         .args(miniflare_arguments.iter().map(std::convert::AsRef::as_ref))
-        .stdout(if tracing { Stdio::inherit() } else { Stdio::piped() })
-        .stderr(if tracing { Stdio::inherit() } else { Stdio::piped() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .current_dir(&environment.user_dot_grafbase_path)
-        .kill_on_drop(watch);
+        .kill_on_drop(true);
     trace!("Spawning {miniflare:?}");
-    let miniflare = miniflare.spawn().map_err(ServerError::MiniflareCommandError)?;
+    let mut miniflare = miniflare.spawn().map_err(ServerError::MiniflareCommandError)?;
 
-    let _: Result<_, _> = sender.send(ServerMessage::Ready(worker_port));
+    if tracing {
+        redacted_output::forward(&mut miniflare);
+    }
 
     let miniflare_output_result = miniflare.wait_with_output();
+    tokio::pin!(miniflare_output_result);
 
     tokio::select! {
-        result = miniflare_output_result => {
-            let output = result.map_err(ServerError::MiniflareCommandError)?;
-
-            output
-                .status
-                .success()
-                .then_some(())
-                .ok_or_else(|| ServerError::MiniflareError(String::from_utf8_lossy(&output.stderr).into_owned()))?;
-        }
+        () = wait_for_worker(worker_port) => {}
+        result = &mut miniflare_output_result => return miniflare_result(result),
+        bridge_handle_result = &mut bridge_handle => { bridge_handle_result??; return Ok(()); }
+    }
+
+    let _: Result<_, _> = event_bus.send(Event::WorkerReady);
+    let _: Result<_, _> = sender.send(ServerMessage::Ready(public_port));
+
+    tokio::select! {
+        result = miniflare_output_result => miniflare_result(result)?,
         bridge_handle_result = bridge_handle => { bridge_handle_result??; }
     }
 
     Ok(())
 }
 
+fn miniflare_result(result: std::io::Result<std::process::Output>) -> Result<(), ServerError> {
+    let output = result.map_err(ServerError::MiniflareCommandError)?;
+
+    output
+        .status
+        .success()
+        .then_some(())
+        .ok_or_else(|| ServerError::MiniflareError(String::from_utf8_lossy(&output.stderr).into_owned()))
+}
+
+/// whether resolvers can be left as is if their build artifacts are newer than the schema registry
+/// when the given file changes, as resolvers only depend on the schema through the registry
+/// and receive the environment variables on each invocation
+fn honours_resolver_freshness(environment_options: &EnvironmentOptions, path: &Path) -> bool {
+    let directory = Path::new(GRAFBASE_DIRECTORY_NAME);
+
+    path == directory.join(GRAFBASE_SCHEMA_FILE_NAME)
+        || path == directory.join(GRAFBASE_TS_CONFIG_FILE_NAME)
+        || environment_options.is_env_file(path)
+}
+
 fn export_embedded_files() -> Result<(), ServerError> {
     let environment = Environment::get();
 
@@ -342,7 +781,7 @@ fn export_embedded_files() -> Result<(), ServerError> {
     Ok(())
 }
 
-fn create_project_dot_grafbase_directory() -> Result<(), ServerError> {
+pub(crate) fn create_project_dot_grafbase_directory() -> Result<(), ServerError> {
     let project = Project::get();
 
     let project_dot_grafbase_path = project.dot_grafbase_directory_path.clone();
@@ -371,6 +810,7 @@ pub struct DetectedResolver {
 // schema-parser is run via NodeJS due to it being built to run in a Wasm (via wasm-bindgen) environement
 // and due to schema-parser not being open source
 async fn run_schema_parser(
+    sender: &Sender<ServerMessage>,
     environment_variables: &std::collections::HashMap<String, String>,
 ) -> Result<Vec<DetectedResolver>, ServerError> {
     trace!("parsing schema");
@@ -400,6 +840,16 @@ async fn run_schema_parser(
             SchemaLocation::Graphql(ref path) => Cow::Borrowed(path.to_str().ok_or(ServerError::ProjectPath)?),
         };
 
+        let schema = tokio::fs::read_to_string(&*schema_path)
+            .await
+            .map_err(|error| ServerError::ReadFile(PathBuf::from(&*schema_path), error))?;
+
+        let warnings = missing_variable_warnings(&schema, environment_variables);
+
+        if !warnings.is_empty() {
+            let _: Result<_, _> = sender.send(ServerMessage::Warnings(warnings));
+        }
+
         let mut node_command = Command::new("node")
             .args([
                 parser_path.to_str().ok_or(ServerError::CachePath)?,
@@ -409,6 +859,8 @@ async fn run_schema_parser(
             .stdin(Stdio::piped())
             .stderr(Stdio::piped())
             .stdout(Stdio::piped())
+            // stopped along with an abandoned build
+            .kill_on_drop(true)
             .spawn()
             .map_err(ServerError::SchemaParserError)?;
 
@@ -504,6 +956,7 @@ async fn parse_and_generate_config_from_ts(ts_config_path: &Path) -> Result<Stri
         .args(args)
         .stderr(Stdio::piped())
         .stdout(Stdio::piped())
+        .kill_on_drop(true)
         .spawn()
         .map_err(ServerError::SchemaParserError)?;
 
@@ -577,6 +1030,13 @@ async fn validate_dependencies() -> Result<(), ServerError> {
 // we segment the ephemeral port range into 100 segments and select a segment based on the last two digits of the process ID.
 // this allows for simultainious start of up to 100 CLIs
 fn get_bridge_port(http_port: u16) -> Result<u16, ServerError> {
+    // TODO: loop back and limit iteration to get an even range for each
+    find_available_port_in_range(ephemeral_ports(http_port), LocalAddressType::Localhost)
+        .ok_or(ServerError::AvailablePort)
+}
+
+/// the ephemeral port range, starting at the segment of `http_port`
+fn ephemeral_ports(http_port: u16) -> impl ExactSizeIterator<Item = u16> {
     // must be 0-99, will fit in u16
     #[allow(clippy::cast_possible_truncation)]
     let segment = http_port % 100;
@@ -586,8 +1046,5 @@ fn get_bridge_port(http_port: u16) -> Result<u16, ServerError> {
     let offset = size / 100 * segment;
     let start = EPHEMERAL_PORT_RANGE.min().expect("must exist");
     // allows us to loop back to the start of the range, giving any offset the same amount of potential ports
-    let range = EPHEMERAL_PORT_RANGE.map(|port| (port + offset) % size + start);
-
-    // TODO: loop back and limit iteration to get an even range for each
-    find_available_port_in_range(range, LocalAddressType::Localhost).ok_or(ServerError::AvailablePort)
+    EPHEMERAL_PORT_RANGE.map(move |port| (port + offset) % size + start)
 }
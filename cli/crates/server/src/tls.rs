@@ -0,0 +1,154 @@
+use crate::errors::ServerError;
+use crate::types::HttpsOptions;
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use common::consts::{CERTIFICATES_DIRECTORY_NAME, LOCAL_CA_CERTIFICATE_FILE_NAME, LOCAL_CA_KEY_FILE_NAME};
+use common::environment::Environment;
+use common::utils::network_addresses;
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, DistinguishedName, DnType, ExtendedKeyUsagePurpose, IsCa,
+    KeyPair, KeyUsagePurpose, SanType,
+};
+use std::fs;
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+const CERTIFICATE_AUTHORITY_NAME: &str = "Grafbase Local Development CA";
+const CERTIFICATE_AUTHORITY_VALIDITY_DAYS: i64 = 3650;
+// browsers reject server certificates valid for longer than 398 days
+const SERVER_CERTIFICATE_VALIDITY_DAYS: i64 = 365;
+
+/// a certificate and its private key in PEM format
+#[allow(clippy::module_name_repetitions)]
+pub struct TlsCertificate {
+    pub certificate: Vec<u8>,
+    pub key: Vec<u8>,
+}
+
+/// loads the user provided certificate or issues a certificate for `host` with the local certificate authority
+pub fn certificate(https: &HttpsOptions, host: IpAddr) -> Result<TlsCertificate, ServerError> {
+    match https {
+        HttpsOptions::Certificate {
+            certificate_path,
+            key_path,
+        } => Ok(TlsCertificate {
+            certificate: fs::read(certificate_path)
+                .map_err(|error| ServerError::ReadFile(certificate_path.clone(), error))?,
+            key: fs::read(key_path).map_err(|error| ServerError::ReadFile(key_path.clone(), error))?,
+        }),
+        HttpsOptions::LocalCertificateAuthority => {
+            let certificate_authority = certificate_authority()?;
+
+            let mut params = CertificateParams::default();
+            params.distinguished_name = distinguished_name("localhost");
+            params.subject_alt_names = subject_alt_names(host);
+            params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+            set_validity(&mut params, SERVER_CERTIFICATE_VALIDITY_DAYS);
+
+            let certificate = Certificate::from_params(params)?;
+
+            Ok(TlsCertificate {
+                certificate: certificate
+                    .serialize_pem_with_signer(&certificate_authority)?
+                    .into_bytes(),
+                key: certificate.serialize_private_key_pem().into_bytes(),
+            })
+        }
+    }
+}
+
+/// loads the local certificate authority from `~/.grafbase`, creating it on first use
+fn certificate_authority() -> Result<Certificate, ServerError> {
+    let certificates_path = Environment::get()
+        .user_dot_grafbase_path
+        .join(CERTIFICATES_DIRECTORY_NAME);
+    let certificate_path = certificates_path.join(LOCAL_CA_CERTIFICATE_FILE_NAME);
+    let key_path = certificates_path.join(LOCAL_CA_KEY_FILE_NAME);
+
+    match (fs::read_to_string(&certificate_path), fs::read_to_string(&key_path)) {
+        (Ok(certificate), Ok(key)) => {
+            let params = CertificateParams::from_ca_cert_pem(&certificate, KeyPair::from_pem(&key)?)?;
+            return Ok(Certificate::from_params(params)?);
+        }
+        (Err(error), _) if error.kind() != ErrorKind::NotFound => {
+            return Err(ServerError::ReadFile(certificate_path, error));
+        }
+        (_, Err(error)) if error.kind() != ErrorKind::NotFound => {
+            return Err(ServerError::ReadFile(key_path, error));
+        }
+        _ => {}
+    }
+
+    trace!(
+        "creating the local certificate authority at {}",
+        certificates_path.display()
+    );
+
+    let mut params = CertificateParams::default();
+    params.distinguished_name = distinguished_name(CERTIFICATE_AUTHORITY_NAME);
+    params.is_ca = IsCa::Ca(BasicConstraints::Constrained(0));
+    params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign];
+    set_validity(&mut params, CERTIFICATE_AUTHORITY_VALIDITY_DAYS);
+
+    let certificate_authority = Certificate::from_params(params)?;
+
+    fs::create_dir_all(&certificates_path)
+        .map_err(|error| ServerError::WriteCertificateAuthority(certificates_path.clone(), error))?;
+    write_private_file(&key_path, &certificate_authority.serialize_private_key_pem())?;
+    fs::write(&certificate_path, certificate_authority.serialize_pem()?)
+        .map_err(|error| ServerError::WriteCertificateAuthority(certificate_path, error))?;
+
+    Ok(certificate_authority)
+}
+
+fn distinguished_name(common_name: &str) -> DistinguishedName {
+    let mut distinguished_name = DistinguishedName::new();
+    distinguished_name.push(DnType::CommonName, common_name);
+    distinguished_name
+}
+
+/// the names the server can be reached at: localhost, the host itself and, when listening on all interfaces,
+/// the addresses of the network interfaces
+fn subject_alt_names(host: IpAddr) -> Vec<SanType> {
+    let mut addresses = vec![IpAddr::V4(Ipv4Addr::LOCALHOST), IpAddr::V6(Ipv6Addr::LOCALHOST)];
+
+    if !host.is_unspecified() && !addresses.contains(&host) {
+        addresses.push(host);
+    }
+
+    addresses.extend(network_addresses(host));
+
+    std::iter::once(SanType::DnsName("localhost".to_owned()))
+        .chain(addresses.into_iter().map(SanType::IpAddress))
+        .collect()
+}
+
+fn set_validity(params: &mut CertificateParams, days: i64) {
+    // starting a day early to account for clock skew
+    let today = Utc::now().date_naive();
+    let (year, month, day) = ymd(today - Duration::days(1));
+    params.not_before = rcgen::date_time_ymd(year, month, day);
+    let (year, month, day) = ymd(today + Duration::days(days));
+    params.not_after = rcgen::date_time_ymd(year, month, day);
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn ymd(date: NaiveDate) -> (i32, u8, u8) {
+    (date.year(), date.month() as u8, date.day() as u8)
+}
+
+/// writes a file readable only by the current user where supported
+fn write_private_file(path: &Path, contents: &str) -> Result<(), ServerError> {
+    use std::io::Write;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|error| ServerError::WriteCertificateAuthority(path.to_owned(), error))
+}
@@ -1,16 +1,24 @@
+use chrono::{DateTime, Utc};
+use common::consts::{DEFAULT_GRAFBASE_ENV, DEFAULT_WATCH_DEBOUNCE_MILLISECONDS};
+use common::environment::Warning;
 use common::types::ResolverMessageLevel;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
 
 pub const ASSETS_GZIP: &[u8] = include_bytes!("../assets/assets.tar.gz");
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ServerMessage {
     Ready(u16),
-    Reload(PathBuf),
+    Reload(Vec<PathBuf>),
     StartResolverBuild(String),
     CompleteResolverBuild {
         name: String,
-        duration: std::time::Duration,
+        duration: Duration,
     },
     ResolverMessage {
         resolver_name: String,
@@ -18,4 +26,150 @@ pub enum ServerMessage {
         message: String,
     },
     CompilationError(String),
+    Warnings(Vec<Warning>),
+}
+
+/// selects the files environment variables are loaded from and the value of `GRAFBASE_ENV`
+#[derive(Clone, Debug)]
+pub struct EnvironmentOptions {
+    /// the value of `GRAFBASE_ENV`, also selecting the `.env.<mode>` files to load
+    pub mode: String,
+    /// additional files to load after the `.env` files in the grafbase directory, in order of precedence.
+    /// expected to be canonical as they are watched for changes
+    pub env_files: Vec<PathBuf>,
+}
+
+impl Default for EnvironmentOptions {
+    fn default() -> Self {
+        Self {
+            mode: DEFAULT_GRAFBASE_ENV.to_owned(),
+            env_files: Vec::new(),
+        }
+    }
+}
+
+/// selects the files watched for changes and how they are watched
+#[derive(Clone, Debug)]
+pub struct WatchOptions {
+    /// globs (relative to the grafbase directory) of files to watch in addition to the default ones
+    pub include: Vec<String>,
+    /// globs (relative to the grafbase directory) of files not to watch, taking precedence over `include`
+    pub exclude: Vec<String>,
+    /// how long to wait for further changes before reloading
+    pub debounce: Duration,
+    /// poll for changes rather than relying on file system notifications
+    pub poll: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            debounce: Duration::from_millis(DEFAULT_WATCH_DEBOUNCE_MILLISECONDS),
+            poll: false,
+        }
+    }
+}
+
+/// the certificate `grafbase dev` terminates TLS with
+#[derive(Clone, Debug)]
+pub enum HttpsOptions {
+    /// a certificate issued on each start by a local certificate authority cached in `~/.grafbase`
+    LocalCertificateAuthority,
+    /// a user provided certificate and private key, in PEM format
+    Certificate {
+        certificate_path: PathBuf,
+        key_path: PathBuf,
+    },
+}
+
+/// the state of the current build of a running development server
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerState {
+    /// the project is being built, the previous build (if any) keeps serving requests
+    Building,
+    /// the latest build is serving requests
+    Ready,
+    /// the latest build failed, the error page is served
+    Failed,
+}
+
+/// the most recent build of a resolver
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResolverBuild {
+    pub name: String,
+    pub duration_ms: u128,
+    pub built_at: DateTime<Utc>,
+}
+
+/// the status of a running development server, as reported by its admin API
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ServerStatus {
+    pub pid: u32,
+    pub port: u16,
+    pub state: ServerState,
+    pub started_at: DateTime<Utc>,
+    pub reloaded_at: Option<DateTime<Utc>>,
+    /// the changes that triggered the latest reload, relative to the project directory
+    pub changed_paths: Vec<PathBuf>,
+    pub compilation_error: Option<String>,
+    /// resolvers which were built (rather than reused) since the server started
+    pub resolvers: Vec<ResolverBuild>,
+}
+
+/// the development server running in a project, recorded along with the project lock
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DevServerInfo {
+    pub pid: u32,
+    /// the address the server listens on, which may be unspecified to listen on all interfaces
+    pub host: IpAddr,
+    pub port: u16,
+    pub https: bool,
+    pub started_at: DateTime<Utc>,
+}
+
+impl DevServerInfo {
+    /// the address to reach the server at from the local machine,
+    /// the loopback address of the same family if the server listens on all interfaces
+    #[must_use]
+    pub fn local_address(&self) -> SocketAddr {
+        let host = match self.host {
+            IpAddr::V4(host) if host.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(host) if host.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            host => host,
+        };
+
+        SocketAddr::new(host, self.port)
+    }
+}
+
+/// requests a graceful shutdown of a development server, which stops accepting connections,
+/// finishes the requests in flight and stops its servers and child processes
+#[derive(Clone, Debug)]
+pub struct ShutdownHandle {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl ShutdownHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            sender: Arc::new(watch::channel(false).0),
+        }
+    }
+
+    pub fn shutdown(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// resolves once a shutdown is requested
+    pub(crate) async fn requested(&self) {
+        let mut receiver = self.sender.subscribe();
+
+        while !*receiver.borrow() {
+            // the sender is held by `self`
+            let _: Result<_, _> = receiver.changed().await;
+        }
+    }
 }